@@ -8,6 +8,8 @@
 //!
 //! - `time`: Time and timestamp utilities
 //! - `error`: Error handling and conversion utilities
+//! - `merkle`: Shared SHA-256 Merkle tree (build, root, inclusion proofs)
+//! - `numeric`: Numeric safety helpers (finite checks, NaN-safe ordering)
 //! - `concurrency`: Safe concurrency primitives and patterns
 //! - `result_ext`: Extension traits for Result types
 //! - `types`: Common type definitions and aliases
@@ -15,6 +17,8 @@
 
 pub mod concurrency;
 pub mod error;
+pub mod merkle;
+pub mod numeric;
 pub mod resilience;
 pub mod result_ext;
 pub mod time;
@@ -27,5 +31,8 @@ pub mod proptest_support;
 // Re-export commonly used items
 pub use concurrency::{SafeRwLock, SafeRwLockExt};
 pub use error::{MefError, MefResult};
+pub use merkle::{MerkleProof, MerkleTree};
+pub use numeric::{cmp_desc_nan_last, cmp_nan_last, validate_finite};
 pub use result_ext::ResultExt;
-pub use time::{current_timestamp, current_timestamp_millis};
+pub use time::{current_timestamp, current_timestamp_millis, jitter_timestamp};
+pub use types::{CoherenceKernel, ResonanceState, ResonanceTriplet};
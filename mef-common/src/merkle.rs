@@ -0,0 +1,223 @@
+//! Merkle tree utilities
+//!
+//! A single binary SHA-256 Merkle tree shared by components that commit to
+//! ordered lists of records (ledgers, proof registries, audit trails).
+//!
+//! Leaves and internal nodes are domain-separated (`0x00` / `0x01` prefix)
+//! so a leaf can never be confused with an internal node. An unpaired node
+//! at the end of a level is promoted to the next level unchanged rather
+//! than hashed with itself, so `[a, b, c]` and `[a, b, c, c]` have
+//! different roots.
+
+use crate::error::{MefError, MefResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 32-byte SHA-256 digest
+pub type MerkleHash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hash a leaf's data
+pub fn hash_leaf(data: &[u8]) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Hash two child nodes into their parent
+pub fn hash_node(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Side of a sibling relative to the node being proven
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    /// Sibling is the left child
+    Left,
+    /// Sibling is the right child
+    Right,
+}
+
+/// Inclusion proof for a single leaf
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Index of the proven leaf
+    pub index: usize,
+    /// Siblings from the leaf level up to the root
+    ///
+    /// Levels where the node was promoted without a sibling are skipped.
+    pub siblings: Vec<(Side, MerkleHash)>,
+}
+
+/// Binary Merkle tree over an ordered list of leaves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// Hashes per level; `levels[0]` are the leaf hashes, the last level
+    /// holds only the root
+    levels: Vec<Vec<MerkleHash>>,
+}
+
+impl MerkleTree {
+    /// Build a tree from leaf data
+    ///
+    /// # Errors
+    /// Returns a validation error if `leaves` is empty.
+    pub fn build<I, T>(leaves: I) -> MefResult<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let leaf_hashes: Vec<MerkleHash> = leaves
+            .into_iter()
+            .map(|leaf| hash_leaf(leaf.as_ref()))
+            .collect();
+        if leaf_hashes.is_empty() {
+            return Err(MefError::validation("Merkle tree needs at least one leaf"));
+        }
+
+        let mut levels = vec![leaf_hashes];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks(2) yields one or two items"),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self { levels })
+    }
+
+    /// Root hash
+    pub fn root(&self) -> MerkleHash {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Number of leaves
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether the tree has no leaves (never true for a built tree)
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Inclusion proof for the leaf at `index` (None if out of range)
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                let side = if sibling < position {
+                    Side::Left
+                } else {
+                    Side::Right
+                };
+                siblings.push((side, level[sibling]));
+            }
+            position /= 2;
+        }
+
+        Some(MerkleProof { index, siblings })
+    }
+
+    /// Verify that `leaf` is included under `root`
+    pub fn verify(root: &MerkleHash, leaf: &[u8], proof: &MerkleProof) -> bool {
+        let computed = proof
+            .siblings
+            .iter()
+            .fold(hash_leaf(leaf), |node, (side, sibling)| match side {
+                Side::Left => hash_node(sibling, &node),
+                Side::Right => hash_node(&node, sibling),
+            });
+        &computed == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("leaf-{}", i).into_bytes()).collect()
+    }
+
+    #[test]
+    fn test_empty_tree_rejected() {
+        assert!(MerkleTree::build(Vec::<Vec<u8>>::new()).is_err());
+    }
+
+    #[test]
+    fn test_single_leaf() {
+        let tree = MerkleTree::build([b"only"]).unwrap();
+        assert_eq!(tree.root(), hash_leaf(b"only"));
+
+        let proof = tree.proof(0).unwrap();
+        assert!(proof.siblings.is_empty());
+        assert!(MerkleTree::verify(&tree.root(), b"only", &proof));
+        assert!(tree.proof(1).is_none());
+    }
+
+    #[test]
+    fn test_root_matches_manual_construction() {
+        let data = leaves(3);
+        let tree = MerkleTree::build(&data).unwrap();
+
+        let h: Vec<MerkleHash> = data.iter().map(|d| hash_leaf(d)).collect();
+        // Odd node is promoted, not duplicated
+        let expected = hash_node(&hash_node(&h[0], &h[1]), &h[2]);
+        assert_eq!(tree.root(), expected);
+
+        let duplicated = MerkleTree::build([&data[0], &data[1], &data[2], &data[2]]).unwrap();
+        assert_ne!(tree.root(), duplicated.root());
+    }
+
+    #[test]
+    fn test_proofs_verify_for_all_leaves() {
+        for n in [1, 2, 3, 5, 8, 13] {
+            let data = leaves(n);
+            let tree = MerkleTree::build(&data).unwrap();
+            assert_eq!(tree.len(), n);
+
+            for (i, leaf) in data.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+                assert!(
+                    MerkleTree::verify(&tree.root(), leaf, &proof),
+                    "n={} i={}",
+                    n,
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_proof_rejected() {
+        let data = leaves(5);
+        let tree = MerkleTree::build(&data).unwrap();
+        let proof = tree.proof(2).unwrap();
+
+        assert!(!MerkleTree::verify(&tree.root(), b"forged", &proof));
+        assert!(!MerkleTree::verify(&tree.root(), &data[3], &proof));
+
+        let mut flipped = proof.clone();
+        flipped.siblings[0].0 = Side::Left;
+        assert!(!MerkleTree::verify(&tree.root(), &data[2], &flipped));
+    }
+}
@@ -0,0 +1,90 @@
+//! Numeric safety helpers
+//!
+//! Guards against non-finite floating point values (NaN, ±∞) entering the
+//! system, where they would poison distance computations and comparisons,
+//! and total-order comparators that never panic on NaN.
+
+use crate::error::{MefError, MefResult};
+use std::cmp::Ordering;
+
+/// Check that every component of a vector is finite
+///
+/// # Errors
+/// Returns a validation error naming the first offending index and value.
+pub fn validate_finite(vector: &[f64]) -> MefResult<()> {
+    match vector.iter().position(|v| !v.is_finite()) {
+        Some(index) => Err(MefError::validation(format!(
+            "Vector component {} is not finite: {}",
+            index, vector[index]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Ascending total order with NaN placed after every number
+///
+/// Drop-in replacement for `a.partial_cmp(&b).unwrap()` in sorts: numbers
+/// keep their usual order (`-0.0 < 0.0`), any NaN sorts last regardless
+/// of its sign bit.
+pub fn cmp_nan_last(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.total_cmp(&b),
+    }
+}
+
+/// Descending total order with NaN placed after every number
+///
+/// Use for "best first" sorts; `min_by(cmp_desc_nan_last)` selects the
+/// largest number and only yields NaN if every value is NaN.
+pub fn cmp_desc_nan_last(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => b.total_cmp(&a),
+        _ => cmp_nan_last(a, b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_finite_accepts_finite() {
+        assert!(validate_finite(&[]).is_ok());
+        assert!(validate_finite(&[0.0, -1.5, f64::MAX, f64::MIN_POSITIVE]).is_ok());
+    }
+
+    #[test]
+    fn test_sorts_place_nan_last() {
+        let mut values = [2.0, f64::NAN, -1.0, 0.5, -f64::NAN];
+
+        values.sort_by(|a, b| cmp_nan_last(*a, *b));
+        assert_eq!(&values[..3], &[-1.0, 0.5, 2.0]);
+        assert!(values[3..].iter().all(|v| v.is_nan()));
+
+        values.sort_by(|a, b| cmp_desc_nan_last(*a, *b));
+        assert_eq!(&values[..3], &[2.0, 0.5, -1.0]);
+        assert!(values[3..].iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_best_never_nan() {
+        let values = [0.3, f64::NAN, 0.9, 0.1];
+        let best = values
+            .iter()
+            .copied()
+            .min_by(|a, b| cmp_desc_nan_last(*a, *b));
+        assert_eq!(best, Some(0.9));
+    }
+
+    #[test]
+    fn test_validate_finite_rejects_nan_and_inf() {
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = validate_finite(&[1.0, bad, 2.0]).unwrap_err();
+            assert!(matches!(err, MefError::Validation(_)));
+            assert!(err.to_string().contains("component 1"));
+        }
+    }
+}
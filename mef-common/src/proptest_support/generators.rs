@@ -8,7 +8,9 @@ use proptest::prelude::*;
 
 /// Generate arbitrary ResonanceTriplet values
 ///
-/// Generates triplets with components in range [-10.0, 10.0]
+/// Generates finite triplets with components in range [-10.0, 10.0], so
+/// squared magnitudes cannot overflow. May produce the all-zero triplet;
+/// use [`arb_nonzero_resonance_triplet`] where normalization matters.
 ///
 /// # Example
 ///
@@ -17,11 +19,12 @@ use proptest::prelude::*;
 /// use mef_common::proptest_support::arb_resonance_triplet;
 ///
 /// proptest! {
-///     #[test]
 ///     fn test_resonance_magnitude_non_negative(triplet in arb_resonance_triplet()) {
 ///         prop_assert!(triplet.magnitude() >= 0.0);
 ///     }
 /// }
+///
+/// test_resonance_magnitude_non_negative();
 /// ```
 pub fn arb_resonance_triplet() -> impl Strategy<Value = ResonanceTriplet> {
     (-10.0..=10.0, -10.0..=10.0, -10.0..=10.0)
@@ -32,18 +35,22 @@ pub fn arb_resonance_triplet() -> impl Strategy<Value = ResonanceTriplet> {
 ///
 /// Generates triplets that are already normalized (magnitude = 1.0)
 pub fn arb_unit_resonance_triplet() -> impl Strategy<Value = ResonanceTriplet> {
-    arb_resonance_triplet().prop_map(|triplet| triplet.normalize())
+    arb_nonzero_resonance_triplet().prop_map(|triplet| triplet.normalize())
 }
 
 /// Generate non-zero ResonanceTriplet values
 ///
-/// Ensures at least one component is non-zero
+/// Rejects triplets with magnitude below [`MIN_NONZERO_MAGNITUDE`], so
+/// normalizing them is numerically stable.
 pub fn arb_nonzero_resonance_triplet() -> impl Strategy<Value = ResonanceTriplet> {
     arb_resonance_triplet().prop_filter("triplet must be non-zero", |t| {
-        t.psi != 0.0 || t.rho != 0.0 || t.omega != 0.0
+        t.magnitude() >= MIN_NONZERO_MAGNITUDE
     })
 }
 
+/// Smallest magnitude produced by [`arb_nonzero_resonance_triplet`]
+pub const MIN_NONZERO_MAGNITUDE: f64 = 1e-6;
+
 /// Generate arbitrary ContentHash values
 ///
 /// # Example
@@ -51,15 +58,17 @@ pub fn arb_nonzero_resonance_triplet() -> impl Strategy<Value = ResonanceTriplet
 /// ```rust
 /// use proptest::prelude::*;
 /// use mef_common::proptest_support::arb_content_hash;
+/// use mef_common::types::ContentHash;
 ///
 /// proptest! {
-///     #[test]
 ///     fn test_content_hash_hex_roundtrip(hash in arb_content_hash()) {
 ///         let hex = hash.to_hex();
 ///         let parsed = ContentHash::from_hex(&hex).unwrap();
 ///         prop_assert_eq!(hash, parsed);
 ///     }
 /// }
+///
+/// test_content_hash_hex_roundtrip();
 /// ```
 pub fn arb_content_hash() -> impl Strategy<Value = ContentHash> {
     prop::array::uniform32(any::<u8>()).prop_map(ContentHash::from_bytes)
@@ -144,6 +153,24 @@ mod tests {
             prop_assert!(triplet.magnitude() > 0.0);
         }
 
+        #[test]
+        fn test_arb_resonance_triplet_is_bounded(triplet in arb_resonance_triplet()) {
+            for c in [triplet.psi, triplet.rho, triplet.omega] {
+                prop_assert!(c.is_finite() && c.abs() <= 10.0, "component={}", c);
+            }
+        }
+
+        #[test]
+        fn test_normalize_yields_unit_length(triplet in arb_nonzero_resonance_triplet()) {
+            let magnitude = triplet.normalize().magnitude();
+            prop_assert!((magnitude - 1.0).abs() < 1e-10, "magnitude={}", magnitude);
+        }
+
+        #[test]
+        fn test_self_coherence_is_one(triplet in arb_resonance_triplet()) {
+            prop_assert_eq!(triplet.coherence_with(&triplet), 1.0);
+        }
+
         #[test]
         fn test_arb_content_hash_roundtrip(hash in arb_content_hash()) {
             let hex = hash.to_hex();
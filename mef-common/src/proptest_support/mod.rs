@@ -15,11 +15,10 @@
 //!
 //! ```rust
 //! use proptest::prelude::*;
-//! use mef_common::proptest_support::arb_resonance_triplet;
+//! use mef_common::proptest_support::arb_nonzero_resonance_triplet;
 //!
 //! proptest! {
-//!     #[test]
-//!     fn test_resonance_normalization_is_unit_length(triplet in arb_resonance_triplet()) {
+//!     fn test_resonance_normalization_is_unit_length(triplet in arb_nonzero_resonance_triplet()) {
 //!         let normalized = triplet.normalize();
 //!         let magnitude = normalized.magnitude();
 //!
@@ -27,6 +26,8 @@
 //!         prop_assert!((magnitude - 1.0).abs() < 1e-10);
 //!     }
 //! }
+//!
+//! test_resonance_normalization_is_unit_length();
 //! ```
 
 pub mod generators;
@@ -2,6 +2,8 @@
 //!
 //! Provides composite strategies for testing complex scenarios and invariants.
 
+use super::generators::{arb_bytes, arb_resonance_triplet};
+use crate::types::ResonanceTriplet;
 use proptest::prelude::*;
 
 /// Strategy for generating valid quantum masking parameters
@@ -11,6 +13,21 @@ pub fn quantum_masking_params() -> impl Strategy<Value = (f64, f64)> {
     (0.0..=std::f64::consts::TAU, 0.0..=1.0).prop_map(|(theta, sigma)| (theta, sigma))
 }
 
+/// Strategy for generating masking round-trip inputs
+///
+/// Returns (payload, sender, receiver) where the payload is up to `max_len`
+/// bytes and the resonance pair is what masking params get derived from.
+/// Payloads shrink towards the empty vector.
+pub fn masking_case(
+    max_len: usize,
+) -> impl Strategy<Value = (Vec<u8>, ResonanceTriplet, ResonanceTriplet)> {
+    (
+        arb_bytes(max_len),
+        arb_resonance_triplet(),
+        arb_resonance_triplet(),
+    )
+}
+
 /// Strategy for generating valid permutations of length n
 ///
 /// Generates all valid permutations of [0..n)
@@ -22,7 +39,6 @@ pub fn quantum_masking_params() -> impl Strategy<Value = (f64, f64)> {
 /// use mef_common::proptest_support::strategies::permutation;
 ///
 /// proptest! {
-///     #[test]
 ///     fn test_permutation_has_all_elements(perm in permutation(7)) {
 ///         // S7 permutation should have all elements 0..7
 ///         prop_assert_eq!(perm.len(), 7);
@@ -31,6 +47,8 @@ pub fn quantum_masking_params() -> impl Strategy<Value = (f64, f64)> {
 ///         }
 ///     }
 /// }
+///
+/// test_permutation_has_all_elements();
 /// ```
 pub fn permutation(n: usize) -> impl Strategy<Value = Vec<usize>> {
     Just(()).prop_perturb(move |_, mut rng| {
@@ -52,7 +70,7 @@ pub fn s7_permutation() -> impl Strategy<Value = Vec<usize>> {
 /// Strategy for generating sequences of operations
 ///
 /// Useful for testing state machines and workflows
-pub fn operation_sequence<T: Clone>(
+pub fn operation_sequence<T: Clone + std::fmt::Debug>(
     op: impl Strategy<Value = T>,
     min_len: usize,
     max_len: usize,
@@ -72,7 +90,8 @@ pub fn concurrent_scenario() -> impl Strategy<Value = (usize, usize)> {
 /// Returns (total_nodes, partition_sizes)
 pub fn network_partition(max_nodes: usize) -> impl Strategy<Value = (usize, Vec<usize>)> {
     (2usize..=max_nodes).prop_flat_map(|total| {
-        let num_partitions = 2usize..=4usize;
+        // Every partition needs at least one node
+        let num_partitions = 2usize..=total.min(4);
         num_partitions.prop_flat_map(move |n_parts| {
             // Generate partition sizes that sum to total
             partition_sizes(total, n_parts).prop_map(move |sizes| (total, sizes))
@@ -133,10 +152,18 @@ mod tests {
     use super::*;
 
     proptest! {
+        #[test]
+        fn test_masking_case_bounds((payload, sender, receiver) in masking_case(256)) {
+            prop_assert!(payload.len() <= 256);
+            for t in [sender, receiver] {
+                prop_assert!(t.psi.is_finite() && t.rho.is_finite() && t.omega.is_finite());
+            }
+        }
+
         #[test]
         fn test_quantum_masking_params_in_range((theta, sigma) in quantum_masking_params()) {
-            prop_assert!(theta >= 0.0 && theta <= std::f64::consts::TAU);
-            prop_assert!(sigma >= 0.0 && sigma <= 1.0);
+            prop_assert!((0.0..=std::f64::consts::TAU).contains(&theta));
+            prop_assert!((0.0..=1.0).contains(&sigma));
         }
 
         #[test]
@@ -161,8 +188,8 @@ mod tests {
 
         #[test]
         fn test_concurrent_scenario_reasonable((threads, ops) in concurrent_scenario()) {
-            prop_assert!(threads >= 1 && threads <= 16);
-            prop_assert!(ops >= 1 && ops <= 100);
+            prop_assert!((1..=16).contains(&threads));
+            prop_assert!((1..=100).contains(&ops));
         }
 
         #[test]
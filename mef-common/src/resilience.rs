@@ -59,6 +59,7 @@ impl Default for CircuitBreakerConfig {
 ///
 /// ```rust
 /// use mef_common::resilience::{CircuitBreaker, CircuitBreakerConfig};
+/// # fn database_query() -> mef_common::MefResult<u32> { Ok(42) }
 ///
 /// let config = CircuitBreakerConfig::default();
 /// let breaker = CircuitBreaker::new("database", config);
@@ -71,7 +72,7 @@ impl Default for CircuitBreakerConfig {
 ///
 /// match result {
 ///     Ok(data) => { /* Success */ },
-///     Err(e) if e.to_string().contains("Circuit breaker is OPEN") => {
+///     Err(e) if e.to_string().contains("is OPEN") => {
 ///         // Circuit is open, service is down
 ///     },
 ///     Err(e) => { /* Other error */ }
@@ -157,6 +158,7 @@ impl CircuitBreaker {
                 );
 
                 if *success_count >= self.config.success_threshold {
+                    drop(success_count); // Release lock before transition
                     self.transition_to_closed();
                 }
             }
@@ -457,6 +459,7 @@ impl Default for RecoveryConfig {
 ///
 /// ```no_run
 /// use mef_common::resilience::{auto_recover, RecoveryConfig};
+/// # async fn fetch_remote_data() -> mef_common::MefResult<Vec<u8>> { Ok(Vec::new()) }
 ///
 /// # async fn example() -> anyhow::Result<()> {
 /// let config = RecoveryConfig::default();
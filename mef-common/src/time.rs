@@ -4,6 +4,7 @@
 //! Eliminates 27+ duplications of timestamp patterns across the codebase.
 
 use anyhow::{Context, Result};
+use rand::Rng;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Get current timestamp in seconds since UNIX epoch
@@ -109,6 +110,30 @@ pub fn has_expired(timestamp: u64, ttl: u64) -> Result<bool> {
     Ok(elapsed >= ttl)
 }
 
+/// Add bounded random jitter to a timestamp
+///
+/// The jitter is drawn uniformly from `0..=max_jitter` and added with
+/// saturating arithmetic, so timestamps near `u64::MAX` clamp instead of
+/// wrapping.
+///
+/// # Example
+///
+/// ```
+/// use mef_common::time::jitter_timestamp;
+///
+/// let now = 1_700_000_000;
+/// let jittered = jitter_timestamp(now, 300, &mut rand::thread_rng());
+/// assert!(jittered >= now && jittered <= now + 300);
+/// ```
+pub fn jitter_timestamp<R: Rng + ?Sized>(now: u64, max_jitter: u64, rng: &mut R) -> u64 {
+    let jitter = if max_jitter == 0 {
+        0
+    } else {
+        rng.gen_range(0..=max_jitter)
+    };
+    now.saturating_add(jitter)
+}
+
 /// Trait extension for adding pipe functionality to types
 trait Pipe: Sized {
     fn pipe<F, R>(self, f: F) -> R
@@ -176,4 +201,28 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("future"));
     }
+
+    #[test]
+    fn test_jitter_timestamp_within_bounds() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let now = 1_700_000_000;
+        for _ in 0..1000 {
+            let jittered = jitter_timestamp(now, 300, &mut rng);
+            assert!((now..=now + 300).contains(&jittered));
+        }
+        assert_eq!(jitter_timestamp(now, 0, &mut rng), now);
+    }
+
+    #[test]
+    fn test_jitter_timestamp_saturates() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert_eq!(jitter_timestamp(u64::MAX, 300, &mut rng), u64::MAX);
+            assert!(jitter_timestamp(u64::MAX - 10, u64::MAX, &mut rng) >= u64::MAX - 10);
+        }
+    }
 }
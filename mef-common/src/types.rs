@@ -2,6 +2,8 @@
 //!
 //! Provides shared types used across the MEF system.
 
+use crate::error::{MefError, MefResult};
+use crate::numeric::validate_finite;
 use serde::{Deserialize, Serialize};
 
 /// Resonance triplet (ψ, ρ, ω) representing spectral signature
@@ -21,6 +23,25 @@ impl ResonanceTriplet {
         Self { psi, rho, omega }
     }
 
+    /// Create a resonance triplet, rejecting NaN or infinite components
+    ///
+    /// # Errors
+    /// Returns a validation error if any component is not finite.
+    pub fn try_new(psi: f64, rho: f64, omega: f64) -> MefResult<Self> {
+        let triplet = Self::new(psi, rho, omega);
+        triplet.validate()?;
+        Ok(triplet)
+    }
+
+    /// Check that all components are finite
+    ///
+    /// # Errors
+    /// Returns a validation error naming the first non-finite component
+    /// (0 = psi, 1 = rho, 2 = omega).
+    pub fn validate(&self) -> MefResult<()> {
+        validate_finite(&[self.psi, self.rho, self.omega])
+    }
+
     /// Create a zero resonance triplet
     pub fn zero() -> Self {
         Self {
@@ -47,8 +68,88 @@ impl ResonanceTriplet {
             omega: self.omega / mag,
         }
     }
+
+    /// Compute the Euclidean distance to another resonance triplet
+    pub fn distance(&self, other: &Self) -> f64 {
+        let dpsi = self.psi - other.psi;
+        let drho = self.rho - other.rho;
+        let domega = self.omega - other.omega;
+        (dpsi * dpsi + drho * drho + domega * domega).sqrt()
+    }
+
+    /// Compute coherence with another resonance triplet
+    ///
+    /// Coherence is `1 / (1 + distance)`: 1.0 for identical states,
+    /// approaching 0.0 as states move apart.
+    pub fn coherence_with(&self, other: &Self) -> f64 {
+        self.coherence_with_kernel(other, CoherenceKernel::Inverse)
+    }
+
+    /// Compute coherence with another resonance triplet using a kernel
+    pub fn coherence_with_kernel(&self, other: &Self, kernel: CoherenceKernel) -> f64 {
+        kernel.apply(self.distance(other))
+    }
+}
+
+/// Mapping from resonance distance to coherence
+///
+/// Every valid kernel (see [`CoherenceKernel::validate`]) yields 1.0 at
+/// distance 0 and decreases monotonically towards 0.0 as the distance grows.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CoherenceKernel {
+    /// `1 / (1 + d)`, range (0, 1]; decays slowly (heavy tail)
+    #[default]
+    Inverse,
+    /// `exp(-d² / 2σ²)`, range (0, 1]; flat near 0, then drops sharply around σ
+    Gaussian {
+        /// Width of the kernel (σ > 0)
+        sigma: f64,
+    },
+    /// `exp(-rate · d)`, range (0, 1]; decays at a constant relative rate
+    Exponential {
+        /// Decay rate (> 0)
+        rate: f64,
+    },
+}
+
+impl CoherenceKernel {
+    /// Check that the kernel parameter is finite and positive
+    ///
+    /// A zero `sigma` yields NaN at distance 0 and a negative `rate` yields
+    /// coherence above 1, so kernels from configuration should be validated
+    /// before use.
+    pub fn validate(&self) -> MefResult<()> {
+        let (name, value) = match *self {
+            Self::Inverse => return Ok(()),
+            Self::Gaussian { sigma } => ("sigma", sigma),
+            Self::Exponential { rate } => ("rate", rate),
+        };
+
+        if !value.is_finite() || value <= 0.0 {
+            return Err(MefError::validation(format!(
+                "Coherence kernel {} must be finite and positive: {}",
+                name, value
+            )));
+        }
+        Ok(())
+    }
+
+    /// Coherence for a given distance
+    pub fn apply(&self, distance: f64) -> f64 {
+        match *self {
+            Self::Inverse => 1.0 / (1.0 + distance),
+            Self::Gaussian { sigma } => (-(distance * distance) / (2.0 * sigma * sigma)).exp(),
+            Self::Exponential { rate } => (-rate * distance).exp(),
+        }
+    }
 }
 
+/// Canonical resonance state shared across crates
+///
+/// Crates with their own `ResonanceState` convert to and from this type
+/// and delegate distance/coherence computations to it.
+pub type ResonanceState = ResonanceTriplet;
+
 impl Default for ResonanceTriplet {
     fn default() -> Self {
         Self::zero()
@@ -159,6 +260,17 @@ pub type Ttl = u64;
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_new_rejects_non_finite() {
+        assert!(ResonanceTriplet::try_new(1.0, -2.0, 0.5).is_ok());
+
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = ResonanceTriplet::try_new(1.0, bad, 0.5).unwrap_err();
+            assert!(err.to_string().contains("component 1"));
+            assert!(ResonanceTriplet::new(bad, 0.0, 0.0).validate().is_err());
+        }
+    }
+
     #[test]
     fn test_resonance_triplet() {
         let triplet = ResonanceTriplet::new(3.0, 4.0, 0.0);
@@ -179,6 +291,64 @@ mod tests {
         assert_eq!(normalized.magnitude(), 0.0);
     }
 
+    #[test]
+    fn test_resonance_triplet_distance_and_coherence() {
+        let a = ResonanceTriplet::new(0.0, 0.0, 0.0);
+        let b = ResonanceTriplet::new(3.0, 4.0, 0.0);
+
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.distance(&b), b.distance(&a));
+        assert_eq!(a.coherence_with(&a), 1.0);
+        assert!((a.coherence_with(&b) - 1.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_coherence_kernels() {
+        let a = ResonanceTriplet::new(0.0, 0.0, 0.0);
+        let b = ResonanceTriplet::new(3.0, 4.0, 0.0);
+
+        let kernels = [
+            CoherenceKernel::Inverse,
+            CoherenceKernel::Gaussian { sigma: 1.0 },
+            CoherenceKernel::Exponential { rate: 0.5 },
+        ];
+        for kernel in kernels {
+            assert_eq!(a.coherence_with_kernel(&a, kernel), 1.0);
+            let c = a.coherence_with_kernel(&b, kernel);
+            assert!(c > 0.0 && c < 1.0, "{kernel:?} out of range: {c}");
+        }
+
+        // Default kernel matches coherence_with
+        assert_eq!(
+            a.coherence_with_kernel(&b, CoherenceKernel::default()),
+            a.coherence_with(&b)
+        );
+
+        // Gaussian decays faster than Inverse at the same distance
+        let gaussian = a.coherence_with_kernel(&b, CoherenceKernel::Gaussian { sigma: 1.0 });
+        assert!(gaussian < a.coherence_with(&b));
+    }
+
+    #[test]
+    fn test_coherence_kernel_validation() {
+        assert!(CoherenceKernel::Inverse.validate().is_ok());
+        assert!(CoherenceKernel::Gaussian { sigma: 1.0 }.validate().is_ok());
+        assert!(CoherenceKernel::Exponential { rate: 0.5 }
+            .validate()
+            .is_ok());
+
+        for sigma in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(CoherenceKernel::Gaussian { sigma }.validate().is_err());
+        }
+        for rate in [0.0, -0.5, f64::NAN, f64::NEG_INFINITY] {
+            assert!(CoherenceKernel::Exponential { rate }.validate().is_err());
+        }
+
+        // The rejected parameters break the kernel contract
+        assert!(CoherenceKernel::Gaussian { sigma: 0.0 }.apply(0.0).is_nan());
+        assert!(CoherenceKernel::Exponential { rate: -0.5 }.apply(1.0) > 1.0);
+    }
+
     #[test]
     fn test_content_hash_hex() {
         let hash = ContentHash::from_bytes([0x42; 32]);
@@ -1,23 +1,50 @@
 //! Audit Trail with Zero-Knowledge Proofs
 
 use anyhow::Result;
+use mef_common::merkle::{MerkleHash, MerkleProof, MerkleTree};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+/// Prefix of every audit leaf, separating it from other trees' leaves
+const AUDIT_LEAF_TAG: &[u8] = b"mef-ephemeral-services/audit-entry";
+
+/// Single recorded audit event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEntry {
+    /// Recording time (Unix seconds)
     pub timestamp: u64,
+    /// Event name
     pub event: String,
+    /// Optional proof attached to the event
     pub proof: Option<Vec<u8>>,
 }
 
+impl AuditEntry {
+    /// Leaf encoding used for the trail's Merkle tree
+    ///
+    /// `AUDIT_LEAF_TAG ‖ timestamp (u64 LE) ‖ event`. The tag keeps an audit
+    /// leaf from being replayed as a leaf of another tree built with
+    /// [`MerkleTree`]; `MerkleTree::build` additionally prefixes leaves with
+    /// `0x00` and internal nodes with `0x01`, so no leaf can pose as a node.
+    pub fn leaf_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(AUDIT_LEAF_TAG.len() + 8 + self.event.len());
+        bytes.extend_from_slice(AUDIT_LEAF_TAG);
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes.extend_from_slice(self.event.as_bytes());
+        bytes
+    }
+}
+
+/// Append-only audit log of a service
+#[derive(Debug)]
 pub struct AuditTrail {
     id: Uuid,
     entries: Vec<AuditEntry>,
 }
 
 impl AuditTrail {
+    /// Create an empty trail for the service `id`
     pub fn new(id: Uuid) -> Self {
         Self {
             id,
@@ -25,6 +52,7 @@ impl AuditTrail {
         }
     }
 
+    /// Append an event, optionally with a proof
     pub fn record_event(&mut self, event: &str, proof: Option<Vec<u8>>) -> Result<()> {
         self.entries.push(AuditEntry {
             timestamp: Self::now(),
@@ -34,15 +62,43 @@ impl AuditTrail {
         Ok(())
     }
 
+    /// ID of the service this trail audits
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Recorded entries, oldest first
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// SHA-256 digest over all entries
     pub fn get_proof(&self) -> Vec<u8> {
         let mut hasher = Sha256::new();
         for entry in &self.entries {
-            hasher.update(&entry.timestamp.to_le_bytes());
+            hasher.update(entry.timestamp.to_le_bytes());
             hasher.update(entry.event.as_bytes());
         }
         hasher.finalize().to_vec()
     }
 
+    /// Merkle root over all entries (None while the trail is empty)
+    pub fn merkle_root(&self) -> Option<MerkleHash> {
+        self.merkle_tree().map(|tree| tree.root())
+    }
+
+    /// Inclusion proof for the entry at `index`
+    ///
+    /// Verify with [`MerkleTree::verify`] against [`merkle_root`](Self::merkle_root)
+    /// and [`AuditEntry::leaf_bytes`].
+    pub fn entry_proof(&self, index: usize) -> Option<MerkleProof> {
+        self.merkle_tree()?.proof(index)
+    }
+
+    fn merkle_tree(&self) -> Option<MerkleTree> {
+        MerkleTree::build(self.entries.iter().map(AuditEntry::leaf_bytes)).ok()
+    }
+
     fn now() -> u64 {
         std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -51,6 +107,46 @@ impl AuditTrail {
     }
 }
 
+/// Audit records that can check an attached proof
 pub trait ProofCarryingAudit {
+    /// Whether `proof` is valid for this record
     fn verify_proof(&self, proof: &[u8]) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_proofs_verify_against_root() {
+        let mut audit = AuditTrail::new(Uuid::new_v4());
+        assert!(audit.merkle_root().is_none());
+
+        for event in ["created", "started", "vote_cast"] {
+            audit.record_event(event, None).unwrap();
+        }
+
+        let root = audit.merkle_root().unwrap();
+        for (i, entry) in audit.entries().iter().enumerate() {
+            let proof = audit.entry_proof(i).unwrap();
+            assert!(MerkleTree::verify(&root, &entry.leaf_bytes(), &proof));
+        }
+        assert!(audit.entry_proof(3).is_none());
+    }
+
+    #[test]
+    fn test_leaf_bytes_are_tagged() {
+        let entry = AuditEntry {
+            timestamp: 42,
+            event: "created".to_string(),
+            proof: None,
+        };
+
+        let leaf = entry.leaf_bytes();
+        assert!(leaf.starts_with(AUDIT_LEAF_TAG));
+        assert_eq!(
+            &leaf[AUDIT_LEAF_TAG.len()..],
+            [&42u64.to_le_bytes()[..], b"created"].concat()
+        );
+    }
+}
@@ -5,20 +5,30 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Resonance bubble parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BubbleConfig {
+    /// Resonance at the bubble's centre
     pub resonance: ResonanceState,
+    /// Resonance radius participants must fall within
     pub radius: f64,
+    /// Bubble lifetime in seconds
     pub duration_seconds: u64,
+    /// Maximum number of participants
     pub max_participants: usize,
 }
 
+/// Bubble state; `Dissolved` is terminal
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BubbleState {
+    /// Bubble accepts participants
     Active,
+    /// Bubble has been released from the field
     Dissolved,
 }
 
+/// Isolated resonance region hosting a service
+#[derive(Debug)]
 pub struct ResonanceBubble {
     id: Uuid,
     config: BubbleConfig,
@@ -27,6 +37,7 @@ pub struct ResonanceBubble {
 }
 
 impl ResonanceBubble {
+    /// Create an active bubble
     pub fn new(id: Uuid, config: BubbleConfig) -> Result<Self> {
         Ok(Self {
             id,
@@ -36,6 +47,31 @@ impl ResonanceBubble {
         })
     }
 
+    /// Bubble ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Bubble parameters
+    pub fn config(&self) -> &BubbleConfig {
+        &self.config
+    }
+
+    /// Creation time (Unix seconds)
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Current bubble state
+    pub fn state(&self) -> BubbleState {
+        self.state
+    }
+
+    /// Dissolve the bubble, releasing it from the field
+    pub fn dissolve(&mut self) {
+        self.state = BubbleState::Dissolved;
+    }
+
     fn now() -> u64 {
         std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -0,0 +1,287 @@
+//! Ephemeral Escrow
+//!
+//! Time-boxed escrow that locks funds until released to the seller or,
+//! once its deadline passes, automatically refunded to the buyer.
+
+use super::audit_trail::{AuditEntry, AuditTrail};
+use super::reaper::Reapable;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use uuid::Uuid;
+use zeroize::Zeroize;
+
+/// Escrow state; `Released` and `Refunded` are terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EscrowStatus {
+    /// Funds held until release, refund or deadline
+    Locked,
+    /// Funds paid out to the seller
+    Released,
+    /// Funds returned to the buyer
+    Refunded,
+}
+
+/// Escrow between a seller and a buyer with a refund deadline
+///
+/// Participant credentials are wiped on drop and omitted from `Debug`.
+pub struct EphemeralEscrow {
+    id: Uuid,
+    seller: Vec<u8>,
+    buyer: Vec<u8>,
+    amount: u64,
+    status: EscrowStatus,
+    created_at: u64,
+    deadline: u64,
+    audit: AuditTrail,
+}
+
+impl EphemeralEscrow {
+    /// Lock `amount` between seller and buyer for at most `timeout_seconds`
+    pub fn new(seller: Vec<u8>, buyer: Vec<u8>, amount: u64, timeout_seconds: u64) -> Self {
+        let id = Uuid::new_v4();
+        let created_at = Self::now();
+        let mut audit = AuditTrail::new(id);
+        let _ = audit.record_event("escrow_locked", None);
+
+        Self {
+            id,
+            seller,
+            buyer,
+            amount,
+            status: EscrowStatus::Locked,
+            created_at,
+            deadline: created_at.saturating_add(timeout_seconds),
+            audit,
+        }
+    }
+
+    /// Escrow ID (also the audit trail's service ID)
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Locked amount
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Current status
+    pub fn status(&self) -> EscrowStatus {
+        self.status
+    }
+
+    /// Creation time (Unix seconds)
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Refund deadline (Unix seconds)
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Whether the refund deadline has been reached
+    pub fn is_past_deadline(&self) -> bool {
+        Self::now() >= self.deadline
+    }
+
+    /// Audit events recorded so far, oldest first
+    pub fn audit_entries(&self) -> &[AuditEntry] {
+        self.audit.entries()
+    }
+
+    /// Release funds to the seller (only while locked and before the deadline)
+    pub fn release(&mut self) -> Result<()> {
+        self.ensure_locked()?;
+        if self.is_past_deadline() {
+            anyhow::bail!("Escrow deadline passed; funds are due for refund");
+        }
+
+        self.status = EscrowStatus::Released;
+        self.audit.record_event("escrow_released", None)
+    }
+
+    /// Refund funds to the buyer (only while locked)
+    pub fn refund(&mut self) -> Result<()> {
+        self.ensure_locked()?;
+
+        self.status = EscrowStatus::Refunded;
+        self.audit.record_event("escrow_refunded", None)
+    }
+
+    /// Refund automatically once the deadline has passed
+    ///
+    /// Returns `Ok(false)` while the deadline is still ahead, `Ok(true)` when
+    /// the escrow was refunded by this call.
+    pub fn auto_refund(&mut self) -> Result<bool> {
+        self.ensure_locked()?;
+        if !self.is_past_deadline() {
+            return Ok(false);
+        }
+
+        self.status = EscrowStatus::Refunded;
+        self.audit.record_event("escrow_auto_refunded", None)?;
+        Ok(true)
+    }
+
+    fn ensure_locked(&self) -> Result<()> {
+        if self.status != EscrowStatus::Locked {
+            anyhow::bail!("Escrow not in locked state: {:?}", self.status);
+        }
+        Ok(())
+    }
+
+    fn now() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+}
+
+impl std::fmt::Debug for EphemeralEscrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EphemeralEscrow")
+            .field("id", &self.id)
+            .field("amount", &self.amount)
+            .field("status", &self.status)
+            .field("created_at", &self.created_at)
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for EphemeralEscrow {
+    fn drop(&mut self) {
+        // Wipe participant credentials
+        self.seller.zeroize();
+        self.buyer.zeroize();
+    }
+}
+
+impl Reapable for RwLock<EphemeralEscrow> {
+    fn reap(&self) -> Result<bool> {
+        let mut escrow = self
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on escrow: {}", e))?;
+        if escrow.status() != EscrowStatus::Locked {
+            return Ok(false);
+        }
+        escrow.auto_refund()
+    }
+
+    fn is_terminal(&self) -> Result<bool> {
+        let escrow = self
+            .read()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on escrow: {}", e))?;
+        Ok(escrow.status() != EscrowStatus::Locked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn escrow(timeout_seconds: u64) -> EphemeralEscrow {
+        EphemeralEscrow::new(b"seller".to_vec(), b"buyer".to_vec(), 100, timeout_seconds)
+    }
+
+    #[test]
+    fn test_release_before_deadline() {
+        let mut escrow = escrow(300);
+
+        escrow.release().unwrap();
+        assert_eq!(escrow.status(), EscrowStatus::Released);
+        assert!(escrow.auto_refund().is_err());
+    }
+
+    #[test]
+    fn test_debug_omits_credentials() {
+        let escrow = EphemeralEscrow::new(b"s3cret".to_vec(), b"hunter2".to_vec(), 100, 300);
+        let debug = format!("{:?}", escrow);
+
+        assert!(debug.contains("amount: 100"));
+        assert!(!debug.contains("seller") && !debug.contains("buyer"));
+    }
+
+    #[test]
+    fn test_auto_refund_waits_for_deadline() {
+        let mut escrow = escrow(300);
+
+        assert!(!escrow.auto_refund().unwrap());
+        assert_eq!(escrow.status(), EscrowStatus::Locked);
+    }
+
+    #[test]
+    fn test_auto_refund_after_deadline() {
+        let mut escrow = escrow(0);
+
+        assert!(escrow.auto_refund().unwrap());
+        assert_eq!(escrow.status(), EscrowStatus::Refunded);
+        assert!(escrow
+            .audit_entries()
+            .iter()
+            .any(|e| e.event == "escrow_auto_refunded"));
+
+        // Funds cannot be released after the refund
+        assert!(escrow.release().is_err());
+    }
+
+    #[test]
+    fn test_released_escrow_cannot_be_refunded() {
+        let mut escrow = escrow(300);
+        escrow.release().unwrap();
+
+        assert!(escrow.refund().is_err());
+        assert!(escrow.auto_refund().is_err());
+        assert_eq!(escrow.status(), EscrowStatus::Released);
+        assert!(!escrow
+            .audit_entries()
+            .iter()
+            .any(|e| e.event.contains("refunded")));
+    }
+
+    #[test]
+    fn test_release_rejected_after_deadline() {
+        let mut escrow = escrow(0);
+
+        assert!(escrow.release().is_err());
+        assert_eq!(escrow.status(), EscrowStatus::Locked);
+    }
+
+    #[tokio::test]
+    async fn test_reaper_auto_refunds() {
+        let escrow = Arc::new(RwLock::new(escrow(0)));
+
+        let handle = crate::reaper::spawn_reaper(escrow.clone(), Duration::from_millis(10));
+        tokio::time::timeout(Duration::from_secs(2), handle)
+            .await
+            .expect("reaper should stop after refunding")
+            .unwrap();
+
+        assert_eq!(escrow.read().unwrap().status(), EscrowStatus::Refunded);
+    }
+
+    #[tokio::test]
+    async fn test_poisoned_escrow_is_reported_and_stops_reaper() {
+        let escrow = Arc::new(RwLock::new(escrow(0)));
+        let poisoner = escrow.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.write().unwrap();
+            panic!("poison the escrow lock");
+        })
+        .join();
+
+        assert!(escrow.reap().is_err());
+        assert!(escrow.is_terminal().is_err());
+
+        let handle = crate::reaper::spawn_reaper(escrow, Duration::from_millis(10));
+        tokio::time::timeout(Duration::from_secs(2), handle)
+            .await
+            .expect("reaper should stop on an unreadable escrow")
+            .unwrap();
+    }
+}
@@ -30,17 +30,25 @@
 
 pub mod audit_trail;
 pub mod bubble;
+pub mod escrow;
 pub mod lifecycle;
+pub mod reaper;
 pub mod service_registry;
 
 pub use audit_trail::{AuditEntry, AuditTrail, ProofCarryingAudit};
 pub use bubble::{BubbleConfig, BubbleState, ResonanceBubble};
+pub use escrow::{EphemeralEscrow, EscrowStatus};
 pub use lifecycle::{LifecycleEvent, LifecycleManager, LifecycleState};
+pub use reaper::{spawn_reaper, Reapable};
 pub use service_registry::{ServiceDescriptor, ServiceRegistry, ServiceType};
 
 use anyhow::Result;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 /// Resonance state (re-exported for convenience)
@@ -59,9 +67,57 @@ impl ResonanceState {
     pub fn new(psi: f64, rho: f64, omega: f64) -> Self {
         Self { psi, rho, omega }
     }
+
+    /// Calculate Euclidean distance to another state
+    pub fn distance(&self, other: &ResonanceState) -> f64 {
+        mef_common::ResonanceState::from(*self).distance(&(*other).into())
+    }
+
+    /// Calculate coherence with another state
+    pub fn coherence_with(&self, other: &ResonanceState) -> f64 {
+        mef_common::ResonanceState::from(*self).coherence_with(&(*other).into())
+    }
+}
+
+impl From<mef_common::ResonanceState> for ResonanceState {
+    fn from(state: mef_common::ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
+    }
+}
+
+impl From<ResonanceState> for mef_common::ResonanceState {
+    fn from(state: ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
+    }
+}
+
+/// Deterministic generator for service IDs
+///
+/// Produces the same sequence of IDs for the same seed, so tests and audit
+/// trails can be reproduced.
+#[derive(Debug)]
+pub struct ServiceIdGenerator {
+    rng: ChaCha20Rng,
+}
+
+impl ServiceIdGenerator {
+    /// Create a generator from a seed
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: ChaCha20Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Next ID in the sequence
+    pub fn next_id(&mut self) -> Uuid {
+        let mut bytes = [0u8; 16];
+        self.rng.fill_bytes(&mut bytes);
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
 }
 
 /// Ephemeral Service - High-level interface
+#[derive(Debug)]
 pub struct EphemeralService {
     /// Service ID (ephemeral)
     pub id: Uuid,
@@ -86,8 +142,18 @@ impl EphemeralService {
         resonance: ResonanceState,
         duration_seconds: u64,
     ) -> Result<Self> {
-        let id = Uuid::new_v4();
+        Self::new_with_id(Uuid::new_v4(), service_type, resonance, duration_seconds)
+    }
 
+    /// Create new ephemeral service with an explicit ID
+    ///
+    /// Use with [`ServiceIdGenerator`] for reproducible IDs.
+    pub fn new_with_id(
+        id: Uuid,
+        service_type: ServiceType,
+        resonance: ResonanceState,
+        duration_seconds: u64,
+    ) -> Result<Self> {
         let descriptor = ServiceDescriptor {
             id,
             service_type,
@@ -155,6 +221,31 @@ impl EphemeralService {
         audit.get_proof()
     }
 
+    /// Expire the service if it is past its TTL
+    ///
+    /// Transitions the lifecycle to `Expired`, dissolves the bubble and
+    /// records a `service_expired` audit event. Returns `true` if the
+    /// service was reaped by this call.
+    pub fn reap_if_expired(&self) -> Result<bool> {
+        self.reaper_handle().reap()
+    }
+
+    /// Spawn a background task that reaps the service once its TTL passes
+    ///
+    /// The task checks every `interval` and exits after the service reaches
+    /// a terminal state. Call `abort()` on the handle to cancel it.
+    pub fn spawn_reaper(&self, interval: Duration) -> JoinHandle<()> {
+        spawn_reaper(Arc::new(self.reaper_handle()), interval)
+    }
+
+    fn reaper_handle(&self) -> ServiceReaper {
+        ServiceReaper {
+            lifecycle: self.lifecycle.clone(),
+            bubble: self.bubble.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+
     fn current_timestamp() -> u64 {
         std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -163,6 +254,50 @@ impl EphemeralService {
     }
 }
 
+/// Shared service state handed to the background reaper
+struct ServiceReaper {
+    lifecycle: Arc<std::sync::RwLock<LifecycleManager>>,
+    bubble: Arc<std::sync::RwLock<ResonanceBubble>>,
+    audit: Arc<std::sync::RwLock<AuditTrail>>,
+}
+
+impl Reapable for ServiceReaper {
+    fn reap(&self) -> Result<bool> {
+        let mut lifecycle = self
+            .lifecycle
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on lifecycle: {}", e))?;
+        if !lifecycle.is_past_ttl() {
+            return Ok(false);
+        }
+        lifecycle.expire()?;
+
+        self.bubble
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on bubble: {}", e))?
+            .dissolve();
+
+        let mut audit = self
+            .audit
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on audit: {}", e))?;
+        audit.record_event("service_expired", None)?;
+
+        Ok(true)
+    }
+
+    fn is_terminal(&self) -> Result<bool> {
+        let lifecycle = self
+            .lifecycle
+            .read()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on lifecycle: {}", e))?;
+        Ok(matches!(
+            lifecycle.state(),
+            LifecycleState::Stopped | LifecycleState::Expired
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,6 +311,36 @@ mod tests {
         assert!(!service.is_active());
     }
 
+    #[test]
+    fn test_resonance_state_matches_canonical() {
+        let a = ResonanceState::new(0.5, -1.0, 2.0);
+        let b = ResonanceState::new(1.5, 0.0, -0.5);
+
+        // Round-trip through the canonical type is lossless
+        let canonical: mef_common::ResonanceState = a.into();
+        assert_eq!(ResonanceState::from(canonical), a);
+
+        // Coherence agrees with the canonical formula
+        let expected = canonical.coherence_with(&b.into());
+        assert_eq!(a.coherence_with(&b), expected);
+    }
+
+    #[test]
+    fn test_service_with_explicit_id() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let id = ServiceIdGenerator::seeded(7).next_id();
+        assert_eq!(id, ServiceIdGenerator::seeded(7).next_id());
+
+        let a = EphemeralService::new_with_id(id, ServiceType::Voting, resonance, 300).unwrap();
+        let b = EphemeralService::new_with_id(id, ServiceType::Voting, resonance, 300).unwrap();
+
+        assert_eq!(a.id, id);
+        assert_eq!(a.descriptor.id, b.descriptor.id);
+        assert_eq!(a.descriptor.service_type, b.descriptor.service_type);
+        assert_eq!(a.descriptor.resonance, b.descriptor.resonance);
+        assert_eq!(a.audit.read().unwrap().id(), b.audit.read().unwrap().id());
+    }
+
     #[test]
     fn test_service_lifecycle() {
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
@@ -199,4 +364,59 @@ mod tests {
         let proof = service.get_audit_proof();
         assert!(!proof.is_empty());
     }
+
+    #[test]
+    fn test_reap_before_ttl_is_noop() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let service = EphemeralService::new(ServiceType::Messaging, resonance, 300).unwrap();
+
+        service.start().unwrap();
+        assert!(!service.reap_if_expired().unwrap());
+        assert!(service.is_active());
+    }
+
+    #[tokio::test]
+    async fn test_reaper_expires_service() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let service = EphemeralService::new(ServiceType::Messaging, resonance, 1).unwrap();
+        service.start().unwrap();
+
+        let handle = service.spawn_reaper(Duration::from_millis(20));
+
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("reaper should finish once the service expires")
+            .unwrap();
+
+        assert_eq!(
+            service.lifecycle.read().unwrap().state(),
+            LifecycleState::Expired
+        );
+        assert_eq!(
+            service.bubble.read().unwrap().state(),
+            BubbleState::Dissolved
+        );
+
+        let audit = service.audit.read().unwrap();
+        let expired_events = audit
+            .entries()
+            .iter()
+            .filter(|e| e.event == "service_expired")
+            .count();
+        assert_eq!(expired_events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reaper_is_cancellable() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let service = EphemeralService::new(ServiceType::Voting, resonance, 300).unwrap();
+        service.start().unwrap();
+
+        let handle = service.spawn_reaper(Duration::from_millis(10));
+        handle.abort();
+
+        let err = handle.await.unwrap_err();
+        assert!(err.is_cancelled());
+        assert!(service.is_active());
+    }
 }
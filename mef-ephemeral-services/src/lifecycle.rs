@@ -2,54 +2,119 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{channel, Receiver, Sender};
 use uuid::Uuid;
 
+/// Service lifecycle state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LifecycleState {
+    /// Created but not yet started
     Created,
+    /// Running
     Active,
+    /// Stopped explicitly
     Stopped,
+    /// Outlived its duration
     Expired,
 }
 
+/// State transition sent to subscribers
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LifecycleEvent {
+    /// Transition time (Unix seconds)
     pub timestamp: u64,
+    /// State entered
     pub state: LifecycleState,
 }
 
+/// Tracks a service's lifecycle and notifies subscribers
+#[derive(Debug)]
 pub struct LifecycleManager {
     id: Uuid,
     state: LifecycleState,
     duration: u64,
     started_at: Option<u64>,
+    subscribers: Vec<Sender<LifecycleEvent>>,
 }
 
 impl LifecycleManager {
+    /// Create a manager for service `id` that lives `duration` seconds once started
     pub fn new(id: Uuid, duration: u64) -> Self {
         Self {
             id,
             state: LifecycleState::Created,
             duration,
             started_at: None,
+            subscribers: Vec::new(),
         }
     }
 
+    /// Service ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Subscribe to state transitions
+    ///
+    /// Every subsequent transition is sent to the returned receiver.
+    /// Dropped receivers are pruned on the next transition.
+    pub fn subscribe(&mut self) -> Receiver<LifecycleEvent> {
+        let (tx, rx) = channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Start the service and its TTL clock
     pub fn start(&mut self) -> Result<()> {
-        self.state = LifecycleState::Active;
         self.started_at = Some(Self::now());
+        self.transition(LifecycleState::Active);
         Ok(())
     }
 
+    /// Stop the service
     pub fn stop(&mut self) -> Result<()> {
-        self.state = LifecycleState::Stopped;
+        self.transition(LifecycleState::Stopped);
         Ok(())
     }
 
+    /// Whether the service is running
     pub fn is_active(&self) -> bool {
         self.state == LifecycleState::Active
     }
 
+    /// Current lifecycle state
+    pub fn state(&self) -> LifecycleState {
+        self.state
+    }
+
+    /// Whether an active service has outlived its duration
+    pub fn is_past_ttl(&self) -> bool {
+        match (self.state, self.started_at) {
+            (LifecycleState::Active, Some(started_at)) => {
+                Self::now() >= started_at.saturating_add(self.duration)
+            }
+            _ => false,
+        }
+    }
+
+    /// Transition an active service to `Expired`
+    pub fn expire(&mut self) -> Result<()> {
+        if self.state != LifecycleState::Active {
+            anyhow::bail!("Cannot expire service in state {:?}", self.state);
+        }
+        self.transition(LifecycleState::Expired);
+        Ok(())
+    }
+
+    fn transition(&mut self, state: LifecycleState) {
+        self.state = state;
+        let event = LifecycleEvent {
+            timestamp: Self::now(),
+            state,
+        };
+        self.subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
     fn now() -> u64 {
         std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -57,3 +122,26 @@ impl LifecycleManager {
             .as_secs()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_receives_transitions_in_order() {
+        let mut lifecycle = LifecycleManager::new(Uuid::new_v4(), 60);
+        let rx = lifecycle.subscribe();
+        let dropped = lifecycle.subscribe();
+        drop(dropped);
+
+        lifecycle.start().unwrap();
+        lifecycle.stop().unwrap();
+
+        let states: Vec<_> = rx.try_iter().map(|event| event.state).collect();
+        assert_eq!(
+            states,
+            vec![LifecycleState::Active, LifecycleState::Stopped]
+        );
+        assert_eq!(lifecycle.subscribers.len(), 1);
+    }
+}
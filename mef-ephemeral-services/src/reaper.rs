@@ -0,0 +1,37 @@
+//! Background Reaper
+//!
+//! Periodically drives expiry of ephemeral resources so they dissolve on
+//! their own instead of relying on manual cleanup.
+
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Resource with a deadline that a reaper can enforce
+pub trait Reapable: Send + Sync {
+    /// Apply the expiry transition if the deadline has passed.
+    /// Returns `true` if a transition happened on this call.
+    fn reap(&self) -> Result<bool>;
+
+    /// Whether the resource reached a terminal state (nothing left to reap)
+    fn is_terminal(&self) -> Result<bool>;
+}
+
+/// Spawn a task that calls [`Reapable::reap`] every `interval`.
+///
+/// The task exits once the target is terminal or its state can no longer
+/// be read; abort the returned handle to cancel it earlier.
+pub fn spawn_reaper(target: Arc<dyn Reapable>, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if !matches!(target.is_terminal(), Ok(false)) {
+                break;
+            }
+            // A failed reap is retried on the next tick
+            let _ = target.reap();
+        }
+    })
+}
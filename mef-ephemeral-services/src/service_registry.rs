@@ -1,23 +1,170 @@
 //! Service Registry
 
 use super::ResonanceState;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Kind of ephemeral service
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ServiceType {
+    /// Ballot collection
     Voting,
+    /// Listing and trading of goods
     Marketplace,
+    /// Ephemeral chat
     Messaging,
+    /// Bidding on a single lot
     Auction,
+    /// User-defined service kind, identified by its tag
+    Custom(String),
 }
 
+impl ServiceType {
+    /// Create a custom service type with the given tag
+    pub fn custom(tag: impl Into<String>) -> Self {
+        Self::Custom(tag.into())
+    }
+
+    /// Tag of a custom service type (`None` for built-in types)
+    pub fn custom_tag(&self) -> Option<&str> {
+        match self {
+            Self::Custom(tag) => Some(tag),
+            _ => None,
+        }
+    }
+}
+
+/// Registry entry describing a service
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceDescriptor {
+    /// Service ID
     pub id: Uuid,
+    /// Kind of service
     pub service_type: ServiceType,
+    /// Resonance the service is reachable at
     pub resonance: ResonanceState,
+    /// Creation time (Unix seconds)
     pub created_at: u64,
 }
 
-pub struct ServiceRegistry;
+/// Registry of live service descriptors, keyed by service ID
+#[derive(Debug, Default)]
+pub struct ServiceRegistry {
+    services: HashMap<Uuid, ServiceDescriptor>,
+}
+
+impl ServiceRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a service
+    ///
+    /// Fails if the ID is already registered or a custom tag is blank.
+    pub fn register(&mut self, descriptor: ServiceDescriptor) -> Result<()> {
+        if let ServiceType::Custom(tag) = &descriptor.service_type {
+            if tag.trim().is_empty() {
+                anyhow::bail!("Custom service tag must not be empty");
+            }
+        }
+        if self.services.contains_key(&descriptor.id) {
+            anyhow::bail!("Service already registered: {}", descriptor.id);
+        }
+        self.services.insert(descriptor.id, descriptor);
+        Ok(())
+    }
+
+    /// Remove a service, returning its descriptor if it was registered
+    pub fn unregister(&mut self, id: Uuid) -> Option<ServiceDescriptor> {
+        self.services.remove(&id)
+    }
+
+    /// Look up a service by ID
+    pub fn get(&self, id: Uuid) -> Option<&ServiceDescriptor> {
+        self.services.get(&id)
+    }
+
+    /// Find services of the given type (custom types match by tag)
+    pub fn find_by_type(&self, service_type: &ServiceType) -> Vec<&ServiceDescriptor> {
+        self.services
+            .values()
+            .filter(|d| &d.service_type == service_type)
+            .collect()
+    }
+
+    /// Number of registered services
+    pub fn len(&self) -> usize {
+        self.services.len()
+    }
+
+    /// Whether no services are registered
+    pub fn is_empty(&self) -> bool {
+        self.services.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptor(service_type: ServiceType) -> ServiceDescriptor {
+        ServiceDescriptor {
+            id: Uuid::new_v4(),
+            service_type,
+            resonance: ResonanceState::new(1.0, 1.0, 1.0),
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_find_custom_service_by_type() {
+        let mut registry = ServiceRegistry::new();
+        let auction = descriptor(ServiceType::custom("auction"));
+        let auction_id = auction.id;
+
+        registry.register(auction).unwrap();
+        registry.register(descriptor(ServiceType::Voting)).unwrap();
+
+        let found = registry.find_by_type(&ServiceType::Custom("auction".to_string()));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, auction_id);
+        assert_eq!(found[0].service_type.custom_tag(), Some("auction"));
+    }
+
+    #[test]
+    fn test_custom_service_does_not_match_builtin_types() {
+        let mut registry = ServiceRegistry::new();
+        registry
+            .register(descriptor(ServiceType::custom("auction")))
+            .unwrap();
+
+        // Built-in Auction is distinct from Custom("auction")
+        assert!(registry.find_by_type(&ServiceType::Auction).is_empty());
+        assert!(registry.find_by_type(&ServiceType::Marketplace).is_empty());
+        assert!(registry
+            .find_by_type(&ServiceType::custom("lottery"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_custom_tag_serialization_roundtrip() {
+        let original = descriptor(ServiceType::custom("auction"));
+
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: ServiceDescriptor = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.service_type, ServiceType::custom("auction"));
+        assert_eq!(restored.id, original.id);
+    }
+
+    #[test]
+    fn test_empty_custom_tag_rejected() {
+        let mut registry = ServiceRegistry::new();
+        assert!(registry
+            .register(descriptor(ServiceType::custom("  ")))
+            .is_err());
+    }
+}
@@ -0,0 +1,50 @@
+/*!
+ * Performance Benchmarks - Fork Resolution
+ *
+ * Benchmarks MEF-Attractor fork resolution over growing candidate sets.
+ * Run with `--features parallel` to compare against parallel scoring.
+ */
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use mef_fork_healing::{AttractorConfig, Block, MandorlaAttractor, ResonanceState};
+use uuid::Uuid;
+
+fn candidates(count: usize) -> Vec<Block> {
+    (0..count)
+        .map(|i| {
+            let offset = i as f64 / count as f64;
+            Block {
+                id: Uuid::from_u128(i as u128 + 1),
+                height: 10,
+                resonance: ResonanceState::new(1.0 + offset, 1.0 - offset, 1.0),
+                prev_hash: vec![0; 32],
+                data: vec![0u8; 256],
+                timestamp: i as u64,
+                signature: None,
+            }
+        })
+        .collect()
+}
+
+// Benchmark fork resolution for increasing candidate counts
+fn bench_resolve_fork(c: &mut Criterion) {
+    let attractor = MandorlaAttractor::new(AttractorConfig::default());
+    let field = ResonanceState::new(1.0, 1.0, 1.0);
+    let mut group = c.benchmark_group("resolve_fork");
+
+    for count in [10, 100, 1_000, 10_000] {
+        let blocks = candidates(count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &blocks, |b, blocks| {
+            b.iter(|| {
+                attractor
+                    .resolve_fork(black_box(blocks.clone()), black_box(field))
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_resolve_fork);
+criterion_main!(benches);
@@ -2,12 +2,29 @@
 
 use super::{Block, ResonanceState};
 use anyhow::Result;
+use ed25519_dalek::VerifyingKey;
 use serde::{Deserialize, Serialize};
 
+/// Candidate count from which scoring runs in parallel
+#[cfg(feature = "parallel")]
+const PARALLEL_SCORING_THRESHOLD: usize = 16;
+
+/// Fork resolution weights and candidate filters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttractorConfig {
+    /// Weight of the resonance coherence in the total score
     pub coherence_weight: f64,
+    /// Weight of the timestamp score in the total score
     pub timestamp_weight: f64,
+    /// Candidates with coherence below this floor are never selected
+    #[serde(default)]
+    pub min_coherence: f64,
+    /// Discard candidates without a valid signature from a trusted signer
+    #[serde(default)]
+    pub require_signatures: bool,
+    /// Ed25519 public keys accepted as block signers
+    #[serde(default)]
+    pub trusted_signers: Vec<[u8; 32]>,
 }
 
 impl Default for AttractorConfig {
@@ -15,27 +32,56 @@ impl Default for AttractorConfig {
         Self {
             coherence_weight: 0.8,
             timestamp_weight: 0.2,
+            min_coherence: 0.0,
+            require_signatures: false,
+            trusted_signers: Vec::new(),
         }
     }
 }
 
+/// Score of a single fork candidate
 #[derive(Debug, Clone)]
 pub struct CoherenceScore {
+    /// Scored block
     pub block_id: uuid::Uuid,
+    /// Coherence with the field resonance
     pub coherence: f64,
+    /// Timestamp score (earlier blocks score higher)
     pub timestamp_score: f64,
+    /// Weighted total
     pub total_score: f64,
 }
 
+/// Selects the strongest attractor among competing blocks
+#[derive(Debug)]
 pub struct MandorlaAttractor {
     config: AttractorConfig,
 }
 
 impl MandorlaAttractor {
+    /// Create an attractor with the given configuration
     pub fn new(config: AttractorConfig) -> Self {
         Self { config }
     }
 
+    /// Attractor configuration
+    pub fn config(&self) -> &AttractorConfig {
+        &self.config
+    }
+
+    /// Select the winning block among fork candidates
+    ///
+    /// The candidate with the highest total score wins. Exact score ties are
+    /// broken by the lexicographically smallest block hash, so the result
+    /// does not depend on the order of `candidates`.
+    ///
+    /// When `require_signatures` is set, unsigned candidates and candidates
+    /// not signed by a trusted signer are discarded before scoring.
+    ///
+    /// Fails if no candidate reaches `min_coherence`.
+    ///
+    /// With the `parallel` feature, large candidate sets are scored
+    /// concurrently; the winner is identical to sequential scoring.
     pub fn resolve_fork(
         &self,
         candidates: Vec<Block>,
@@ -45,30 +91,135 @@ impl MandorlaAttractor {
             anyhow::bail!("No candidates for fork resolution");
         }
 
-        let scores: Vec<CoherenceScore> = candidates
-            .iter()
-            .map(|block| {
-                let coherence = block.compute_coherence(&field_resonance);
-                let timestamp_score = 1.0 / (1.0 + block.timestamp as f64 / 1000.0);
-                let total_score = self.config.coherence_weight * coherence
-                    + self.config.timestamp_weight * timestamp_score;
-
-                CoherenceScore {
-                    block_id: block.id,
-                    coherence,
-                    timestamp_score,
-                    total_score,
-                }
-            })
-            .collect();
+        let candidates = if self.config.require_signatures {
+            let signers: Vec<VerifyingKey> = self
+                .config
+                .trusted_signers
+                .iter()
+                .filter_map(|key| VerifyingKey::from_bytes(key).ok())
+                .collect();
+
+            let signed: Vec<Block> = candidates
+                .into_iter()
+                .filter(|block| signers.iter().any(|key| block.verify_signature(key)))
+                .collect();
+
+            if signed.is_empty() {
+                anyhow::bail!("No fork candidates with a valid trusted signature");
+            }
+            signed
+        } else {
+            candidates
+        };
+
+        let (scores, hashes) = self.score_candidates(&candidates, &field_resonance);
 
         let winner_idx = scores
             .iter()
+            .zip(&hashes)
             .enumerate()
-            .max_by(|(_, a), (_, b)| a.total_score.partial_cmp(&b.total_score).unwrap())
+            .filter(|(_, (score, _))| score.coherence >= self.config.min_coherence)
+            .max_by(|(_, (a, a_hash)), (_, (b, b_hash))| {
+                a.total_score
+                    .total_cmp(&b.total_score)
+                    .then_with(|| b_hash.cmp(a_hash))
+            })
             .map(|(idx, _)| idx)
-            .unwrap();
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No viable fork candidate: all {} candidates below min coherence {}",
+                    candidates.len(),
+                    self.config.min_coherence
+                )
+            })?;
 
         Ok(candidates[winner_idx].clone())
     }
+
+    /// Score every candidate against the field, keeping candidate order
+    fn score_candidates(
+        &self,
+        candidates: &[Block],
+        field_resonance: &ResonanceState,
+    ) -> (Vec<CoherenceScore>, Vec<Vec<u8>>) {
+        #[cfg(feature = "parallel")]
+        if candidates.len() >= PARALLEL_SCORING_THRESHOLD {
+            use rayon::prelude::*;
+
+            return candidates
+                .par_iter()
+                .map(|block| (self.score(block, field_resonance), block.hash()))
+                .unzip();
+        }
+
+        candidates
+            .iter()
+            .map(|block| (self.score(block, field_resonance), block.hash()))
+            .unzip()
+    }
+
+    /// Score a single candidate
+    fn score(&self, block: &Block, field_resonance: &ResonanceState) -> CoherenceScore {
+        let coherence = block.compute_coherence(field_resonance);
+        let timestamp_score = 1.0 / (1.0 + block.timestamp as f64 / 1000.0);
+        let total_score = self.config.coherence_weight * coherence
+            + self.config.timestamp_weight * timestamp_score;
+
+        CoherenceScore {
+            block_id: block.id,
+            coherence,
+            timestamp_score,
+            total_score,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+
+    fn candidates(count: usize) -> Vec<Block> {
+        (0..count)
+            .map(|i| {
+                // Pairs of candidates share resonance, forcing hash tie-breaks
+                let offset = (i / 2) as f64 * 0.05;
+                Block {
+                    id: uuid::Uuid::from_u128(i as u128 + 1),
+                    height: 10,
+                    resonance: ResonanceState::new(1.0 + offset, 1.0 - offset, 1.0),
+                    prev_hash: vec![0; 32],
+                    data: vec![i as u8],
+                    timestamp: 0,
+                    signature: None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_scoring_matches_sequential() {
+        let attractor = MandorlaAttractor::new(AttractorConfig::default());
+        let field = ResonanceState::new(1.1, 0.9, 1.0);
+        let blocks = candidates(64);
+        assert!(blocks.len() >= PARALLEL_SCORING_THRESHOLD);
+
+        let (parallel, parallel_hashes) = attractor.score_candidates(&blocks, &field);
+        let (sequential, sequential_hashes): (Vec<_>, Vec<_>) = blocks
+            .iter()
+            .map(|block| (attractor.score(block, &field), block.hash()))
+            .unzip();
+
+        assert_eq!(parallel_hashes, sequential_hashes);
+        for (p, s) in parallel.iter().zip(&sequential) {
+            assert_eq!(p.block_id, s.block_id);
+            assert_eq!(p.total_score, s.total_score);
+        }
+
+        // Resolution does not depend on candidate order either
+        let winner = attractor.resolve_fork(blocks.clone(), field).unwrap();
+        let mut reversed = blocks;
+        reversed.reverse();
+        let reversed_winner = attractor.resolve_fork(reversed, field).unwrap();
+        assert_eq!(winner.id, reversed_winner.id);
+    }
 }
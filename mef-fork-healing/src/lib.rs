@@ -29,6 +29,7 @@ pub use attractor::{AttractorConfig, CoherenceScore, MandorlaAttractor};
 pub use multiversum::{ForkCandidate, ForkResolution, Multiversum};
 
 use anyhow::Result;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
@@ -36,58 +37,117 @@ use uuid::Uuid;
 /// Resonance state
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ResonanceState {
+    /// Psi component
     pub psi: f64,
+    /// Rho component
     pub rho: f64,
+    /// Omega component
     pub omega: f64,
 }
 
 impl ResonanceState {
+    /// Create a resonance state
     pub fn new(psi: f64, rho: f64, omega: f64) -> Self {
         Self { psi, rho, omega }
     }
 
+    /// Coherence with another state (1.0 = identical)
     pub fn coherence_with(&self, other: &ResonanceState) -> f64 {
-        let dpsi = self.psi - other.psi;
-        let drho = self.rho - other.rho;
-        let domega = self.omega - other.omega;
-        let distance = (dpsi * dpsi + drho * drho + domega * domega).sqrt();
-        1.0 / (1.0 + distance)
+        mef_common::ResonanceState::from(*self).coherence_with(&(*other).into())
+    }
+}
+
+impl From<mef_common::ResonanceState> for ResonanceState {
+    fn from(state: mef_common::ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
+    }
+}
+
+impl From<ResonanceState> for mef_common::ResonanceState {
+    fn from(state: ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
     }
 }
 
 /// Block representation (simplified)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
+    /// Block ID
     pub id: Uuid,
+    /// Chain height
     pub height: u64,
+    /// Block resonance
     pub resonance: ResonanceState,
+    /// Hash of the parent block
     pub prev_hash: Vec<u8>,
+    /// Block payload
     pub data: Vec<u8>,
+    /// Creation time (Unix seconds)
     pub timestamp: u64,
+    /// Ed25519 signature over [`signing_digest`](Block::signing_digest)
+    #[serde(default)]
+    pub signature: Option<Vec<u8>>,
 }
 
 impl Block {
+    /// SHA-256 over ID, height, parent hash and payload
     pub fn hash(&self) -> Vec<u8> {
         let mut hasher = Sha256::new();
         hasher.update(self.id.as_bytes());
-        hasher.update(&self.height.to_le_bytes());
+        hasher.update(self.height.to_le_bytes());
         hasher.update(&self.prev_hash);
         hasher.update(&self.data);
         hasher.finalize().to_vec()
     }
 
+    /// Digest covered by the block signature
+    ///
+    /// Extends [`hash`](Self::hash) with the resonance and timestamp, which
+    /// fork resolution scores on but the chain linkage does not include.
+    pub fn signing_digest(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(b"mef-fork-healing/block-signature");
+        hasher.update(self.hash());
+        hasher.update(self.resonance.psi.to_le_bytes());
+        hasher.update(self.resonance.rho.to_le_bytes());
+        hasher.update(self.resonance.omega.to_le_bytes());
+        hasher.update(self.timestamp.to_le_bytes());
+        hasher.finalize().to_vec()
+    }
+
+    /// Sign the block's signing digest
+    pub fn sign(&mut self, key: &SigningKey) {
+        self.signature = Some(key.sign(&self.signing_digest()).to_bytes().to_vec());
+    }
+
+    /// Verify the block signature against a public key
+    ///
+    /// Returns `false` for unsigned blocks and for blocks modified after signing.
+    pub fn verify_signature(&self, pubkey: &VerifyingKey) -> bool {
+        let Some(bytes) = &self.signature else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(bytes) else {
+            return false;
+        };
+        pubkey.verify(&self.signing_digest(), &signature).is_ok()
+    }
+
+    /// Coherence of the block resonance with the field resonance
     pub fn compute_coherence(&self, field_resonance: &ResonanceState) -> f64 {
         self.resonance.coherence_with(field_resonance)
     }
 }
 
 /// Fork Healer - Main interface
+#[derive(Debug)]
 pub struct ForkHealer {
     attractor: MandorlaAttractor,
     multiversum: Multiversum,
 }
 
 impl ForkHealer {
+    /// Create a healer with the given attractor configuration
     pub fn new(config: AttractorConfig) -> Self {
         Self {
             attractor: MandorlaAttractor::new(config),
@@ -95,10 +155,7 @@ impl ForkHealer {
         }
     }
 
-    pub fn default() -> Self {
-        Self::new(AttractorConfig::default())
-    }
-
+    /// Select the winning block among fork candidates
     pub fn resolve_fork(
         &self,
         candidates: Vec<Block>,
@@ -106,6 +163,22 @@ impl ForkHealer {
     ) -> Result<Block> {
         self.attractor.resolve_fork(candidates, field_resonance)
     }
+
+    /// Attractor configuration used for fork resolution
+    pub fn config(&self) -> &AttractorConfig {
+        self.attractor.config()
+    }
+
+    /// Tracked fork branches
+    pub fn multiversum(&self) -> &Multiversum {
+        &self.multiversum
+    }
+}
+
+impl Default for ForkHealer {
+    fn default() -> Self {
+        Self::new(AttractorConfig::default())
+    }
 }
 
 #[cfg(test)]
@@ -119,6 +192,20 @@ mod tests {
         assert!((r1.coherence_with(&r2) - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_resonance_state_matches_canonical() {
+        let a = ResonanceState::new(0.5, -1.0, 2.0);
+        let b = ResonanceState::new(1.5, 0.0, -0.5);
+
+        // Round-trip through the canonical type is lossless
+        let canonical: mef_common::ResonanceState = a.into();
+        assert_eq!(ResonanceState::from(canonical), a);
+
+        // Coherence agrees with the canonical formula
+        let expected = canonical.coherence_with(&b.into());
+        assert_eq!(a.coherence_with(&b), expected);
+    }
+
     #[test]
     fn test_fork_healing() {
         let healer = ForkHealer::default();
@@ -131,6 +218,7 @@ mod tests {
             prev_hash: vec![0; 32],
             data: vec![1, 2, 3],
             timestamp: 0,
+            signature: None,
         };
 
         let block2 = Block {
@@ -140,6 +228,7 @@ mod tests {
             prev_hash: vec![0; 32],
             data: vec![4, 5, 6],
             timestamp: 0,
+            signature: None,
         };
 
         let winner = healer
@@ -147,4 +236,175 @@ mod tests {
             .unwrap();
         assert_eq!(winner.id, block1.id);
     }
+
+    #[test]
+    fn test_fork_rejects_candidates_below_min_coherence() {
+        let healer = ForkHealer::new(AttractorConfig {
+            min_coherence: 0.5,
+            ..AttractorConfig::default()
+        });
+        let field = ResonanceState::new(1.0, 1.0, 1.0);
+
+        let block = |psi: f64| Block {
+            id: Uuid::new_v4(),
+            height: 10,
+            resonance: ResonanceState::new(psi, psi, psi),
+            prev_hash: vec![0; 32],
+            data: vec![],
+            timestamp: 0,
+            signature: None,
+        };
+
+        // Both far from the field: coherence well below 0.5
+        let far1 = block(5.0);
+        let far2 = block(-5.0);
+        assert!(healer
+            .resolve_fork(vec![far1.clone(), far2.clone()], field)
+            .is_err());
+
+        // A close candidate clears the floor and wins
+        let near = block(1.1);
+        let winner = healer
+            .resolve_fork(vec![far1, near.clone(), far2], field)
+            .unwrap();
+        assert_eq!(winner.id, near.id);
+    }
+
+    fn signed_healer(key: &SigningKey) -> ForkHealer {
+        ForkHealer::new(AttractorConfig {
+            require_signatures: true,
+            trusted_signers: vec![key.verifying_key().to_bytes()],
+            ..AttractorConfig::default()
+        })
+    }
+
+    fn block_at(psi: f64) -> Block {
+        Block {
+            id: Uuid::new_v4(),
+            height: 10,
+            resonance: ResonanceState::new(psi, psi, psi),
+            prev_hash: vec![0; 32],
+            data: vec![7, 7, 7],
+            timestamp: 0,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn test_block_signature_roundtrip() {
+        let key = SigningKey::from_bytes(&[1u8; 32]);
+        let other = SigningKey::from_bytes(&[2u8; 32]);
+
+        let mut block = block_at(1.0);
+        assert!(!block.verify_signature(&key.verifying_key()));
+
+        block.sign(&key);
+        assert!(block.verify_signature(&key.verifying_key()));
+        assert!(!block.verify_signature(&other.verifying_key()));
+
+        // Tampering after signing invalidates the signature
+        block.data.push(0);
+        assert!(!block.verify_signature(&key.verifying_key()));
+    }
+
+    #[test]
+    fn test_signature_covers_resonance_and_timestamp() {
+        let key = SigningKey::from_bytes(&[1u8; 32]);
+
+        let mut block = block_at(1.0);
+        block.sign(&key);
+        let hash = block.hash();
+
+        block.resonance.omega += 0.5;
+        assert!(!block.verify_signature(&key.verifying_key()));
+        // Chain linkage is unaffected
+        assert_eq!(block.hash(), hash);
+
+        let mut block = block_at(1.0);
+        block.sign(&key);
+        block.timestamp += 1;
+        assert!(!block.verify_signature(&key.verifying_key()));
+    }
+
+    #[test]
+    fn test_fork_discards_block_with_forged_resonance() {
+        let key = SigningKey::from_bytes(&[1u8; 32]);
+        let healer = signed_healer(&key);
+        let field = ResonanceState::new(1.0, 1.0, 1.0);
+
+        let mut honest = block_at(2.0);
+        honest.sign(&key);
+
+        // Signed far from the field, then relayed with the field's resonance
+        let mut forged = block_at(5.0);
+        forged.sign(&key);
+        forged.resonance = field;
+
+        let winner = healer
+            .resolve_fork(vec![forged.clone(), honest.clone()], field)
+            .unwrap();
+        assert_eq!(winner.id, honest.id);
+
+        assert!(healer.resolve_fork(vec![forged], field).is_err());
+    }
+
+    #[test]
+    fn test_fork_discards_unsigned_and_tampered_blocks() {
+        let key = SigningKey::from_bytes(&[1u8; 32]);
+        let healer = signed_healer(&key);
+        let field = ResonanceState::new(1.0, 1.0, 1.0);
+
+        // Best resonance match, but unsigned
+        let unsigned = block_at(1.0);
+
+        // Signed, then modified
+        let mut tampered = block_at(1.0);
+        tampered.sign(&key);
+        tampered.data = vec![9, 9, 9];
+
+        assert!(healer
+            .resolve_fork(vec![unsigned.clone(), tampered.clone()], field)
+            .is_err());
+
+        // A validly signed block wins even with weaker coherence
+        let mut signed = block_at(2.0);
+        signed.sign(&key);
+        let winner = healer
+            .resolve_fork(vec![unsigned, tampered, signed.clone()], field)
+            .unwrap();
+        assert_eq!(winner.id, signed.id);
+    }
+
+    #[test]
+    fn test_fork_tie_breaks_on_smallest_hash() {
+        let healer = ForkHealer::default();
+        let field = ResonanceState::new(1.0, 1.0, 1.0);
+
+        // Identical resonance and timestamp: scores tie exactly
+        let block = |data: u8| Block {
+            id: Uuid::new_v4(),
+            height: 10,
+            resonance: ResonanceState::new(2.0, 2.0, 2.0),
+            prev_hash: vec![0; 32],
+            data: vec![data],
+            timestamp: 0,
+            signature: None,
+        };
+        let block1 = block(1);
+        let block2 = block(2);
+
+        let expected = if block1.hash() < block2.hash() {
+            block1.id
+        } else {
+            block2.id
+        };
+
+        let forward = healer
+            .resolve_fork(vec![block1.clone(), block2.clone()], field)
+            .unwrap();
+        let reverse = healer.resolve_fork(vec![block2, block1], field).unwrap();
+
+        assert_eq!(forward.id, expected);
+        assert_eq!(reverse.id, expected);
+    }
 }
@@ -3,32 +3,47 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Block competing on a fork branch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForkCandidate {
+    /// Candidate block
     pub block_id: Uuid,
+    /// Height the fork occurred at
     pub height: u64,
+    /// Branch the block belongs to
     pub branch_id: Uuid,
 }
 
+/// Outcome of a resolved fork
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForkResolution {
+    /// Winning block
     pub winner: Uuid,
+    /// Blocks that lost
     pub alternatives: Vec<Uuid>,
+    /// Resolution time (Unix seconds)
     pub timestamp: u64,
 }
 
+/// Tracks competing fork branches
+#[derive(Debug, Default)]
 pub struct Multiversum {
     branches: Vec<ForkCandidate>,
 }
 
 impl Multiversum {
+    /// Create an empty tracker
     pub fn new() -> Self {
-        Self {
-            branches: Vec::new(),
-        }
+        Self::default()
     }
 
+    /// Track a fork candidate
     pub fn track_fork(&mut self, candidate: ForkCandidate) {
         self.branches.push(candidate);
     }
+
+    /// Tracked candidates, in tracking order
+    pub fn branches(&self) -> &[ForkCandidate] {
+        &self.branches
+    }
 }
@@ -6,8 +6,9 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use mef_ghost_network::packet::{CarrierType, ResonanceState};
-use mef_ghost_network::protocol::{GhostProtocol, MaskingParams, ProtocolConfig};
-use mef_ghost_network::transport::{PacketCodec, WireFormat};
+use mef_ghost_network::protocol::{GhostProtocol, MaskingParams};
+use mef_ghost_network::transport::codec::WireFormat;
+use mef_ghost_network::transport::PacketCodec;
 
 // Benchmark complete 6-step protocol flow
 fn bench_complete_protocol_flow(c: &mut Criterion) {
@@ -145,15 +146,13 @@ fn bench_resonance_matching(c: &mut Criterion) {
     let epsilon = 0.1;
 
     group.bench_function("resonance_match_close", |b| {
-        b.iter(|| {
-            packet_resonance.matches_resonance(black_box(&node_resonance), black_box(epsilon))
-        })
+        b.iter(|| packet_resonance.is_resonant_with(black_box(&node_resonance), black_box(epsilon)))
     });
 
     let far_resonance = ResonanceState::new(10.0, 10.0, 10.0);
 
     group.bench_function("resonance_match_far", |b| {
-        b.iter(|| far_resonance.matches_resonance(black_box(&node_resonance), black_box(epsilon)))
+        b.iter(|| far_resonance.is_resonant_with(black_box(&node_resonance), black_box(epsilon)))
     });
 
     group.finish();
@@ -174,7 +173,7 @@ fn bench_masking_params(c: &mut Criterion) {
     });
 
     c.bench_function("masking_params_ephemeral_key", |b| {
-        b.iter(|| MaskingParams::generate_ephemeral_key())
+        b.iter(MaskingParams::generate_ephemeral_key)
     });
 }
 
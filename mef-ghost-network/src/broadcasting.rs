@@ -11,14 +11,46 @@
  * - Automatic channel dissolution
  */
 
-use crate::packet::{GhostPacket, NodeIdentity, ResonanceState};
-use crate::transport::Transport;
+use crate::packet::{GhostPacket, NodeIdentity, PacketPriority, ResonanceState};
+use crate::transport::{PeerId, PeerViolation, Transport};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{Mutex, Notify};
+
+/// Number of recent real packets kept for decoy shaping
+const TRAFFIC_PROFILE_WINDOW: usize = 256;
+
+/// Lifetime of decoy channels and of the record of sent decoy IDs (seconds)
+const DECOY_TTL_SECS: u64 = 300;
+
+/// Resonance window subscription - a band in resonance space
+///
+/// A window covers every resonance state within `radius` of `center`,
+/// letting a channel receive packets across a band rather than only
+/// near its own resonance point.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionWindow {
+    /// Center of the window
+    pub center: ResonanceState,
+
+    /// Radius of the window (same metric as resonance epsilon)
+    pub radius: f64,
+}
+
+impl SubscriptionWindow {
+    /// Create new subscription window
+    pub fn new(center: ResonanceState, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Check if a resonance state falls inside this window
+    pub fn contains(&self, resonance: &ResonanceState) -> bool {
+        self.center.is_resonant_with(resonance, self.radius)
+    }
+}
 
 /// Broadcast channel - ephemeral, resonance-based
 #[derive(Debug, Clone)]
@@ -40,6 +72,9 @@ pub struct BroadcastChannel {
 
     /// Whether this is a decoy channel
     pub is_decoy: bool,
+
+    /// Additional resonance windows this channel is subscribed to
+    pub subscriptions: Vec<SubscriptionWindow>,
 }
 
 impl BroadcastChannel {
@@ -55,12 +90,13 @@ impl BroadcastChannel {
                 .as_secs(),
             ttl_seconds,
             is_decoy: false,
+            subscriptions: Vec::new(),
         }
     }
 
     /// Create decoy channel for privacy
     pub fn new_decoy(resonance: ResonanceState) -> Self {
-        let mut channel = Self::new(resonance, 0.1, 300); // 5 minute TTL
+        let mut channel = Self::new(resonance, 0.1, DECOY_TTL_SECS);
         channel.is_decoy = true;
         channel
     }
@@ -74,10 +110,24 @@ impl BroadcastChannel {
         now < self.created_at + self.ttl_seconds
     }
 
+    /// Subscribe this channel to an additional resonance window
+    pub fn subscribe(&mut self, window: SubscriptionWindow) {
+        self.subscriptions.push(window);
+    }
+
+    /// Check if a resonance state falls in any subscribed window
+    pub fn in_subscribed_window(&self, resonance: &ResonanceState) -> bool {
+        self.subscriptions.iter().any(|w| w.contains(resonance))
+    }
+
     /// Check if a packet matches this channel's resonance
+    ///
+    /// A packet matches if it is near the channel's own resonance or
+    /// lands in any of its subscribed windows.
     pub fn matches_packet(&self, packet: &GhostPacket) -> bool {
         self.resonance
             .is_resonant_with(&packet.resonance, self.epsilon)
+            || self.in_subscribed_window(&packet.resonance)
     }
 
     /// Check if a node matches this channel's resonance
@@ -105,32 +155,167 @@ pub struct BroadcastStats {
     /// Total channels dissolved
     pub channels_dissolved: usize,
 
+    /// Channel deliveries made by directed broadcasts
+    #[serde(default)]
+    pub targeted_sends: usize,
+
     /// Average resonance match rate
     pub avg_match_rate: f64,
 }
 
+/// Rolling profile of real traffic used to shape decoys
+///
+/// Keeps the last [`TRAFFIC_PROFILE_WINDOW`] real packets' payload/carrier
+/// sizes and inter-packet gaps so that decoys can be sampled from the same
+/// empirical distribution instead of standing out.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrafficProfile {
+    /// Recent (masked_payload, stego_carrier) sizes in bytes
+    pub sizes: VecDeque<(usize, usize)>,
+
+    /// Recent gaps between consecutive real packets (milliseconds)
+    pub inter_packet_ms: VecDeque<u64>,
+
+    /// Timestamp of the last real packet (milliseconds since epoch)
+    pub last_packet_ms: Option<u64>,
+
+    /// Recent delivery priorities
+    #[serde(default)]
+    pub priorities: VecDeque<PacketPriority>,
+}
+
+impl TrafficProfile {
+    /// Record a real packet observed at `now_ms`
+    pub fn record(&mut self, packet: &GhostPacket, now_ms: u64) {
+        if self.sizes.len() >= TRAFFIC_PROFILE_WINDOW {
+            self.sizes.pop_front();
+        }
+        self.sizes
+            .push_back((packet.masked_payload.len(), packet.stego_carrier.len()));
+
+        if self.priorities.len() >= TRAFFIC_PROFILE_WINDOW {
+            self.priorities.pop_front();
+        }
+        self.priorities.push_back(packet.priority);
+
+        if let Some(last) = self.last_packet_ms {
+            if self.inter_packet_ms.len() >= TRAFFIC_PROFILE_WINDOW {
+                self.inter_packet_ms.pop_front();
+            }
+            self.inter_packet_ms.push_back(now_ms.saturating_sub(last));
+        }
+        self.last_packet_ms = Some(now_ms);
+    }
+
+    /// Sample a (payload, carrier) size pair from observed traffic
+    pub fn sample_sizes<R: rand::Rng>(&self, rng: &mut R) -> Option<(usize, usize)> {
+        if self.sizes.is_empty() {
+            return None;
+        }
+        Some(self.sizes[rng.gen_range(0..self.sizes.len())])
+    }
+
+    /// Sample a delivery priority from observed traffic
+    pub fn sample_priority<R: rand::Rng>(&self, rng: &mut R) -> Option<PacketPriority> {
+        if self.priorities.is_empty() {
+            return None;
+        }
+        Some(self.priorities[rng.gen_range(0..self.priorities.len())])
+    }
+
+    /// Sample an inter-packet delay from observed traffic
+    pub fn sample_delay<R: rand::Rng>(&self, rng: &mut R) -> Option<Duration> {
+        if self.inter_packet_ms.is_empty() {
+            return None;
+        }
+        let ms = self.inter_packet_ms[rng.gen_range(0..self.inter_packet_ms.len())];
+        Some(Duration::from_millis(ms))
+    }
+}
+
+/// Default per-dimension jitter applied to decoy resonances
+pub const DEFAULT_DECOY_JITTER: f64 = 0.05;
+
+/// Distribution that decoy resonances are drawn from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum DecoyResonance {
+    /// Uniform over [-1, 1]³
+    #[default]
+    Uniform,
+
+    /// Resample the resonance of a random active (non-decoy) channel and
+    /// offset each dimension by up to `jitter`, so decoys follow the same
+    /// distribution as real channels. Falls back to `Uniform` while no real
+    /// channels exist.
+    ///
+    /// Decoys then land inside real channels' resonance windows, so
+    /// receivers see them and reject them as undecodable.
+    ActiveChannels {
+        /// Maximum per-dimension offset from the sampled channel
+        jitter: f64,
+    },
+}
+
+/// Packet held in a channel buffer
+///
+/// Whether a packet is a decoy is known only to the engine that generated
+/// it; on the wire decoys look like any other packet.
+#[derive(Debug, Clone)]
+struct BufferedPacket {
+    packet: GhostPacket,
+    is_decoy: bool,
+}
+
+impl BufferedPacket {
+    /// Delivery rank: real packets before decoys, then by priority
+    fn rank(&self) -> (bool, PacketPriority) {
+        (!self.is_decoy, self.packet.priority)
+    }
+}
+
 /// Addressless broadcast engine
 pub struct BroadcastEngine {
     /// Active broadcast channels
     channels: Arc<RwLock<HashMap<uuid::Uuid, BroadcastChannel>>>,
 
     /// Packet buffer for each channel (in-memory fallback)
-    buffers: Arc<RwLock<HashMap<uuid::Uuid, VecDeque<GhostPacket>>>>,
+    buffers: Arc<RwLock<HashMap<uuid::Uuid, VecDeque<BufferedPacket>>>>,
 
     /// Statistics
     stats: Arc<RwLock<BroadcastStats>>,
 
+    /// Size, priority and timing profile of recent real packets
+    traffic_profile: Arc<RwLock<TrafficProfile>>,
+
     /// Maximum packets per channel buffer
     max_buffer_size: usize,
 
     /// Decoy traffic generation rate (packets per second)
     decoy_rate: f64,
 
+    /// Target number of decoys per real packet
+    decoy_ratio: f64,
+
+    /// Distribution of decoy resonances
+    decoy_resonance: DecoyResonance,
+
     /// Automatic channel cleanup interval (seconds)
     cleanup_interval: u64,
 
     /// Optional network transport (None = in-memory only)
     transport: Option<Arc<Mutex<dyn Transport>>>,
+
+    /// Wakes receivers waiting for newly buffered packets
+    packet_notify: Arc<Notify>,
+
+    /// Delivering peer of each packet in the last transport receive batch
+    packet_sources: Arc<RwLock<HashMap<uuid::Uuid, PeerId>>>,
+
+    /// IDs of decoys this engine sent, with their send time (seconds)
+    ///
+    /// Decoys are indistinguishable on the wire, so this is the only way to
+    /// rank a decoy that is relayed back over the transport below real traffic.
+    sent_decoys: Arc<RwLock<HashMap<uuid::Uuid, u64>>>,
 }
 
 impl BroadcastEngine {
@@ -140,10 +325,16 @@ impl BroadcastEngine {
             channels: Arc::new(RwLock::new(HashMap::new())),
             buffers: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(RwLock::new(BroadcastStats::default())),
+            traffic_profile: Arc::new(RwLock::new(TrafficProfile::default())),
             max_buffer_size,
             decoy_rate,
+            decoy_ratio: 1.0,
+            decoy_resonance: DecoyResonance::default(),
             cleanup_interval,
             transport: None,
+            packet_notify: Arc::new(Notify::new()),
+            packet_sources: Arc::new(RwLock::new(HashMap::new())),
+            sent_decoys: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -158,20 +349,53 @@ impl BroadcastEngine {
             channels: Arc::new(RwLock::new(HashMap::new())),
             buffers: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(RwLock::new(BroadcastStats::default())),
+            traffic_profile: Arc::new(RwLock::new(TrafficProfile::default())),
             max_buffer_size,
             decoy_rate,
+            decoy_ratio: 1.0,
+            decoy_resonance: DecoyResonance::default(),
             cleanup_interval,
             transport: Some(transport),
+            packet_notify: Arc::new(Notify::new()),
+            packet_sources: Arc::new(RwLock::new(HashMap::new())),
+            sent_decoys: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    /// Create with default settings (in-memory only)
-    pub fn default() -> Self {
-        Self::new(
-            1000, // Max 1000 packets per channel
-            10.0, // 10 decoy packets per second
-            60,   // Cleanup every 60 seconds
-        )
+    /// Set the decoy-to-real packet ratio (e.g. 2.0 = two decoys per real packet)
+    pub fn with_decoy_ratio(mut self, ratio: f64) -> Self {
+        self.decoy_ratio = if ratio.is_finite() {
+            ratio.max(0.0)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// Get the configured decoy-to-real packet ratio
+    pub fn decoy_ratio(&self) -> f64 {
+        self.decoy_ratio
+    }
+
+    /// Set the distribution decoy resonances are drawn from
+    pub fn with_decoy_resonance(mut self, decoy_resonance: DecoyResonance) -> Self {
+        self.decoy_resonance = decoy_resonance;
+        self
+    }
+
+    /// Get the configured decoy resonance distribution
+    pub fn decoy_resonance(&self) -> DecoyResonance {
+        self.decoy_resonance
+    }
+
+    /// Get the configured decoy generation rate (packets per second)
+    pub fn decoy_rate(&self) -> f64 {
+        self.decoy_rate
+    }
+
+    /// Get the configured channel cleanup interval (seconds)
+    pub fn cleanup_interval(&self) -> u64 {
+        self.cleanup_interval
     }
 
     /// Create new broadcast channel
@@ -225,6 +449,32 @@ impl BroadcastEngine {
         Ok(channel_id)
     }
 
+    /// Subscribe an existing channel to a resonance window (center + radius)
+    ///
+    /// Packets whose resonance falls inside the window are routed to the
+    /// channel and delivered to nodes reading from it.
+    pub fn subscribe_window(
+        &self,
+        channel_id: uuid::Uuid,
+        center: ResonanceState,
+        radius: f64,
+    ) -> Result<()> {
+        if !radius.is_finite() || radius <= 0.0 {
+            anyhow::bail!("Subscription radius must be positive and finite");
+        }
+
+        let mut channels = self
+            .channels
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on channels: {}", e))?;
+        let channel = channels
+            .get_mut(&channel_id)
+            .ok_or_else(|| anyhow::anyhow!("Channel not found: {}", channel_id))?;
+        channel.subscribe(SubscriptionWindow::new(center, radius));
+
+        Ok(())
+    }
+
     /// Broadcast packet to resonant channels
     ///
     /// Packet is routed to all channels with matching resonance.
@@ -233,19 +483,90 @@ impl BroadcastEngine {
     /// If transport is configured, broadcasts via network.
     /// Otherwise, uses in-memory buffers.
     pub async fn broadcast(&self, packet: GhostPacket) -> Result<Vec<uuid::Uuid>> {
-        let channels = self
-            .channels
-            .read()
-            .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on channels: {}", e))?;
-        let mut matching_channels = Vec::new();
+        self.broadcast_inner(packet, false, None).await
+    }
+
+    /// Broadcast a packet to the nearest matching channels only
+    ///
+    /// Matching channels are ranked by the distance between their resonance
+    /// and `packet.resonance`; only the closest `max_targets` receive the
+    /// packet. Equally distant channels are ordered by channel ID.
+    ///
+    /// Only available without a network transport: channels are local
+    /// buffers with no peer behind them, and the transport can only flood.
+    ///
+    /// # Returns
+    /// * IDs of the targeted channels, nearest first
+    /// * `Err` if the engine broadcasts over a network transport
+    pub async fn broadcast_directed(
+        &self,
+        packet: GhostPacket,
+        max_targets: usize,
+    ) -> Result<Vec<uuid::Uuid>> {
+        if self.transport.is_some() {
+            anyhow::bail!(
+                "Directed broadcast is not supported over a network transport; use broadcast"
+            );
+        }
+        self.broadcast_inner(packet, false, Some(max_targets)).await
+    }
+
+    /// Relay a received packet to the next hop
+    ///
+    /// Every forward consumes one hop of the packet's TTL before it is
+    /// re-broadcast, so a packet created with TTL `n` survives exactly `n`
+    /// forwards. Packets whose TTL is already exhausted are not sent.
+    ///
+    /// # Returns
+    /// * IDs of the matching channels, or None if the packet was dropped
+    pub async fn forward(&self, mut packet: GhostPacket) -> Result<Option<Vec<uuid::Uuid>>> {
+        if !packet.decrement_ttl() {
+            return Ok(None);
+        }
+
+        let is_decoy = self.is_sent_decoy(&packet.id)?;
+        self.broadcast_inner(packet, is_decoy, None).await.map(Some)
+    }
 
-        // Find all resonant channels (core innovation - resonance-based routing)
-        for (channel_id, channel) in channels.iter() {
-            if channel.is_alive() && channel.matches_packet(&packet) {
-                matching_channels.push(*channel_id);
+    async fn broadcast_inner(
+        &self,
+        packet: GhostPacket,
+        is_decoy: bool,
+        max_targets: Option<usize>,
+    ) -> Result<Vec<uuid::Uuid>> {
+        let mut matching: Vec<(uuid::Uuid, f64)> = Vec::new();
+        {
+            let channels = self
+                .channels
+                .read()
+                .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on channels: {}", e))?;
+
+            // Find all resonant channels (core innovation - resonance-based routing)
+            for (channel_id, channel) in channels.iter() {
+                if channel.is_alive() && channel.matches_packet(&packet) {
+                    let distance = channel.resonance.distance_to(&packet.resonance);
+                    matching.push((*channel_id, distance));
+                }
             }
+        } // Release read lock
+
+        // Directed broadcast: keep only the nearest channels
+        if let Some(max_targets) = max_targets {
+            matching.sort_by(|(a_id, a), (b_id, b)| a.total_cmp(b).then_with(|| a_id.cmp(b_id)));
+            matching.truncate(max_targets);
+        }
+        let matching_channels: Vec<uuid::Uuid> = matching.into_iter().map(|(id, _)| id).collect();
+
+        if is_decoy {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            self.sent_decoys
+                .write()
+                .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on sent decoys: {}", e))?
+                .insert(packet.id, now);
         }
-        drop(channels); // Release read lock
 
         // If we have network transport, broadcast via network
         if let Some(ref transport) = self.transport {
@@ -261,13 +582,18 @@ impl BroadcastEngine {
                 .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on buffers: {}", e))?;
             for channel_id in matching_channels.iter() {
                 if let Some(buffer) = buffers.get_mut(channel_id) {
-                    // Enforce buffer size limit
-                    if buffer.len() >= self.max_buffer_size {
-                        buffer.pop_front(); // Drop oldest packet
-                    }
-                    buffer.push_back(packet.clone());
+                    let buffered = BufferedPacket {
+                        packet: packet.clone(),
+                        is_decoy,
+                    };
+                    enqueue_by_priority(buffer, buffered, self.max_buffer_size);
                 }
             }
+            drop(buffers);
+
+            if !matching_channels.is_empty() {
+                self.packet_notify.notify_waiters();
+            }
         }
 
         // Update statistics
@@ -276,6 +602,21 @@ impl BroadcastEngine {
             .write()
             .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on stats: {}", e))?;
         stats.packets_sent += 1;
+        if max_targets.is_some() {
+            stats.targeted_sends += matching_channels.len();
+        }
+        drop(stats);
+
+        if !is_decoy {
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let mut profile = self.traffic_profile.write().map_err(|e| {
+                anyhow::anyhow!("Failed to acquire write lock on traffic profile: {}", e)
+            })?;
+            profile.record(&packet, now_ms);
+        }
 
         Ok(matching_channels)
     }
@@ -286,31 +627,53 @@ impl BroadcastEngine {
     ///
     /// If transport is configured, receives from network and filters by resonance.
     /// Otherwise, uses in-memory buffers.
-    pub async fn receive(&self, node: &NodeIdentity) -> Result<Vec<GhostPacket>> {
+    ///
+    /// `epsilon` is the resonance window packets are matched against; pass the
+    /// protocol's `effective_epsilon()` so adaptive widening reaches the wire.
+    pub async fn receive(&self, node: &NodeIdentity, epsilon: f64) -> Result<Vec<GhostPacket>> {
         let mut received_packets = Vec::new();
 
         // If we have network transport, receive from network
         if let Some(ref transport) = self.transport {
+            // Windows subscribed by channels belonging to this node
+            let windows: Vec<SubscriptionWindow> = {
+                let channels = self.channels.read().map_err(|e| {
+                    anyhow::anyhow!("Failed to acquire read lock on channels: {}", e)
+                })?;
+                channels
+                    .values()
+                    .filter(|channel| channel.is_alive() && channel.matches_node(node))
+                    .flat_map(|channel| channel.subscriptions.iter().copied())
+                    .collect()
+            };
+
             let mut t = transport.lock().await;
+            let mut sources = HashMap::new();
 
             // Try to receive packets from network (non-blocking)
             // We collect all available packets and filter by resonance
-            loop {
-                match tokio::time::timeout(
-                    std::time::Duration::from_millis(10), // Short timeout for non-blocking
-                    t.receive(),
-                )
-                .await
+            // Stops at the first timeout or error - no more packets
+            while let Ok(Ok((peer_id, packet))) = tokio::time::timeout(
+                std::time::Duration::from_millis(10), // Short timeout for non-blocking
+                t.receive(),
+            )
+            .await
+            {
+                // Filter by resonance matching or subscribed windows
+                if packet.matches_resonance(&node.resonance, epsilon)
+                    || windows.iter().any(|w| w.contains(&packet.resonance))
                 {
-                    Ok(Ok((_peer_id, packet))) => {
-                        // Filter by resonance matching
-                        if packet.matches_resonance(&node.resonance, 0.1) {
-                            received_packets.push(packet);
-                        }
-                    }
-                    _ => break, // Timeout or error - no more packets
+                    sources.insert(packet.id, peer_id);
+                    // Only our own decoys, relayed back, are known to be decoys
+                    let is_decoy = self.is_sent_decoy(&packet.id)?;
+                    received_packets.push(BufferedPacket { packet, is_decoy });
                 }
             }
+            drop(t);
+
+            *self.packet_sources.write().map_err(|e| {
+                anyhow::anyhow!("Failed to acquire write lock on packet sources: {}", e)
+            })? = sources;
         } else {
             // Fallback: Use in-memory buffers
             let channels = self
@@ -318,11 +681,11 @@ impl BroadcastEngine {
                 .read()
                 .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on channels: {}", e))?;
 
-            // Find matching channels
-            let matching_channel_ids: Vec<uuid::Uuid> = channels
+            // Find matching channels along with their subscribed windows
+            let matching_channels: Vec<(uuid::Uuid, Vec<SubscriptionWindow>)> = channels
                 .iter()
                 .filter(|(_, channel)| channel.is_alive() && channel.matches_node(node))
-                .map(|(id, _)| *id)
+                .map(|(id, channel)| (*id, channel.subscriptions.clone()))
                 .collect();
             drop(channels); // Release read lock
 
@@ -331,19 +694,28 @@ impl BroadcastEngine {
                 .buffers
                 .write()
                 .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on buffers: {}", e))?;
-            for channel_id in matching_channel_ids {
+            for (channel_id, windows) in matching_channels {
                 if let Some(buffer) = buffers.get_mut(&channel_id) {
                     // Take all packets from buffer
-                    while let Some(packet) = buffer.pop_front() {
-                        // Double-check resonance match with node
-                        if packet.matches_resonance(&node.resonance, 0.1) {
-                            received_packets.push(packet);
+                    while let Some(buffered) = buffer.pop_front() {
+                        // Double-check resonance match with node or subscribed band
+                        let packet = &buffered.packet;
+                        if packet.matches_resonance(&node.resonance, epsilon)
+                            || windows.iter().any(|w| w.contains(&packet.resonance))
+                        {
+                            received_packets.push(buffered);
                         }
                     }
                 }
             }
         }
 
+        // Deliver real traffic before local decoys and higher priorities
+        // first; the stable sort keeps FIFO order within a priority class
+        received_packets.sort_by_key(|p| std::cmp::Reverse(p.rank()));
+        let received_packets: Vec<GhostPacket> =
+            received_packets.into_iter().map(|p| p.packet).collect();
+
         // Update statistics
         let mut stats = self
             .stats
@@ -354,37 +726,43 @@ impl BroadcastEngine {
         Ok(received_packets)
     }
 
+    /// Charge a protocol violation to the peer that delivered a packet
+    ///
+    /// Only packets from the most recent transport `receive` batch can be
+    /// attributed; in-memory packets have no delivering peer.
+    ///
+    /// # Returns
+    /// * `true` if the violation got the peer banned
+    pub async fn report_violation(
+        &self,
+        packet_id: uuid::Uuid,
+        violation: PeerViolation,
+    ) -> Result<bool> {
+        let Some(ref transport) = self.transport else {
+            return Ok(false);
+        };
+
+        let peer = self
+            .packet_sources
+            .read()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on packet sources: {}", e))?
+            .get(&packet_id)
+            .copied();
+
+        match peer {
+            Some(peer) => Ok(transport.lock().await.report_violation(peer, violation)),
+            None => Ok(false),
+        }
+    }
+
     /// Generate decoy traffic for privacy
     ///
     /// Creates fake packets to maintain constant background noise,
-    /// making traffic analysis more difficult.
+    /// making traffic analysis more difficult. Decoy sizes are sampled from
+    /// recently observed real packets (see [`TrafficProfile`]).
     pub async fn generate_decoy_traffic(&self, count: usize) -> Result<()> {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
-
         for _ in 0..count {
-            // Random resonance state
-            let resonance = ResonanceState::new(
-                rng.gen_range(-1.0..1.0),
-                rng.gen_range(-1.0..1.0),
-                rng.gen_range(-1.0..1.0),
-            );
-
-            // Create decoy channel
-            self.create_decoy_channel(resonance)?;
-
-            // Create decoy packet
-            let packet = GhostPacket::new(
-                resonance,                           // target_resonance
-                resonance,                           // sender_resonance (decoy, so same)
-                vec![0u8; rng.gen_range(100..1000)], // masked_payload
-                vec![0u8; rng.gen_range(100..1000)], // stego_carrier
-                crate::packet::CarrierType::Raw,     // carrier_type
-                None,                                // zk_proof
-            );
-
-            // Broadcast decoy packet
-            self.broadcast(packet).await?;
+            self.send_decoy().await?;
         }
 
         let mut stats = self
@@ -396,6 +774,160 @@ impl BroadcastEngine {
         Ok(())
     }
 
+    /// Generate decoy traffic paced like real traffic
+    ///
+    /// Same as [`generate_decoy_traffic`](Self::generate_decoy_traffic), but
+    /// sleeps between decoys for a gap sampled from real inter-packet timing.
+    pub async fn generate_paced_decoy_traffic(&self, count: usize) -> Result<()> {
+        for _ in 0..count {
+            if let Some(delay) = self.sample_decoy_delay() {
+                tokio::time::sleep(delay).await;
+            }
+            self.send_decoy().await?;
+
+            let mut stats = self
+                .stats
+                .write()
+                .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on stats: {}", e))?;
+            stats.decoy_packets += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Number of decoys still owed to reach the configured decoy ratio
+    pub fn pending_decoys(&self) -> usize {
+        let stats = self.get_stats();
+        let real = stats.packets_sent.saturating_sub(stats.decoy_packets);
+        let target = (real as f64 * self.decoy_ratio).ceil() as usize;
+        target.saturating_sub(stats.decoy_packets)
+    }
+
+    /// Top up decoy traffic to the configured decoy-to-real ratio
+    ///
+    /// Returns the number of decoys generated.
+    pub async fn maintain_decoy_ratio(&self) -> Result<usize> {
+        let owed = self.pending_decoys();
+        self.generate_decoy_traffic(owed).await?;
+        Ok(owed)
+    }
+
+    /// Sample an inter-packet delay from observed real traffic
+    pub fn sample_decoy_delay(&self) -> Option<Duration> {
+        let profile = self.traffic_profile.read().unwrap_or_else(|e| {
+            eprintln!("Warning: RwLock poisoned in sample_decoy_delay: {}", e);
+            e.into_inner()
+        });
+        profile.sample_delay(&mut rand::thread_rng())
+    }
+
+    /// Build a decoy packet shaped like recent real traffic
+    ///
+    /// Payload and carrier sizes are resampled from the traffic profile and
+    /// filled with random bytes; without any observed traffic, sizes fall
+    /// back to a uniform 100..1000 byte range. The priority is resampled the
+    /// same way (default `Normal`), so nothing on the wire marks the packet
+    /// as a decoy. The resonance is drawn from the configured
+    /// [`DecoyResonance`] distribution.
+    pub fn build_decoy_packet(&self) -> GhostPacket {
+        self.build_decoy_packet_with(&mut rand::thread_rng())
+    }
+
+    /// Build a decoy packet drawing all randomness from `rng`
+    fn build_decoy_packet_with<R: rand::Rng>(&self, rng: &mut R) -> GhostPacket {
+        let (sampled, priority) = {
+            let profile = self.traffic_profile.read().unwrap_or_else(|e| {
+                eprintln!("Warning: RwLock poisoned in build_decoy_packet: {}", e);
+                e.into_inner()
+            });
+            (profile.sample_sizes(rng), profile.sample_priority(rng))
+        };
+        let (payload_len, carrier_len) =
+            sampled.unwrap_or_else(|| (rng.gen_range(100..1000), rng.gen_range(100..1000)));
+
+        let resonance = self.sample_decoy_resonance(rng);
+
+        let mut masked_payload = vec![0u8; payload_len];
+        rng.fill(masked_payload.as_mut_slice());
+        let mut stego_carrier = vec![0u8; carrier_len];
+        rng.fill(stego_carrier.as_mut_slice());
+
+        GhostPacket::new(
+            resonance,                       // target_resonance
+            resonance,                       // sender_resonance (decoy, so same)
+            masked_payload,                  // masked_payload
+            stego_carrier,                   // stego_carrier
+            crate::packet::CarrierType::Raw, // carrier_type
+            None,                            // zk_proof
+        )
+        .with_priority(priority.unwrap_or_default())
+    }
+
+    /// Sample a decoy resonance from the configured distribution
+    fn sample_decoy_resonance<R: rand::Rng>(&self, rng: &mut R) -> ResonanceState {
+        if let DecoyResonance::ActiveChannels { jitter } = self.decoy_resonance {
+            let centers: Vec<ResonanceState> = {
+                let channels = self.channels.read().unwrap_or_else(|e| {
+                    eprintln!("Warning: RwLock poisoned in sample_decoy_resonance: {}", e);
+                    e.into_inner()
+                });
+                channels
+                    .values()
+                    .filter(|c| !c.is_decoy && c.is_alive())
+                    .map(|c| c.resonance)
+                    .collect()
+            };
+
+            if !centers.is_empty() {
+                let center = centers[rng.gen_range(0..centers.len())];
+                let jitter = if jitter.is_finite() {
+                    jitter.abs()
+                } else {
+                    0.0
+                };
+                let mut offset = || {
+                    if jitter > 0.0 {
+                        rng.gen_range(-jitter..=jitter)
+                    } else {
+                        0.0
+                    }
+                };
+                return ResonanceState::new(
+                    center.psi + offset(),
+                    center.rho + offset(),
+                    center.omega + offset(),
+                );
+            }
+        }
+
+        ResonanceState::new(
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+        )
+    }
+
+    /// Create a decoy channel and broadcast one shaped decoy packet into it
+    async fn send_decoy(&self) -> Result<()> {
+        let packet = self.build_decoy_packet();
+
+        // Create decoy channel
+        self.create_decoy_channel(packet.resonance)?;
+
+        // Broadcast decoy packet (excluded from the traffic profile)
+        self.broadcast_inner(packet, true, None).await?;
+        Ok(())
+    }
+
+    /// Whether a packet is a decoy sent by this engine
+    fn is_sent_decoy(&self, packet_id: &uuid::Uuid) -> Result<bool> {
+        Ok(self
+            .sent_decoys
+            .read()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire read lock on sent decoys: {}", e))?
+            .contains_key(packet_id))
+    }
+
     /// Cleanup expired channels (automatic dissolution)
     ///
     /// Removes channels that have exceeded their TTL.
@@ -428,6 +960,16 @@ impl BroadcastEngine {
         drop(channels); // Release write locks before acquiring stats lock
         drop(buffers);
 
+        // Decoys outlive their channel only as long as relays may echo them
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.sent_decoys
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on sent decoys: {}", e))?
+            .retain(|_, sent_at| now < *sent_at + DECOY_TTL_SECS);
+
         // Update statistics
         let mut stats = self
             .stats
@@ -464,7 +1006,20 @@ impl BroadcastEngine {
             .clone()
     }
 
+    /// Get a copy of the real-traffic profile used to shape decoys
+    pub fn traffic_profile(&self) -> TrafficProfile {
+        self.traffic_profile
+            .read()
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: RwLock poisoned in traffic_profile: {}", e);
+                e.into_inner()
+            })
+            .clone()
+    }
+
     /// Reset statistics
+    ///
+    /// The traffic profile is shaping state, not a counter, and is kept.
     pub fn reset_stats(&self) {
         // Use unwrap_or_else to handle poison error and continue
         let mut stats = self.stats.write().unwrap_or_else(|e| {
@@ -484,6 +1039,18 @@ impl BroadcastEngine {
         channels.values().filter(|c| c.is_alive()).count()
     }
 
+    /// Get notifier signalled whenever a packet lands in a channel buffer
+    ///
+    /// Used by streaming receivers to wait for traffic instead of busy-polling.
+    pub fn packet_notify(&self) -> Arc<Notify> {
+        self.packet_notify.clone()
+    }
+
+    /// Check if a network transport is configured
+    pub fn has_transport(&self) -> bool {
+        self.transport.is_some()
+    }
+
     /// Get buffer size for a channel
     pub fn get_buffer_size(&self, channel_id: uuid::Uuid) -> Option<usize> {
         // Use unwrap_or_else to handle poison error by returning None
@@ -495,6 +1062,68 @@ impl BroadcastEngine {
     }
 }
 
+impl std::fmt::Debug for BroadcastEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BroadcastEngine")
+            .field("max_buffer_size", &self.max_buffer_size)
+            .field("decoy_rate", &self.decoy_rate)
+            .field("decoy_ratio", &self.decoy_ratio)
+            .field("decoy_resonance", &self.decoy_resonance)
+            .field("cleanup_interval", &self.cleanup_interval)
+            .field("has_transport", &self.transport.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for BroadcastEngine {
+    /// Create with default settings (in-memory only)
+    fn default() -> Self {
+        Self::new(
+            1000, // Max 1000 packets per channel
+            10.0, // 10 decoy packets per second
+            60,   // Cleanup every 60 seconds
+        )
+    }
+}
+
+/// Insert a packet into a channel buffer ordered by delivery rank
+///
+/// The buffer is kept sorted from highest to lowest rank (real packets
+/// before decoys, then by priority), FIFO within a rank. When full, the
+/// oldest packet of the lowest rank is dropped, unless the incoming packet
+/// ranks below everything buffered, in which case it is dropped instead.
+fn enqueue_by_priority(
+    buffer: &mut VecDeque<BufferedPacket>,
+    packet: BufferedPacket,
+    max_len: usize,
+) {
+    if max_len == 0 {
+        return;
+    }
+
+    let rank = packet.rank();
+    if buffer.len() >= max_len {
+        let lowest = match buffer.back() {
+            Some(back) => back.rank(),
+            None => return,
+        };
+        if rank < lowest {
+            return; // Incoming packet is the least important
+        }
+        let oldest_lowest = buffer
+            .iter()
+            .position(|p| p.rank() == lowest)
+            .unwrap_or(buffer.len() - 1);
+        buffer.remove(oldest_lowest);
+    }
+
+    let position = buffer
+        .iter()
+        .position(|p| p.rank() < rank)
+        .unwrap_or(buffer.len());
+    buffer.insert(position, packet);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -552,6 +1181,7 @@ mod tests {
 
         // Create and broadcast packet
         let packet = GhostPacket::new(
+            resonance,
             resonance,
             b"test payload".to_vec(),
             b"test carrier".to_vec(),
@@ -566,7 +1196,7 @@ mod tests {
         let node = NodeIdentity::new(ResonanceState::new(1.05, 1.05, 1.05), None);
 
         // Receive packets
-        let received = engine.receive(&node).await.unwrap();
+        let received = engine.receive(&node, 0.1).await.unwrap();
         assert_eq!(received.len(), 1);
         assert_eq!(received[0].id, packet.id);
     }
@@ -581,6 +1211,7 @@ mod tests {
 
         // Broadcast packet with very different resonance
         let packet = GhostPacket::new(
+            ResonanceState::new(10.0, 10.0, 10.0),
             ResonanceState::new(10.0, 10.0, 10.0),
             b"test".to_vec(),
             b"test".to_vec(),
@@ -602,6 +1233,343 @@ mod tests {
         assert_eq!(stats.decoy_packets, 5);
     }
 
+    fn packet_at(resonance: ResonanceState) -> GhostPacket {
+        GhostPacket::new(
+            resonance,
+            resonance,
+            b"payload".to_vec(),
+            b"carrier".to_vec(),
+            CarrierType::Raw,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_subscription_window_contains() {
+        let window = SubscriptionWindow::new(ResonanceState::new(0.0, 0.0, 0.0), 1.0);
+
+        assert!(window.contains(&ResonanceState::new(0.5, 0.5, 0.0)));
+        assert!(!window.contains(&ResonanceState::new(1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_subscribe_window_rejects_invalid_radius() {
+        let engine = BroadcastEngine::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let channel_id = engine.create_channel(resonance, 0.1, 300).unwrap();
+
+        assert!(engine.subscribe_window(channel_id, resonance, 0.0).is_err());
+        assert!(engine
+            .subscribe_window(channel_id, resonance, f64::NAN)
+            .is_err());
+        assert!(engine
+            .subscribe_window(uuid::Uuid::new_v4(), resonance, 1.0)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_subscription_windows() {
+        let engine = BroadcastEngine::default();
+
+        let node_resonance = ResonanceState::new(0.0, 0.0, 0.0);
+        let channel_id = engine.create_channel(node_resonance, 0.1, 300).unwrap();
+
+        // Two overlapping bands along psi: [4.5, 5.5] and [5.0, 6.0]
+        engine
+            .subscribe_window(channel_id, ResonanceState::new(5.0, 0.0, 0.0), 0.5)
+            .unwrap();
+        engine
+            .subscribe_window(channel_id, ResonanceState::new(5.5, 0.0, 0.0), 0.5)
+            .unwrap();
+
+        let in_first = packet_at(ResonanceState::new(4.7, 0.0, 0.0));
+        let in_overlap = packet_at(ResonanceState::new(5.25, 0.0, 0.0));
+        let in_second = packet_at(ResonanceState::new(5.9, 0.0, 0.0));
+        let outside = packet_at(ResonanceState::new(7.0, 0.0, 0.0));
+        let near_node = packet_at(ResonanceState::new(0.05, 0.0, 0.0));
+
+        for packet in [&in_first, &in_overlap, &in_second, &outside, &near_node] {
+            engine.broadcast(packet.clone()).await.unwrap();
+        }
+
+        let node = NodeIdentity::new(node_resonance, None);
+        let received = engine.receive(&node, 0.1).await.unwrap();
+        let ids: Vec<uuid::Uuid> = received.iter().map(|p| p.id).collect();
+
+        assert_eq!(received.len(), 4);
+        assert!(ids.contains(&in_first.id));
+        assert!(ids.contains(&in_second.id));
+        assert!(ids.contains(&near_node.id));
+        assert!(!ids.contains(&outside.id));
+
+        // Packet in the overlap is delivered exactly once
+        assert_eq!(ids.iter().filter(|id| **id == in_overlap.id).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_does_not_leak_to_other_nodes() {
+        let engine = BroadcastEngine::default();
+
+        let subscriber = ResonanceState::new(0.0, 0.0, 0.0);
+        let channel_id = engine.create_channel(subscriber, 0.1, 300).unwrap();
+        engine
+            .subscribe_window(channel_id, ResonanceState::new(5.0, 0.0, 0.0), 0.5)
+            .unwrap();
+
+        engine
+            .broadcast(packet_at(ResonanceState::new(5.0, 0.0, 0.0)))
+            .await
+            .unwrap();
+
+        // A node far from the subscribing channel does not see the band
+        let other = NodeIdentity::new(ResonanceState::new(-3.0, 0.0, 0.0), None);
+        assert!(engine.receive(&other, 0.1).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_traffic_profile_window_is_bounded() {
+        let mut profile = TrafficProfile::default();
+        let packet = packet_at(ResonanceState::zero());
+
+        for i in 0..(TRAFFIC_PROFILE_WINDOW as u64 + 10) {
+            profile.record(&packet, i * 5);
+        }
+
+        assert_eq!(profile.sizes.len(), TRAFFIC_PROFILE_WINDOW);
+        assert_eq!(profile.inter_packet_ms.len(), TRAFFIC_PROFILE_WINDOW);
+        assert!(profile.inter_packet_ms.iter().all(|gap| *gap == 5));
+    }
+
+    #[tokio::test]
+    async fn test_decoy_sizes_match_real_distribution() {
+        use rand::{Rng, SeedableRng};
+
+        let engine = BroadcastEngine::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(108);
+
+        // Real traffic: bimodal payload sizes (small control vs large data packets)
+        let mut real_sizes = Vec::new();
+        for _ in 0..TRAFFIC_PROFILE_WINDOW {
+            let size = if rng.gen_bool(0.7) {
+                rng.gen_range(40..80)
+            } else {
+                rng.gen_range(1500..2000)
+            };
+            real_sizes.push(size as f64);
+            let packet = GhostPacket::new(
+                ResonanceState::zero(),
+                ResonanceState::zero(),
+                vec![1u8; size],
+                vec![1u8; size],
+                CarrierType::Raw,
+                None,
+            );
+            engine.broadcast(packet).await.unwrap();
+        }
+
+        let decoy_sizes: Vec<f64> = (0..4000)
+            .map(|_| {
+                engine
+                    .build_decoy_packet_with(&mut rng)
+                    .masked_payload
+                    .len() as f64
+            })
+            .collect();
+
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let fraction_small =
+            |v: &[f64]| v.iter().filter(|s| **s < 100.0).count() as f64 / v.len() as f64;
+
+        let real_mean = mean(&real_sizes);
+        let decoy_mean = mean(&decoy_sizes);
+        assert!(
+            (real_mean - decoy_mean).abs() / real_mean < 0.1,
+            "decoy mean {} too far from real mean {}",
+            decoy_mean,
+            real_mean
+        );
+        assert!((fraction_small(&real_sizes) - fraction_small(&decoy_sizes)).abs() < 0.05);
+
+        // Decoys never fall outside the observed support
+        assert!(decoy_sizes
+            .iter()
+            .all(|s| (40.0..80.0).contains(s) || (1500.0..2000.0).contains(s)));
+    }
+
+    #[tokio::test]
+    async fn test_decoys_excluded_from_traffic_profile() {
+        let engine = BroadcastEngine::default();
+
+        engine.generate_decoy_traffic(10).await.unwrap();
+
+        assert_eq!(engine.get_stats().decoy_packets, 10);
+        assert!(engine.traffic_profile().sizes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_decoy_ratio_knob() {
+        let engine = BroadcastEngine::default().with_decoy_ratio(2.5);
+        assert_eq!(engine.decoy_ratio(), 2.5);
+
+        for _ in 0..4 {
+            engine
+                .broadcast(packet_at(ResonanceState::zero()))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(engine.pending_decoys(), 10);
+        assert_eq!(engine.maintain_decoy_ratio().await.unwrap(), 10);
+        assert_eq!(engine.pending_decoys(), 0);
+        assert_eq!(engine.get_stats().decoy_packets, 10);
+    }
+
+    #[tokio::test]
+    async fn test_priority_delivery_order() {
+        let engine = BroadcastEngine::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        engine.create_channel(resonance, 0.1, 300).unwrap();
+
+        let priorities = [
+            PacketPriority::Low,
+            PacketPriority::High,
+            PacketPriority::Normal,
+            PacketPriority::Low,
+            PacketPriority::High,
+        ];
+        let mut sent = Vec::new();
+        for priority in priorities {
+            let packet = packet_at(resonance).with_priority(priority);
+            sent.push((packet.id, priority));
+            engine.broadcast(packet).await.unwrap();
+        }
+
+        let node = NodeIdentity::new(resonance, None);
+        let received = engine.receive(&node, 0.1).await.unwrap();
+        let order: Vec<uuid::Uuid> = received.iter().map(|p| p.id).collect();
+
+        // High (FIFO), then Normal, then Low (FIFO)
+        let expected = vec![sent[1].0, sent[4].0, sent[2].0, sent[0].0, sent[3].0];
+        assert_eq!(order, expected);
+    }
+
+    #[tokio::test]
+    async fn test_decoys_never_preempt_real_traffic() {
+        let engine = BroadcastEngine::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        engine.create_channel(resonance, 0.1, 300).unwrap();
+
+        let mut decoys = Vec::new();
+        for i in 0..6 {
+            let packet = packet_at(resonance);
+            let is_decoy = i % 2 == 0;
+            if is_decoy {
+                // Decoys may carry any priority on the wire
+                decoys.push(packet.id);
+                engine
+                    .broadcast_inner(packet.with_priority(PacketPriority::High), true, None)
+                    .await
+                    .unwrap();
+            } else {
+                engine
+                    .broadcast(packet.with_priority(PacketPriority::Low))
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let node = NodeIdentity::new(resonance, None);
+        let received = engine.receive(&node, 0.1).await.unwrap();
+        assert_eq!(received.len(), 6);
+
+        let decoy_positions: Vec<usize> = received
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| decoys.contains(&p.id))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(decoy_positions, vec![3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_relayed_decoy_does_not_preempt_real_traffic() {
+        let bus = crate::transport::SharedBroadcastBus::new();
+        let engine = |transport: crate::transport::BusTransport| {
+            BroadcastEngine::with_transport(
+                1000,
+                10.0,
+                60,
+                Arc::new(tokio::sync::Mutex::new(transport)),
+            )
+        };
+        let (a, b) = (engine(bus.attach()), engine(bus.attach()));
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        a.create_channel(resonance, 0.1, 300).unwrap();
+        b.create_channel(resonance, 0.1, 300).unwrap();
+        let node = NodeIdentity::new(resonance, None);
+
+        // A's decoy reaches B, which relays it back after a real packet
+        let decoy = packet_at(resonance).with_priority(PacketPriority::High);
+        a.broadcast_inner(decoy.clone(), true, None).await.unwrap();
+        let relayed = b.receive(&node, 0.1).await.unwrap();
+        assert_eq!(relayed.len(), 1);
+
+        let real = packet_at(resonance).with_priority(PacketPriority::Low);
+        b.broadcast(real.clone()).await.unwrap();
+        b.forward(relayed[0].clone()).await.unwrap();
+
+        // A knows its decoy, so the high priority does not let it overtake
+        let received = a.receive(&node, 0.1).await.unwrap();
+        let order: Vec<uuid::Uuid> = received.iter().map(|p| p.id).collect();
+        assert_eq!(order, vec![real.id, decoy.id]);
+    }
+
+    #[tokio::test]
+    async fn test_decoy_priority_follows_real_traffic() {
+        let engine = BroadcastEngine::default();
+
+        // Without real traffic decoys look like regular packets
+        assert_eq!(engine.build_decoy_packet().priority, PacketPriority::Normal);
+
+        for _ in 0..10 {
+            engine
+                .broadcast(packet_at(ResonanceState::zero()).with_priority(PacketPriority::High))
+                .await
+                .unwrap();
+        }
+
+        for _ in 0..50 {
+            assert_eq!(engine.build_decoy_packet().priority, PacketPriority::High);
+        }
+    }
+
+    #[test]
+    fn test_full_buffer_evicts_lowest_priority() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let mut buffer = VecDeque::new();
+        let buffered = |packet: &GhostPacket, is_decoy: bool| BufferedPacket {
+            packet: packet.clone(),
+            is_decoy,
+        };
+        let ids = |buffer: &VecDeque<BufferedPacket>| -> Vec<uuid::Uuid> {
+            buffer.iter().map(|p| p.packet.id).collect()
+        };
+
+        let decoy = packet_at(resonance).with_priority(PacketPriority::High);
+        let normal = packet_at(resonance);
+        enqueue_by_priority(&mut buffer, buffered(&decoy, true), 2);
+        enqueue_by_priority(&mut buffer, buffered(&normal, false), 2);
+
+        // Real packet displaces the decoy
+        let high = packet_at(resonance).with_priority(PacketPriority::High);
+        enqueue_by_priority(&mut buffer, buffered(&high, false), 2);
+        assert_eq!(ids(&buffer), vec![high.id, normal.id]);
+
+        // A decoy cannot displace real traffic
+        enqueue_by_priority(&mut buffer, buffered(&decoy, true), 2);
+        assert_eq!(ids(&buffer), vec![high.id, normal.id]);
+    }
+
     #[test]
     fn test_channel_cleanup() {
         let engine = BroadcastEngine::default();
@@ -634,6 +1602,7 @@ mod tests {
         // Broadcast 15 packets (exceeds buffer)
         for _ in 0..15 {
             let packet = GhostPacket::new(
+                resonance,
                 resonance,
                 b"test".to_vec(),
                 b"test".to_vec(),
@@ -656,6 +1625,7 @@ mod tests {
 
         // Broadcast packet
         let packet = GhostPacket::new(
+            resonance,
             resonance,
             b"test".to_vec(),
             b"test".to_vec(),
@@ -668,4 +1638,160 @@ mod tests {
         assert_eq!(stats.packets_sent, 1);
         assert_eq!(stats.channels_created, 1);
     }
+
+    #[tokio::test]
+    async fn test_forward_decrements_ttl_until_dropped() {
+        let engine = BroadcastEngine::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        engine.create_channel(resonance, 0.1, 300).unwrap();
+        let node = NodeIdentity::new(resonance, None);
+
+        let mut packet = GhostPacket::new(
+            resonance,
+            resonance,
+            b"hops".to_vec(),
+            b"hops".to_vec(),
+            CarrierType::Raw,
+            None,
+        )
+        .with_ttl(3);
+
+        // Each hop consumes one TTL unit and keeps the packet verifiable
+        for expected_ttl in (0..3).rev() {
+            assert!(engine.forward(packet).await.unwrap().is_some());
+            let received = engine.receive(&node, 0.1).await.unwrap();
+            assert_eq!(received.len(), 1);
+            packet = received.into_iter().next().unwrap();
+            assert_eq!(packet.ttl, expected_ttl);
+            assert!(packet.verify_integrity());
+        }
+        assert_eq!(engine.get_stats().packets_sent, 3);
+
+        // Forwarded three times: the next hop drops it without sending
+        assert!(engine.forward(packet).await.unwrap().is_none());
+        assert_eq!(engine.get_stats().packets_sent, 3);
+        assert!(engine.receive(&node, 0.1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_directed_broadcast_targets_nearest_channels() {
+        let engine = BroadcastEngine::default();
+        let target = ResonanceState::new(1.0, 1.0, 1.0);
+
+        // All channels match (wide epsilon) but sit at increasing distances
+        let channels: Vec<uuid::Uuid> = [0.1, 0.2, 0.3, 0.4]
+            .iter()
+            .map(|offset| {
+                engine
+                    .create_channel(ResonanceState::new(1.0 + offset, 1.0, 1.0), 1.0, 300)
+                    .unwrap()
+            })
+            .collect();
+
+        let targeted = engine
+            .broadcast_directed(packet_at(target), 2)
+            .await
+            .unwrap();
+        assert_eq!(targeted, vec![channels[0], channels[1]]);
+
+        // Only the two nearest channels buffered the packet
+        let buffered: Vec<usize> = channels
+            .iter()
+            .map(|id| engine.get_buffer_size(*id).unwrap())
+            .collect();
+        assert_eq!(buffered, vec![1, 1, 0, 0]);
+
+        let stats = engine.get_stats();
+        assert_eq!(stats.packets_sent, 1);
+        assert_eq!(stats.targeted_sends, 2);
+
+        // Regular broadcast still floods every matching channel
+        let flooded = engine.broadcast(packet_at(target)).await.unwrap();
+        assert_eq!(flooded.len(), 4);
+        assert_eq!(engine.get_stats().targeted_sends, 2);
+    }
+
+    #[tokio::test]
+    async fn test_directed_broadcast_rejected_in_transport_mode() {
+        let bus = crate::transport::SharedBroadcastBus::new();
+        let mut peer = bus.attach();
+        let engine = BroadcastEngine::with_transport(
+            1000,
+            10.0,
+            60,
+            Arc::new(tokio::sync::Mutex::new(bus.attach())),
+        );
+        let target = ResonanceState::new(1.0, 1.0, 1.0);
+        engine.create_channel(target, 1.0, 300).unwrap();
+
+        // The transport cannot address single channels, so nothing is flooded
+        assert!(engine
+            .broadcast_directed(packet_at(target), 1)
+            .await
+            .is_err());
+        let delivered =
+            tokio::time::timeout(std::time::Duration::from_millis(50), peer.receive()).await;
+        assert!(delivered.is_err());
+
+        let stats = engine.get_stats();
+        assert_eq!(stats.packets_sent, 0);
+        assert_eq!(stats.targeted_sends, 0);
+
+        // Regular broadcast still reaches the other bus member
+        engine.broadcast(packet_at(target)).await.unwrap();
+        let (_, packet) =
+            tokio::time::timeout(std::time::Duration::from_millis(50), peer.receive())
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(packet.resonance, target);
+    }
+
+    #[test]
+    fn test_decoy_resonance_follows_active_channels() {
+        assert_eq!(
+            BroadcastEngine::default().decoy_resonance(),
+            DecoyResonance::Uniform
+        );
+
+        let engine =
+            BroadcastEngine::default().with_decoy_resonance(DecoyResonance::ActiveChannels {
+                jitter: DEFAULT_DECOY_JITTER,
+            });
+        let cluster = [
+            ResonanceState::new(0.50, 0.50, 0.50),
+            ResonanceState::new(0.55, 0.48, 0.52),
+            ResonanceState::new(0.47, 0.53, 0.49),
+        ];
+        for resonance in cluster {
+            engine.create_channel(resonance, 0.1, 300).unwrap();
+        }
+        // Existing decoy channels do not attract further decoys
+        engine
+            .create_decoy_channel(ResonanceState::new(-0.9, -0.9, -0.9))
+            .unwrap();
+
+        let (lo, hi) = (0.47 - DEFAULT_DECOY_JITTER, 0.55 + DEFAULT_DECOY_JITTER);
+        for _ in 0..200 {
+            let r = engine.build_decoy_packet().resonance;
+            for c in [r.psi, r.rho, r.omega] {
+                assert!(
+                    (lo..=hi).contains(&c),
+                    "decoy component {} outside cluster",
+                    c
+                );
+            }
+        }
+
+        // Uniform decoys spread well beyond the cluster
+        let uniform = BroadcastEngine::default();
+        for resonance in cluster {
+            uniform.create_channel(resonance, 0.1, 300).unwrap();
+        }
+        let outside = (0..200)
+            .map(|_| uniform.build_decoy_packet().resonance)
+            .filter(|r| !(lo..=hi).contains(&r.psi))
+            .count();
+        assert!(outside > 100);
+    }
 }
@@ -16,12 +16,83 @@ use crate::transport::Transport;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+/// Node capability advertised in discovery beacons
+///
+/// Serialized as its lowercase string name, so beacons stay compatible
+/// with peers that still announce free-form capability strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Capability {
+    /// Persistent data storage
+    Storage,
+    /// General-purpose computation
+    Compute,
+    /// Packet relaying / forwarding
+    Relay,
+    /// Transaction validation
+    Validator,
+    /// Any capability without a dedicated variant
+    Custom(String),
+}
+
+impl Capability {
+    /// Canonical string name
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Storage => "storage",
+            Self::Compute => "compute",
+            Self::Relay => "relay",
+            Self::Validator => "validator",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl FromStr for Capability {
+    type Err = Infallible;
+
+    /// Parse a capability name; unknown names become [`Capability::Custom`]
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "storage" => Self::Storage,
+            "compute" => Self::Compute,
+            "relay" => Self::Relay,
+            "validator" => Self::Validator,
+            _ => Self::Custom(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Capability {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(capability) => capability,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl From<Capability> for String {
+    fn from(capability: Capability) -> Self {
+        capability.as_str().to_string()
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Discovery beacon - temporary resonance announcement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveryBeacon {
@@ -38,7 +109,7 @@ pub struct DiscoveryBeacon {
     pub ttl_seconds: u64,
 
     /// Optional capabilities/metadata
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
 
     /// Beacon signature (for authenticity)
     pub signature: Option<Vec<u8>>,
@@ -49,7 +120,7 @@ impl DiscoveryBeacon {
     pub fn new(
         resonance: ResonanceState,
         ttl_seconds: u64,
-        capabilities: Option<Vec<String>>,
+        capabilities: Option<Vec<Capability>>,
     ) -> Self {
         Self {
             id: Uuid::new_v4(),
@@ -191,12 +262,16 @@ pub struct DiscoveredNode {
     pub beacon_id: Uuid,
 
     /// Capabilities
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
 }
 
 impl DiscoveredNode {
     /// Create new discovered node entry
-    pub fn new(identity: NodeIdentity, beacon_id: Uuid, capabilities: Option<Vec<String>>) -> Self {
+    pub fn new(
+        identity: NodeIdentity,
+        beacon_id: Uuid,
+        capabilities: Option<Vec<Capability>>,
+    ) -> Self {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -309,15 +384,6 @@ impl DiscoveryEngine {
         }
     }
 
-    /// Create with default settings (local only)
-    pub fn default() -> Self {
-        Self::new(
-            300, // 5 minute node timeout
-            120, // 2 minute beacon TTL
-            0.2, // Discovery epsilon (wider than normal)
-        )
-    }
-
     /// Announce presence via beacon
     ///
     /// If transport is configured, broadcasts beacon to network.
@@ -325,19 +391,17 @@ impl DiscoveryEngine {
     pub async fn announce(
         &self,
         identity: &NodeIdentity,
-        capabilities: Option<Vec<String>>,
+        capabilities: Option<Vec<Capability>>,
     ) -> Result<Uuid> {
         let beacon = DiscoveryBeacon::new(identity.resonance, self.beacon_ttl, capabilities);
 
         let beacon_id = beacon.id;
 
         // Store beacon locally
-        let mut beacons = self
-            .beacons
+        self.beacons
             .write()
-            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on beacons: {}", e))?;
-        beacons.insert(beacon_id, beacon.clone());
-        drop(beacons); // Release lock
+            .map_err(|e| anyhow::anyhow!("Failed to acquire write lock on beacons: {}", e))?
+            .insert(beacon_id, beacon.clone());
 
         // If we have transport, broadcast beacon to network
         if let Some(ref transport) = self.transport {
@@ -385,24 +449,20 @@ impl DiscoveryEngine {
             let mut beacons_received = 0;
 
             // Try to receive multiple beacons (non-blocking)
-            loop {
-                match tokio::time::timeout(
-                    std::time::Duration::from_millis(10), // Short timeout
-                    t.receive(),
-                )
-                .await
+            // Stops at the first timeout or error - no more beacons
+            while let Ok(Ok((_peer_id, packet))) = tokio::time::timeout(
+                std::time::Duration::from_millis(10), // Short timeout
+                t.receive(),
+            )
+            .await
+            {
+                // Try to deserialize beacon from packet payload
+                if let Ok(beacon) =
+                    serde_json::from_slice::<DiscoveryBeacon>(&packet.masked_payload)
                 {
-                    Ok(Ok((_peer_id, packet))) => {
-                        // Try to deserialize beacon from packet payload
-                        if let Ok(beacon) =
-                            serde_json::from_slice::<DiscoveryBeacon>(&packet.masked_payload)
-                        {
-                            if self.receive_beacon(beacon).is_ok() {
-                                beacons_received += 1;
-                            }
-                        }
+                    if self.receive_beacon(beacon).is_ok() {
+                        beacons_received += 1;
                     }
-                    _ => break, // Timeout or error - no more beacons
                 }
             }
 
@@ -504,7 +564,10 @@ impl DiscoveryEngine {
     }
 
     /// Find nodes with specific capabilities
-    pub fn find_nodes_with_capabilities(&self, required_caps: &[String]) -> Vec<DiscoveredNode> {
+    pub fn find_nodes_with_capabilities(
+        &self,
+        required_caps: &[Capability],
+    ) -> Vec<DiscoveredNode> {
         let discovered = self.discovered_nodes.read().unwrap_or_else(|e| {
             eprintln!(
                 "Warning: RwLock poisoned in find_nodes_with_capabilities: {}",
@@ -590,6 +653,17 @@ impl DiscoveryEngine {
             .collect()
     }
 
+    /// Number of tracked nodes, including inactive ones not yet cleaned up
+    pub fn tracked_node_count(&self) -> usize {
+        self.discovered_nodes
+            .read()
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: RwLock poisoned in tracked_node_count: {}", e);
+                e.into_inner()
+            })
+            .len()
+    }
+
     /// Cleanup expired beacons and inactive nodes
     pub fn cleanup(&self) -> Result<(usize, usize)> {
         // Cleanup expired beacons
@@ -636,6 +710,15 @@ impl DiscoveryEngine {
             .clone()
     }
 
+    /// Reset statistics
+    pub fn reset_stats(&self) {
+        let mut stats = self.stats.write().unwrap_or_else(|e| {
+            eprintln!("Warning: RwLock poisoned in reset_stats: {}", e);
+            e.into_inner()
+        });
+        *stats = DiscoveryStats::default();
+    }
+
     /// Get active node count
     pub fn active_node_count(&self) -> usize {
         self.get_active_nodes().len()
@@ -651,6 +734,28 @@ impl DiscoveryEngine {
     }
 }
 
+impl std::fmt::Debug for DiscoveryEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiscoveryEngine")
+            .field("node_timeout", &self.node_timeout)
+            .field("beacon_ttl", &self.beacon_ttl)
+            .field("discovery_epsilon", &self.discovery_epsilon)
+            .field("has_transport", &self.transport.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for DiscoveryEngine {
+    /// Create with default settings (local only)
+    fn default() -> Self {
+        Self::new(
+            300, // 5 minute node timeout
+            120, // 2 minute beacon TTL
+            0.2, // Discovery epsilon (wider than normal)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -684,7 +789,7 @@ mod tests {
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
         let identity = NodeIdentity::new(resonance, None);
 
-        let beacon_id = engine.announce(&identity, None).await.unwrap();
+        engine.announce(&identity, None).await.unwrap();
         assert_eq!(engine.active_beacon_count(), 1);
 
         let stats = engine.get_stats();
@@ -737,24 +842,69 @@ mod tests {
 
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
 
-        let caps1 = Some(vec!["storage".to_string(), "compute".to_string()]);
+        let caps1 = Some(vec![Capability::Storage, Capability::Compute]);
         let beacon1 = DiscoveryBeacon::new(resonance, 300, caps1);
         engine.receive_beacon(beacon1).unwrap();
 
-        let caps2 = Some(vec!["storage".to_string()]);
+        let caps2 = Some(vec![Capability::Storage]);
         let beacon2 = DiscoveryBeacon::new(resonance, 300, caps2);
         engine.receive_beacon(beacon2).unwrap();
 
         // Find nodes with storage capability
-        let found = engine.find_nodes_with_capabilities(&["storage".to_string()]);
+        let found = engine.find_nodes_with_capabilities(&[Capability::Storage]);
         assert_eq!(found.len(), 2);
 
         // Find nodes with both storage and compute
         let found =
-            engine.find_nodes_with_capabilities(&["storage".to_string(), "compute".to_string()]);
+            engine.find_nodes_with_capabilities(&[Capability::Storage, Capability::Compute]);
         assert_eq!(found.len(), 1);
     }
 
+    #[test]
+    fn test_capability_parsing() {
+        assert_eq!(
+            "storage".parse::<Capability>().unwrap(),
+            Capability::Storage
+        );
+        assert_eq!(
+            "Compute".parse::<Capability>().unwrap(),
+            Capability::Compute
+        );
+        assert_eq!(
+            "gpu-inference".parse::<Capability>().unwrap(),
+            Capability::Custom("gpu-inference".to_string())
+        );
+        assert_eq!(Capability::Relay.to_string(), "relay");
+    }
+
+    #[test]
+    fn test_capabilities_serialize_as_strings() {
+        let beacon = DiscoveryBeacon::new(
+            ResonanceState::new(1.0, 1.0, 1.0),
+            300,
+            Some(vec![
+                Capability::Storage,
+                Capability::Custom("archive".to_string()),
+            ]),
+        );
+
+        let json = serde_json::to_value(&beacon).unwrap();
+        assert_eq!(
+            json["capabilities"],
+            serde_json::json!(["storage", "archive"])
+        );
+
+        // Beacons from peers announcing plain strings still deserialize
+        let decoded: DiscoveryBeacon = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.capabilities,
+            Some(vec![
+                Capability::Storage,
+                Capability::Custom("archive".to_string())
+            ])
+        );
+    }
+
     #[test]
     fn test_discovery_event() {
         let pattern = vec![
@@ -773,16 +923,26 @@ mod tests {
         let engine = DiscoveryEngine::new(1, 1, 0.2); // 1 second timeout
 
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
-        let mut beacon = DiscoveryBeacon::new(resonance, 1, None);
-
-        // Expire beacon
-        beacon.timestamp -= 10;
+        let beacon = DiscoveryBeacon::new(resonance, 1, None);
+        let beacon_id = beacon.id;
 
+        // Expired beacons are rejected on receipt, so age it once stored
         engine.receive_beacon(beacon).unwrap();
+        engine
+            .beacons
+            .write()
+            .unwrap()
+            .get_mut(&beacon_id)
+            .unwrap()
+            .timestamp -= 10;
+        for node in engine.discovered_nodes.write().unwrap().values_mut() {
+            node.last_seen -= 10;
+        }
 
         // Cleanup should remove expired beacon and inactive node
         let (beacons_removed, nodes_removed) = engine.cleanup().unwrap();
-        assert!(beacons_removed > 0 || nodes_removed > 0);
+        assert_eq!(beacons_removed, 1);
+        assert_eq!(nodes_removed, 1);
     }
 
     #[test]
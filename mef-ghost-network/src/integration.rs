@@ -6,30 +6,52 @@
  * - Broadcasting (resonance-based routing)
  * - Discovery (temporary beacons)
  * - Ghost Protocol (6-step flow)
+ * - Fork healing (MEF-Attractor) for conflicting commits
  */
 
 use crate::broadcasting::BroadcastEngine;
-use crate::discovery::DiscoveryEngine;
+use crate::discovery::{Capability, DiscoveryEngine};
 use crate::packet::{GhostPacket, GhostTransaction, NodeIdentity, ResonanceState};
-use crate::protocol::{GhostProtocol, MaskingParams, ProtocolConfig};
-use crate::transport::{Libp2pTransport, PeerId, Transport, TransportConfig};
-use anyhow::{Context, Result};
+use crate::protocol::{CommitReceipt, GhostProtocol, MaskingParams, ProtocolConfig};
+use crate::transport::{
+    Libp2pTransport, PeerId, PeerManager, PeerViolation, Transport, TransportConfig,
+};
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
-use std::sync::Arc;
+use ed25519_dalek::SigningKey;
+use mef_fork_healing::{Block, ForkHealer};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
 use tokio::sync::Mutex;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Wrapper to enable passing Arc<Mutex<Libp2pTransport>> as Arc<Mutex<dyn Transport>>
 ///
 /// This wrapper is necessary because Rust cannot unsize through Mutex<T> to Mutex<dyn Trait>.
 /// We wrap the Arc<Mutex<Libp2pTransport>> and implement Transport by delegating all methods.
+/// The synchronous methods go through the shared peer manager instead, so
+/// they work while another task holds the transport lock.
 struct TransportWrapper {
     inner: Arc<Mutex<Libp2pTransport>>,
+    peer_manager: Arc<RwLock<PeerManager>>,
+    local_peer_id: PeerId,
 }
 
 impl TransportWrapper {
-    fn new(transport: Arc<Mutex<Libp2pTransport>>) -> Self {
-        Self { inner: transport }
+    fn new(transport: Libp2pTransport) -> Self {
+        Self {
+            peer_manager: transport.peer_manager(),
+            local_peer_id: transport.local_peer_id(),
+            inner: Arc::new(Mutex::new(transport)),
+        }
+    }
+
+    /// Read access to the peer manager, recovering from a poisoned lock
+    fn peer_manager(&self) -> std::sync::RwLockReadGuard<'_, PeerManager> {
+        self.peer_manager.read().unwrap_or_else(|e| {
+            warn!("Peer manager lock poisoned: {}", e);
+            e.into_inner()
+        })
     }
 }
 
@@ -56,19 +78,15 @@ impl Transport for TransportWrapper {
     }
 
     fn peers(&self) -> Vec<PeerId> {
-        // For non-async methods, we need to use try_lock or block_on
-        // Since we're in a sync context, we'll use try_lock with fallback
-        self.inner
-            .try_lock()
-            .map(|guard| guard.peers())
-            .unwrap_or_default()
+        self.peer_manager().get_all_peers()
     }
 
     fn local_peer_id(&self) -> PeerId {
-        self.inner
-            .try_lock()
-            .map(|guard| guard.local_peer_id())
-            .unwrap_or_default()
+        self.local_peer_id
+    }
+
+    fn report_violation(&self, peer: PeerId, violation: PeerViolation) -> bool {
+        self.peer_manager().record_violation(peer, violation)
     }
 
     async fn shutdown(&mut self) -> Result<()> {
@@ -97,6 +115,20 @@ pub struct GhostNetworkNode {
 
     /// Main broadcast channel ID
     main_channel_id: uuid::Uuid,
+
+    /// Fork healer resolving competing commits at the same height, with the
+    /// shared field resonance it scores candidates against
+    fork_healer: Option<(Arc<ForkHealer>, ResonanceState)>,
+
+    /// Committed blocks by logical height
+    committed: Arc<RwLock<BTreeMap<u64, CommittedBlock>>>,
+}
+
+/// Transaction committed at a height, with the hash of its block
+#[derive(Debug, Clone)]
+struct CommittedBlock {
+    tx: GhostTransaction,
+    block_hash: Vec<u8>,
 }
 
 impl GhostNetworkNode {
@@ -109,10 +141,27 @@ impl GhostNetworkNode {
     ///
     /// # Returns
     /// * Fully initialized Ghost Network node ready for communication
+    ///
+    /// Commit receipts are signed with a random key; use
+    /// [`with_node_key`](Self::with_node_key) to keep the key across restarts.
     pub async fn new(
         resonance: ResonanceState,
         transport_config: TransportConfig,
         protocol_config: ProtocolConfig,
+    ) -> Result<Self> {
+        let node_key = SigningKey::from_bytes(&rand::random::<[u8; 32]>());
+        Self::with_node_key(resonance, transport_config, protocol_config, node_key).await
+    }
+
+    /// Create a new Ghost Network node that signs commit receipts with `node_key`
+    ///
+    /// Receipts stay verifiable against the same public key as long as the
+    /// node is restarted with the same (persisted) key.
+    pub async fn with_node_key(
+        resonance: ResonanceState,
+        transport_config: TransportConfig,
+        protocol_config: ProtocolConfig,
+        node_key: SigningKey,
     ) -> Result<Self> {
         // Create node identity
         let identity = NodeIdentity::new(resonance, None);
@@ -124,17 +173,17 @@ impl GhostNetworkNode {
         );
 
         // Create libp2p transport
-        let transport = Arc::new(Mutex::new(
+        let wrapper = TransportWrapper::new(
             Libp2pTransport::new(transport_config)
                 .await
                 .context("Failed to create libp2p transport")?,
-        ));
+        );
+        let transport = wrapper.inner.clone();
 
         // Create trait object wrapper for broadcast and discovery
-        // We wrap the Arc<Mutex<Libp2pTransport>> in TransportWrapper which implements Transport
+        // TransportWrapper implements Transport over the Arc<Mutex<Libp2pTransport>>
         // Then wrap that in Arc<Mutex<dyn Transport>> for sharing between engines
-        let transport_trait: Arc<Mutex<dyn Transport>> =
-            Arc::new(Mutex::new(TransportWrapper::new(transport.clone())));
+        let transport_trait: Arc<Mutex<dyn Transport>> = Arc::new(Mutex::new(wrapper));
 
         // Create broadcast engine with transport
         let broadcast = Arc::new(BroadcastEngine::with_transport(
@@ -153,7 +202,7 @@ impl GhostNetworkNode {
         ));
 
         // Create protocol
-        let protocol = Arc::new(GhostProtocol::new(protocol_config));
+        let protocol = Arc::new(GhostProtocol::new(protocol_config).with_node_key(node_key));
 
         // Create main broadcast channel matching node's resonance
         let main_channel_id = broadcast.create_channel(
@@ -175,9 +224,36 @@ impl GhostNetworkNode {
             discovery,
             protocol,
             main_channel_id,
+            fork_healer: None,
+            committed: Arc::new(RwLock::new(BTreeMap::new())),
         })
     }
 
+    /// Attach a fork healer for resolving conflicting commits
+    ///
+    /// Without a fork healer, competing candidates at the same height are
+    /// rejected by [`commit_transactions`](Self::commit_transactions).
+    ///
+    /// Candidates are scored against `field`, not against this node's own
+    /// resonance. Every node must be configured with the same field (e.g.
+    /// from network configuration); otherwise nodes seeing the same
+    /// candidates may commit different winners.
+    ///
+    /// Fails if the healer requires signed blocks: fork candidates are built
+    /// from received transactions, which carry no block signature, so every
+    /// candidate would be discarded.
+    pub fn with_fork_healer(
+        mut self,
+        fork_healer: ForkHealer,
+        field: ResonanceState,
+    ) -> Result<Self> {
+        if fork_healer.config().require_signatures {
+            anyhow::bail!("Fork healer requires signed blocks, but commit candidates are unsigned");
+        }
+        self.fork_healer = Some((Arc::new(fork_healer), field));
+        Ok(self)
+    }
+
     /// Get node's peer ID
     pub async fn peer_id(&self) -> PeerId {
         self.transport.lock().await.local_peer_id()
@@ -188,6 +264,16 @@ impl GhostNetworkNode {
         self.identity.resonance
     }
 
+    /// Public key that verifies this node's commit receipts
+    pub fn node_public_key(&self) -> ed25519_dalek::VerifyingKey {
+        self.protocol.node_public_key()
+    }
+
+    /// Get the ID of the node's main broadcast channel
+    pub fn main_channel_id(&self) -> uuid::Uuid {
+        self.main_channel_id
+    }
+
     /// Listen on an address
     pub async fn listen(&mut self, addr: String) -> Result<()> {
         info!(event = "node_listening", addr = %addr, "Starting to listen");
@@ -203,7 +289,7 @@ impl GhostNetworkNode {
     /// Announce presence to the network
     ///
     /// Broadcasts a discovery beacon so other nodes can find this node.
-    pub async fn announce(&self, capabilities: Option<Vec<String>>) -> Result<uuid::Uuid> {
+    pub async fn announce(&self, capabilities: Option<Vec<Capability>>) -> Result<uuid::Uuid> {
         info!(
             event = "node_announcing",
             capabilities = ?capabilities,
@@ -260,6 +346,39 @@ impl GhostNetworkNode {
             self.protocol
                 .create_transaction(self.identity.resonance, target_resonance, action)?;
 
+        self.send(transaction).await
+    }
+
+    /// Send a transaction claiming a logical commit height
+    ///
+    /// Like [`send_transaction`](Self::send_transaction), but receivers
+    /// commit the transaction at `height` and resolve competing claims for
+    /// the same height with their fork healer.
+    pub async fn send_commit(
+        &self,
+        target_resonance: ResonanceState,
+        height: u64,
+        action: Vec<u8>,
+    ) -> Result<Vec<uuid::Uuid>> {
+        info!(
+            event = "commit_sending",
+            height,
+            action_size = action.len(),
+            "Sending commit claim via Ghost Protocol"
+        );
+
+        let transaction = self
+            .protocol
+            .create_transaction(self.identity.resonance, target_resonance, action)?
+            .with_commit_height(height);
+
+        self.send(transaction).await
+    }
+
+    /// Mask, embed and broadcast a created transaction (steps 2-5)
+    async fn send(&self, transaction: GhostTransaction) -> Result<Vec<uuid::Uuid>> {
+        let target_resonance = transaction.target_resonance;
+
         debug!(
             event = "transaction_created",
             tx_id = %transaction.id,
@@ -326,10 +445,15 @@ impl GhostNetworkNode {
     /// - Extracts from carrier
     /// - Unmasks with M⁻¹_{θ,σ}
     /// - Verifies ZK proof
+    /// - Commits transactions that claim a height (see
+    ///   [`send_commit`](Self::send_commit))
     /// - Returns validated transactions
     pub async fn receive_transactions(&self) -> Result<Vec<GhostTransaction>> {
         // Receive packets via resonance-based routing
-        let packets = self.broadcast.receive(&self.identity).await?;
+        let packets = self
+            .broadcast
+            .receive(&self.identity, self.protocol.effective_epsilon())
+            .await?;
 
         debug!(
             event = "packets_received",
@@ -337,6 +461,13 @@ impl GhostNetworkNode {
             "Received packets from network"
         );
 
+        Ok(self.process_packets(packets).await)
+    }
+
+    /// Validate received packets and commit the height claims among them
+    ///
+    /// Rejections caused by the sender are charged to the delivering peer.
+    async fn process_packets(&self, packets: Vec<GhostPacket>) -> Vec<GhostTransaction> {
         let mut transactions = Vec::new();
 
         // Process each packet through Ghost Protocol
@@ -369,11 +500,177 @@ impl GhostNetworkNode {
                         error = %e,
                         "Packet validation failed"
                     );
+                    crate::report_rejection(&self.broadcast, packet.id, &e).await;
                 }
             }
         }
 
-        Ok(transactions)
+        self.commit_received(&transactions);
+        transactions
+    }
+
+    /// Commit received height claims, grouped by height
+    ///
+    /// Competing claims in the same batch go to the fork healer together.
+    /// A claim for the committed tip height challenges the committed
+    /// transaction (see [`commit_transactions`](Self::commit_transactions)),
+    /// so the winner does not depend on which batch a claim arrived in.
+    fn commit_received(&self, transactions: &[GhostTransaction]) {
+        let mut by_height: BTreeMap<u64, Vec<GhostTransaction>> = BTreeMap::new();
+        for tx in transactions {
+            if let Some(height) = tx.commit_height {
+                by_height.entry(height).or_default().push(tx.clone());
+            }
+        }
+
+        for (height, candidates) in by_height {
+            if let Err(e) = self.commit_transactions(height, candidates) {
+                warn!(
+                    event = "commit_rejected",
+                    height,
+                    error = %e,
+                    "Received commit claims were not committed"
+                );
+            }
+        }
+    }
+
+    /// Commit one transaction at a logical height
+    ///
+    /// A single candidate is committed directly. Competing candidates are
+    /// turned into fork blocks and handed to the fork healer; only the
+    /// winner chosen by the MEF-Attractor (highest coherence with the shared
+    /// fork field, see [`with_fork_healer`](Self::with_fork_healer)) is
+    /// committed, the others are dropped.
+    ///
+    /// If the height is the committed tip, the committed transaction joins
+    /// the candidates and the fork is resolved again. A winning challenger
+    /// replaces it and gets a fresh receipt. Heights below the tip are
+    /// final, since later heights link to them.
+    ///
+    /// # Arguments
+    /// * `height` - Logical height the candidates compete for
+    /// * `candidates` - Validated transactions claiming that height
+    ///
+    /// # Returns
+    /// * Receipt for the committed transaction, signed with the node key
+    ///   (see [`node_public_key`](Self::node_public_key)); its block hash is
+    ///   the digest the next height links to
+    /// * `Err` if there are no candidates, the committed transaction at the
+    ///   height wins again (or no fork healer is attached to challenge it),
+    ///   the height is below the committed tip, or there are several
+    ///   candidates and no fork healer is attached
+    pub fn commit_transactions(
+        &self,
+        height: u64,
+        mut candidates: Vec<GhostTransaction>,
+    ) -> Result<CommitReceipt> {
+        let mut committed = self
+            .committed
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock on committed: {}", e))?;
+
+        let incumbent = committed.get(&height).map(|block| block.tx.id);
+        if let Some(incumbent) = incumbent {
+            candidates.retain(|tx| tx.id != incumbent);
+            if candidates.is_empty() || self.fork_healer.is_none() {
+                anyhow::bail!("Height {} is already committed", height);
+            }
+            // Later heights link to the incumbent; replacing it would orphan them
+            if let Some((&tip, _)) = committed.last_key_value() {
+                if tip > height {
+                    anyhow::bail!(
+                        "Height {} is below the committed tip {} and cannot be challenged",
+                        height,
+                        tip
+                    );
+                }
+            }
+            candidates.push(committed[&height].tx.clone());
+        }
+
+        let prev_hash = height
+            .checked_sub(1)
+            .and_then(|prev| committed.get(&prev))
+            .map(|block| block.block_hash.clone())
+            .unwrap_or_else(|| vec![0; 32]);
+
+        let winner = match candidates.len() {
+            0 => anyhow::bail!("No candidates to commit at height {}", height),
+            1 => candidates.into_iter().next().unwrap(),
+            count => {
+                let (healer, field) = self.fork_healer.as_ref().ok_or_else(|| {
+                    anyhow!(
+                        "{} conflicting commits at height {} and no fork healer attached",
+                        count,
+                        height
+                    )
+                })?;
+
+                let blocks = candidates
+                    .iter()
+                    .map(|tx| fork_block(tx, height, &prev_hash))
+                    .collect();
+
+                let field =
+                    mef_fork_healing::ResonanceState::new(field.psi, field.rho, field.omega);
+                let block = healer.resolve_fork(blocks, field)?;
+
+                warn!(
+                    event = "fork_resolved",
+                    height,
+                    candidates = count,
+                    winner = %block.id,
+                    "Resolved conflicting commits via fork healer"
+                );
+
+                candidates
+                    .into_iter()
+                    .find(|tx| tx.id == block.id)
+                    .ok_or_else(|| anyhow!("Fork winner {} is not a candidate", block.id))?
+            }
+        };
+
+        if let Some(incumbent) = incumbent {
+            if winner.id == incumbent {
+                anyhow::bail!("Height {} is already committed to {}", height, incumbent);
+            }
+            warn!(
+                event = "commit_replaced",
+                height,
+                replaced = %incumbent,
+                tx_id = %winner.id,
+                "Challenger replaced the committed transaction"
+            );
+        }
+
+        info!(
+            event = "transaction_committed",
+            height,
+            tx_id = %winner.id,
+            "Transaction committed"
+        );
+
+        let block_hash = fork_block(&winner, height, &prev_hash).hash();
+        let receipt = self
+            .protocol
+            .sign_commit_receipt(winner.id, block_hash.clone(), height);
+        committed.insert(
+            height,
+            CommittedBlock {
+                tx: winner,
+                block_hash,
+            },
+        );
+        Ok(receipt)
+    }
+
+    /// Get the transaction committed at a height
+    pub fn committed_at(&self, height: u64) -> Option<GhostTransaction> {
+        self.committed
+            .read()
+            .ok()
+            .and_then(|committed| committed.get(&height).map(|block| block.tx.clone()))
     }
 
     /// Generate decoy traffic for privacy
@@ -383,6 +680,17 @@ impl GhostNetworkNode {
         self.broadcast.generate_decoy_traffic(count).await
     }
 
+    /// Cleanup expired channels, inactive nodes and stale replay records
+    ///
+    /// Should be called periodically.
+    pub fn cleanup(&self) -> Result<()> {
+        self.broadcast.cleanup_expired_channels()?;
+        self.discovery.cleanup()?;
+        self.protocol.cleanup_seen_packets();
+        self.protocol.cleanup_rate_limiters();
+        Ok(())
+    }
+
     /// Get broadcast statistics
     pub fn broadcast_stats(&self) -> crate::broadcasting::BroadcastStats {
         self.broadcast.get_stats()
@@ -408,9 +716,40 @@ impl GhostNetworkNode {
     }
 }
 
+impl std::fmt::Debug for GhostNetworkNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GhostNetworkNode")
+            .field("identity", &self.identity)
+            .field("main_channel_id", &self.main_channel_id)
+            .field("has_fork_healer", &self.fork_healer.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Build a fork candidate block from a transaction
+///
+/// The block carries the transaction's ID and sender resonance, so the
+/// attractor scores it by how well the sender resonates with the field.
+fn fork_block(tx: &GhostTransaction, height: u64, prev_hash: &[u8]) -> Block {
+    Block {
+        id: tx.id,
+        height,
+        resonance: mef_fork_healing::ResonanceState::new(
+            tx.sender_resonance.psi,
+            tx.sender_resonance.rho,
+            tx.sender_resonance.omega,
+        ),
+        prev_hash: prev_hash.to_vec(),
+        data: tx.to_bytes(),
+        timestamp: tx.timestamp,
+        signature: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transport::bus::ScoredBus;
 
     #[tokio::test]
     async fn test_node_creation() {
@@ -436,11 +775,437 @@ mod tests {
             .unwrap();
 
         // Announce presence
-        let beacon_id = node.announce(Some(vec!["test".to_string()])).await.unwrap();
+        let beacon_id = node
+            .announce(Some(vec![Capability::Custom("test".to_string())]))
+            .await
+            .unwrap();
 
-        assert!(beacon_id.to_string().len() > 0);
+        assert!(!beacon_id.to_string().is_empty());
 
         let stats = node.discovery_stats();
         assert_eq!(stats.beacons_sent, 1);
     }
+
+    #[tokio::test]
+    async fn test_conflicting_commits_resolved_by_fork_healer() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let protocol = GhostProtocol::default();
+        let near = protocol
+            .create_transaction(ResonanceState::new(1.1, 1.0, 1.0), resonance, b"a".to_vec())
+            .unwrap();
+        let far = protocol
+            .create_transaction(ResonanceState::new(5.0, 5.0, 5.0), resonance, b"b".to_vec())
+            .unwrap();
+
+        // Without a fork healer, conflicting commits are refused
+        assert!(node
+            .commit_transactions(7, vec![near.clone(), far.clone()])
+            .is_err());
+        assert!(node.committed_at(7).is_none());
+
+        let node = node
+            .with_fork_healer(ForkHealer::default(), resonance)
+            .unwrap();
+        let receipt = node
+            .commit_transactions(7, vec![far.clone(), near.clone()])
+            .unwrap();
+
+        // The attractor picks the sender closest to the node's resonance
+        assert_eq!(receipt.transaction_id, near.id);
+        assert_eq!(node.committed_at(7).unwrap().id, near.id);
+
+        // A later challenge by the loser does not displace the winner
+        assert!(node.commit_transactions(7, vec![far]).is_err());
+        assert_eq!(node.committed_at(7).unwrap().id, near.id);
+    }
+
+    /// Mask, embed and wrap a transaction the way `send` does
+    fn seal(protocol: &GhostProtocol, tx: &GhostTransaction) -> GhostPacket {
+        let params = MaskingParams::from_resonance(&tx.sender_resonance, &tx.target_resonance);
+        let masked = protocol.mask_transaction(tx, &params).unwrap();
+        let carrier = protocol
+            .embed_transaction(&masked, crate::packet::CarrierType::Raw)
+            .unwrap();
+        protocol
+            .create_packet(
+                tx,
+                masked,
+                carrier,
+                crate::packet::CarrierType::Raw,
+                &params,
+            )
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_received_commit_claims_resolved_by_fork_healer() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap()
+        .with_fork_healer(ForkHealer::default(), resonance)
+        .unwrap();
+
+        let protocol = GhostProtocol::default();
+        let near = protocol
+            .create_transaction(ResonanceState::new(1.1, 1.0, 1.0), resonance, b"a".to_vec())
+            .unwrap()
+            .with_commit_height(3);
+        let far = protocol
+            .create_transaction(ResonanceState::new(5.0, 5.0, 5.0), resonance, b"b".to_vec())
+            .unwrap()
+            .with_commit_height(3);
+        let plain = protocol
+            .create_transaction(resonance, resonance, b"c".to_vec())
+            .unwrap();
+
+        let packets = [&far, &near, &plain]
+            .into_iter()
+            .map(|tx| seal(&protocol, tx))
+            .collect();
+        let received = node.process_packets(packets).await;
+
+        // All valid transactions are returned, claims or not
+        let ids: Vec<_> = received.iter().map(|tx| tx.id).collect();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains(&near.id) && ids.contains(&far.id) && ids.contains(&plain.id));
+
+        // Only the winning claim is committed at the contested height
+        assert_eq!(node.committed_at(3).unwrap().id, near.id);
+        assert!(node.committed_at(0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_commits_in_separate_batches_resolved_by_attractor() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let protocol = GhostProtocol::default();
+        let near = protocol
+            .create_transaction(ResonanceState::new(1.1, 1.0, 1.0), resonance, b"a".to_vec())
+            .unwrap()
+            .with_commit_height(5);
+        let far = protocol
+            .create_transaction(ResonanceState::new(5.0, 5.0, 5.0), resonance, b"b".to_vec())
+            .unwrap()
+            .with_commit_height(5);
+
+        // Same outcome whichever claim arrives first
+        for order in [[&far, &near], [&near, &far]] {
+            let bus = crate::SharedBroadcastBus::new();
+            let mut sender = bus.attach();
+            let mut node = GhostNetworkNode::new(
+                resonance,
+                TransportConfig::local(),
+                ProtocolConfig::default(),
+            )
+            .await
+            .unwrap()
+            .with_fork_healer(ForkHealer::default(), resonance)
+            .unwrap();
+            node.broadcast = Arc::new(BroadcastEngine::with_transport(
+                1000,
+                10.0,
+                60,
+                Arc::new(Mutex::new(bus.attach())),
+            ));
+
+            for tx in order {
+                sender.broadcast(seal(&protocol, tx)).await.unwrap();
+                assert_eq!(node.receive_transactions().await.unwrap().len(), 1);
+            }
+
+            assert_eq!(node.committed_at(5).unwrap().id, near.id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nodes_with_different_resonance_pick_same_winner() {
+        let field = ResonanceState::new(1.0, 1.0, 1.0);
+        let protocol = GhostProtocol::default();
+        let near = protocol
+            .create_transaction(ResonanceState::new(1.1, 1.0, 1.0), field, b"a".to_vec())
+            .unwrap();
+        let far = protocol
+            .create_transaction(ResonanceState::new(5.0, 5.0, 5.0), field, b"b".to_vec())
+            .unwrap();
+
+        // The second node sits right next to the losing sender
+        let mut winners = Vec::new();
+        for resonance in [field, ResonanceState::new(5.0, 5.0, 5.0)] {
+            let node = GhostNetworkNode::new(
+                resonance,
+                TransportConfig::local(),
+                ProtocolConfig::default(),
+            )
+            .await
+            .unwrap()
+            .with_fork_healer(ForkHealer::default(), field)
+            .unwrap();
+
+            let receipt = node
+                .commit_transactions(2, vec![far.clone(), near.clone()])
+                .unwrap();
+            winners.push(receipt.transaction_id);
+        }
+
+        assert_eq!(winners, vec![near.id, near.id]);
+    }
+
+    #[tokio::test]
+    async fn test_challenge_below_committed_tip_rejected() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap()
+        .with_fork_healer(ForkHealer::default(), resonance)
+        .unwrap();
+
+        let protocol = GhostProtocol::default();
+        let tx = |sender: ResonanceState, action: &[u8]| {
+            protocol
+                .create_transaction(sender, resonance, action.to_vec())
+                .unwrap()
+        };
+        let far = tx(ResonanceState::new(5.0, 5.0, 5.0), b"far");
+        let next = tx(resonance, b"next");
+        let near = tx(ResonanceState::new(1.1, 1.0, 1.0), b"near");
+
+        node.commit_transactions(1, vec![far.clone()]).unwrap();
+        node.commit_transactions(2, vec![next]).unwrap();
+
+        // Height 2 links to `far`, so the stronger challenger is refused
+        assert!(node.commit_transactions(1, vec![near]).is_err());
+        assert_eq!(node.committed_at(1).unwrap().id, far.id);
+    }
+
+    #[tokio::test]
+    async fn test_fork_healer_requiring_signatures_is_rejected() {
+        let node = GhostNetworkNode::new(
+            ResonanceState::new(1.0, 1.0, 1.0),
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let healer = ForkHealer::new(mef_fork_healing::AttractorConfig {
+            require_signatures: true,
+            ..Default::default()
+        });
+
+        // Fork candidates are unsigned, so such a healer would reject them all
+        assert!(node
+            .with_fork_healer(healer, ResonanceState::new(1.0, 1.0, 1.0))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_commit_receipt_verifies_against_node_key() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let tx = GhostProtocol::default()
+            .create_transaction(resonance, resonance, b"action".to_vec())
+            .unwrap();
+        let receipt = node.commit_transactions(4, vec![tx.clone()]).unwrap();
+
+        // The receipt attests the block that was actually committed
+        assert_eq!(receipt.transaction_id, tx.id);
+        assert_eq!(receipt.height, 4);
+        assert_eq!(
+            receipt.block_hash,
+            fork_block(&node.committed_at(4).unwrap(), 4, &[0; 32]).hash()
+        );
+        assert!(crate::verify_commit_receipt(
+            &receipt,
+            &node.node_public_key()
+        ));
+
+        // Another node's key does not verify it
+        let other = GhostProtocol::default();
+        assert!(!crate::verify_commit_receipt(
+            &receipt,
+            &other.node_public_key()
+        ));
+
+        // Any modified field breaks the signature
+        let mut forged = receipt.clone();
+        forged.block_hash[0] ^= 0xff;
+        assert!(!crate::verify_commit_receipt(
+            &forged,
+            &node.node_public_key()
+        ));
+
+        let mut forged = receipt.clone();
+        forged.height += 1;
+        assert!(!crate::verify_commit_receipt(
+            &forged,
+            &node.node_public_key()
+        ));
+
+        let mut forged = receipt;
+        forged.signature.truncate(10);
+        assert!(!crate::verify_commit_receipt(
+            &forged,
+            &node.node_public_key()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_packets_get_delivering_peer_banned() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let mut node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        // Deliver over a bus, scoring peers in the node's own peer manager
+        let bus = crate::SharedBroadcastBus::new();
+        let mut sender = bus.attach();
+        let peers = node.transport.lock().await.peer_manager();
+        let transport = ScoredBus {
+            inner: bus.attach(),
+            peers: peers.clone(),
+        };
+        let sender_id = transport.peers()[0];
+        node.broadcast = Arc::new(BroadcastEngine::with_transport(
+            1000,
+            10.0,
+            60,
+            Arc::new(Mutex::new(transport)),
+        ));
+
+        let protocol = GhostProtocol::default();
+        for _ in 0..4 {
+            let tx = protocol
+                .create_transaction(resonance, resonance, b"tampered".to_vec())
+                .unwrap();
+            let mut packet = seal(&protocol, &tx);
+            packet.stego_carrier.push(0xFF);
+            sender.broadcast(packet).await.unwrap();
+        }
+
+        assert!(node.receive_transactions().await.unwrap().is_empty());
+        assert_eq!(node.protocol_metrics().rejected_integrity_failed, 4);
+        assert!(peers.read().unwrap().is_banned(sender_id));
+    }
+
+    #[tokio::test]
+    async fn test_violation_reported_while_transport_locked() {
+        let wrapper = TransportWrapper::new(
+            Libp2pTransport::new(TransportConfig::local())
+                .await
+                .unwrap(),
+        );
+        let peer = PeerId::random();
+
+        // A task waiting on the swarm holds the transport lock
+        let _guard = wrapper.inner.lock().await;
+        wrapper.report_violation(peer, PeerViolation::IntegrityFailure);
+
+        assert_eq!(
+            wrapper.peer_manager().peer_score(peer),
+            crate::transport::peer::INITIAL_PEER_SCORE - PeerViolation::IntegrityFailure.penalty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commit_receipts_chain_block_hashes() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let node = GhostNetworkNode::new(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let protocol = GhostProtocol::default();
+        let first = protocol
+            .create_transaction(resonance, resonance, b"first".to_vec())
+            .unwrap();
+        let second = protocol
+            .create_transaction(resonance, resonance, b"second".to_vec())
+            .unwrap();
+
+        let genesis = node.commit_transactions(0, vec![first.clone()]).unwrap();
+        let next = node.commit_transactions(1, vec![second.clone()]).unwrap();
+
+        // Each receipt carries the hash of the block built on its predecessor
+        assert_eq!(genesis.block_hash.len(), 32);
+        assert_eq!(genesis.block_hash, fork_block(&first, 0, &[0; 32]).hash());
+        assert_eq!(
+            next.block_hash,
+            fork_block(&second, 1, &genesis.block_hash).hash()
+        );
+
+        // The block hash covers height and linkage, not just the transaction
+        assert_ne!(next.block_hash, fork_block(&second, 1, &[0; 32]).hash());
+        assert_ne!(
+            next.block_hash,
+            fork_block(&second, 2, &genesis.block_hash).hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_node_key_survives_restart() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let key = [9u8; 32];
+        let node = GhostNetworkNode::with_node_key(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+            SigningKey::from_bytes(&key),
+        )
+        .await
+        .unwrap();
+
+        let tx = GhostProtocol::default()
+            .create_transaction(resonance, resonance, b"action".to_vec())
+            .unwrap();
+        let receipt = node.commit_transactions(0, vec![tx]).unwrap();
+        drop(node);
+
+        // A node restarted with the persisted key verifies earlier receipts
+        let restarted = GhostNetworkNode::with_node_key(
+            resonance,
+            TransportConfig::local(),
+            ProtocolConfig::default(),
+            SigningKey::from_bytes(&key),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            restarted.node_public_key(),
+            SigningKey::from_bytes(&key).verifying_key()
+        );
+        assert!(crate::verify_commit_receipt(
+            &receipt,
+            &restarted.node_public_key()
+        ));
+    }
 }
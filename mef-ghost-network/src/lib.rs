@@ -95,24 +95,43 @@ pub mod transport;
 pub mod integration;
 
 // Re-exports for convenience
-pub use broadcasting::{BroadcastChannel, BroadcastEngine, BroadcastStats};
+pub use broadcasting::{
+    BroadcastChannel, BroadcastEngine, BroadcastStats, DecoyResonance, SubscriptionWindow,
+    TrafficProfile,
+};
 pub use discovery::{
-    DiscoveredNode, DiscoveryBeacon, DiscoveryEngine, DiscoveryEvent, DiscoveryStats, EventType,
+    Capability, DiscoveredNode, DiscoveryBeacon, DiscoveryEngine, DiscoveryEvent, DiscoveryStats,
+    EventType,
 };
 pub use integration::GhostNetworkNode;
-pub use packet::{CarrierType, GhostPacket, GhostTransaction, NodeIdentity, ResonanceState};
-pub use protocol::{GhostProtocol, MaskingParams, ProtocolConfig};
+pub use packet::{
+    CarrierType, GhostPacket, GhostTransaction, NodeIdentity, PacketPriority, ResonanceState,
+};
+pub use protocol::{
+    verify_commit_receipt, AdaptiveEpsilonConfig, CommitReceipt, GhostProtocol, MaskingParams,
+    PacketMetrics, ProtocolConfig, ProtocolConfigBuilder, RejectionRecord, SenderFault,
+};
 pub use transport::{
-    Libp2pTransport, PacketCodec, PeerId, PeerInfo, PeerManager, Transport, TransportConfig,
+    Libp2pTransport, PacketCodec, PeerId, PeerInfo, PeerManager, PeerViolation, SharedBroadcastBus,
+    Transport, TransportConfig,
 };
 
 use anyhow::Result;
+use futures::Stream;
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Upper bound on how long a transaction stream waits before re-polling
+/// (covers transport-backed engines, which have no buffer notification)
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Ghost Network - High-level interface combining all components
 ///
 /// This provides a unified interface for the Ghost Networking Protocol,
 /// combining protocol flow, broadcasting, and discovery.
+#[derive(Debug)]
 pub struct GhostNetwork {
     /// Protocol implementation
     pub protocol: Arc<GhostProtocol>,
@@ -125,6 +144,9 @@ pub struct GhostNetwork {
 
     /// Node identity
     pub identity: Arc<std::sync::RwLock<NodeIdentity>>,
+
+    /// Serializes stats snapshots against resets
+    stats_lock: Arc<std::sync::RwLock<()>>,
 }
 
 impl GhostNetwork {
@@ -135,6 +157,28 @@ impl GhostNetwork {
             broadcast: Arc::new(BroadcastEngine::default()),
             discovery: Arc::new(DiscoveryEngine::default()),
             identity: Arc::new(std::sync::RwLock::new(identity)),
+            stats_lock: Arc::new(std::sync::RwLock::new(())),
+        }
+    }
+
+    /// Create ghost network attached to an in-memory broadcast bus
+    ///
+    /// Packets broadcast by any network on the bus are delivered to all
+    /// other networks attached to it (deterministic multi-node testing).
+    pub fn with_bus(
+        protocol_config: ProtocolConfig,
+        identity: NodeIdentity,
+        bus: &SharedBroadcastBus,
+    ) -> Self {
+        let transport: Arc<tokio::sync::Mutex<dyn Transport>> =
+            Arc::new(tokio::sync::Mutex::new(bus.attach()));
+
+        Self {
+            protocol: Arc::new(GhostProtocol::new(protocol_config)),
+            broadcast: Arc::new(BroadcastEngine::with_transport(1000, 10.0, 60, transport)),
+            discovery: Arc::new(DiscoveryEngine::default()),
+            identity: Arc::new(std::sync::RwLock::new(identity)),
+            stats_lock: Arc::new(std::sync::RwLock::new(())),
         }
     }
 
@@ -145,26 +189,40 @@ impl GhostNetwork {
 
     /// Create with random identity
     pub fn with_random_identity() -> Self {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        Self::with_identity_from_rng(&mut rand::thread_rng())
+    }
 
+    /// Create with an identity derived deterministically from a seed
+    ///
+    /// The same seed always yields the same resonance state and node ID,
+    /// which keeps tests reproducible. Use
+    /// [`with_random_identity`](Self::with_random_identity) in production.
+    pub fn with_seeded_identity(seed: u64) -> Self {
+        use rand::SeedableRng;
+        Self::with_identity_from_rng(&mut rand::rngs::StdRng::seed_from_u64(seed))
+    }
+
+    /// Create with resonance state and node ID drawn from the given RNG
+    fn with_identity_from_rng<R: rand::Rng>(rng: &mut R) -> Self {
         let resonance = ResonanceState::new(
             rng.gen_range(-1.0..1.0),
             rng.gen_range(-1.0..1.0),
             rng.gen_range(-1.0..1.0),
         );
 
-        let identity = NodeIdentity::new(resonance, None);
+        let mut identity = NodeIdentity::new(resonance, None);
+        identity.id = uuid::Builder::from_random_bytes(rng.gen()).into_uuid();
         Self::default_with_identity(identity)
     }
 
     /// Announce presence to the network
-    pub async fn announce(&self, capabilities: Option<Vec<String>>) -> Result<uuid::Uuid> {
+    pub async fn announce(&self, capabilities: Option<Vec<Capability>>) -> Result<uuid::Uuid> {
         let identity = self
             .identity
             .read()
-            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?;
-        self.discovery.announce(&*identity, capabilities).await
+            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?
+            .clone();
+        self.discovery.announce(&identity, capabilities).await
     }
 
     /// Send transaction to target resonance
@@ -173,20 +231,21 @@ impl GhostNetwork {
         target_resonance: ResonanceState,
         action: Vec<u8>,
     ) -> Result<uuid::Uuid> {
-        let identity = self
+        let resonance = self
             .identity
             .read()
-            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?;
+            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?
+            .resonance;
 
         // Step 1: Create transaction
         let tx = self
             .protocol
-            .create_transaction(identity.resonance, target_resonance, action)?;
+            .create_transaction(resonance, target_resonance, action)?;
 
         // Step 2: Mask transaction with resonance-derived parameters
         // R-03-001: Uses current epoch for key rotation
         // R-03-002: Optionally adds forward secrecy
-        let mut params = MaskingParams::from_resonance(&identity.resonance, &target_resonance);
+        let mut params = MaskingParams::from_resonance(&resonance, &target_resonance);
 
         // R-03-002: Add forward secrecy if enabled
         let ephemeral_key = MaskingParams::generate_ephemeral_key();
@@ -213,23 +272,65 @@ impl GhostNetwork {
         let identity = self
             .identity
             .read()
-            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?;
+            .map_err(|e| anyhow::anyhow!("Failed to acquire identity read lock: {}", e))?
+            .clone();
 
-        // Receive packets from broadcast
-        let packets = self.broadcast.receive(&*identity).await?;
-
-        let mut transactions = Vec::new();
+        receive_for(&self.protocol, &self.broadcast, &self.discovery, &identity).await
+    }
 
-        // Process each packet
-        for packet in packets {
-            // The protocol will automatically derive masking parameters
-            // from the sender_resonance (in packet) and our resonance state
-            if let Some(tx) = self.protocol.receive_packet(&packet, &identity.resonance)? {
-                transactions.push(tx);
+    /// Stream of received transactions
+    ///
+    /// Yields transactions as they arrive from the broadcast/transport layer,
+    /// so consumers can `.await` them instead of busy-polling
+    /// [`receive_transactions`](Self::receive_transactions). Packets go through
+    /// the same resonance matching and validation as `receive_transactions`;
+    /// expired or invalid packets are dropped and never surfaced.
+    ///
+    /// The stream never terminates on its own; drop it to stop receiving.
+    pub fn transaction_stream(&self) -> impl Stream<Item = GhostTransaction> + Send + 'static {
+        let protocol = self.protocol.clone();
+        let broadcast = self.broadcast.clone();
+        let discovery = self.discovery.clone();
+        let identity = self.identity.clone();
+
+        futures::stream::unfold(VecDeque::<GhostTransaction>::new(), move |mut pending| {
+            let protocol = protocol.clone();
+            let broadcast = broadcast.clone();
+            let discovery = discovery.clone();
+            let identity = identity.clone();
+
+            async move {
+                loop {
+                    if let Some(tx) = pending.pop_front() {
+                        return Some((tx, pending));
+                    }
+
+                    // Register interest before draining so a broadcast
+                    // landing in between is not missed
+                    let notify = broadcast.packet_notify();
+                    let notified = notify.notified();
+
+                    let node = match identity.read() {
+                        Ok(identity) => identity.clone(),
+                        Err(e) => {
+                            tracing::warn!("Failed to acquire identity read lock: {}", e);
+                            e.into_inner().clone()
+                        }
+                    };
+
+                    match receive_for(&protocol, &broadcast, &discovery, &node).await {
+                        Ok(transactions) => pending.extend(transactions),
+                        Err(e) => {
+                            tracing::warn!("Transaction stream receive failed: {}", e);
+                        }
+                    }
+
+                    if pending.is_empty() {
+                        let _ = tokio::time::timeout(STREAM_POLL_INTERVAL, notified).await;
+                    }
+                }
             }
-        }
-
-        Ok(transactions)
+        })
     }
 
     /// Find nodes matching target resonance
@@ -238,7 +339,7 @@ impl GhostNetwork {
     }
 
     /// Find nodes with specific capabilities
-    pub fn find_nodes_with_capabilities(&self, capabilities: &[String]) -> Vec<DiscoveredNode> {
+    pub fn find_nodes_with_capabilities(&self, capabilities: &[Capability]) -> Vec<DiscoveredNode> {
         self.discovery.find_nodes_with_capabilities(capabilities)
     }
 
@@ -262,28 +363,88 @@ impl GhostNetwork {
         Ok(())
     }
 
+    /// Relay a received packet to the next hop
+    ///
+    /// Consumes one hop of the packet's TTL before re-broadcasting it;
+    /// exhausted packets are dropped and counted in `dropped_ttl_expired`.
+    ///
+    /// # Returns
+    /// * `true` if the packet was re-broadcast, `false` if its TTL was exhausted
+    pub async fn forward_packet(&self, packet: &GhostPacket) -> Result<bool> {
+        match self.broadcast.forward(packet.clone()).await? {
+            Some(_) => Ok(true),
+            None => {
+                self.protocol.record_ttl_expired(packet);
+                Ok(false)
+            }
+        }
+    }
+
     /// Generate decoy traffic for privacy
     pub async fn generate_decoy_traffic(&self, count: usize) -> Result<()> {
         self.broadcast.generate_decoy_traffic(count).await
     }
 
-    /// Cleanup expired channels and inactive nodes
+    /// Cleanup expired channels, inactive nodes and stale replay records
     pub fn cleanup(&self) -> Result<()> {
         self.broadcast.cleanup_expired_channels()?;
         self.discovery.cleanup()?;
+        self.protocol.cleanup_seen_packets();
         Ok(())
     }
 
+    /// Start a background task that reaps expired state periodically
+    ///
+    /// Every `interval` the task runs [`cleanup`](Self::cleanup) and drops
+    /// stale timestamp-failure rate limiters. Errors are logged and the task
+    /// keeps running; abort the returned handle to stop it.
+    pub fn start_cleanup_task(&self, interval: Duration) -> JoinHandle<()> {
+        let broadcast = self.broadcast.clone();
+        let discovery = self.discovery.clone();
+        let protocol = self.protocol.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                if let Err(e) = broadcast.cleanup_expired_channels() {
+                    tracing::warn!(error = %e, "Periodic channel cleanup failed");
+                }
+                if let Err(e) = discovery.cleanup() {
+                    tracing::warn!(error = %e, "Periodic discovery cleanup failed");
+                }
+                protocol.cleanup_seen_packets();
+                protocol.cleanup_rate_limiters();
+            }
+        })
+    }
+
     /// Get network statistics
     pub fn get_stats(&self) -> NetworkStats {
+        let _guard = self.stats_lock.read().unwrap_or_else(|e| e.into_inner());
+
         NetworkStats {
             broadcast: self.broadcast.get_stats(),
             discovery: self.discovery.get_stats(),
+            protocol: self.protocol.get_metrics(),
             active_nodes: self.discovery.active_node_count(),
             active_channels: self.broadcast.active_channel_count(),
         }
     }
 
+    /// Reset broadcast, discovery and protocol counters
+    ///
+    /// All subsystems are reset together; a concurrent [`get_stats`](Self::get_stats)
+    /// sees either the old counters or the zeroed ones, never a mix.
+    pub fn reset_stats(&self) {
+        let _guard = self.stats_lock.write().unwrap_or_else(|e| e.into_inner());
+
+        self.broadcast.reset_stats();
+        self.discovery.reset_stats();
+        self.protocol.reset_metrics();
+    }
+
     /// Get current node identity
     pub fn get_identity(&self) -> Result<NodeIdentity> {
         let identity = self
@@ -294,6 +455,76 @@ impl GhostNetwork {
     }
 }
 
+/// Receive path shared by `receive_transactions` and `transaction_stream`
+///
+/// Adapts the resonance window to the observed node density, then runs every
+/// packet for `node` through the protocol. Packets the protocol rejects
+/// (including decoys) are counted in its metrics and skipped so they cannot
+/// drop the rest of the batch; rejections caused by the sender are charged
+/// to the delivering peer.
+async fn receive_for(
+    protocol: &GhostProtocol,
+    broadcast: &BroadcastEngine,
+    discovery: &DiscoveryEngine,
+    node: &NodeIdentity,
+) -> Result<Vec<GhostTransaction>> {
+    // Adapt the resonance window to the currently observed node density
+    let epsilon = protocol.update_epsilon_from_discovery(discovery);
+
+    // Receive packets from broadcast within the adapted window
+    let packets = broadcast.receive(node, epsilon).await?;
+
+    let mut transactions = Vec::new();
+
+    // Process each packet
+    for packet in packets {
+        // The protocol will automatically derive masking parameters
+        // from the sender_resonance (in packet) and our resonance state
+        match protocol.receive_packet(&packet, &node.resonance) {
+            Ok(Some(tx)) => transactions.push(tx),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::debug!(packet_id = %packet.id, error = %e, "Skipping rejected packet");
+                report_rejection(broadcast, packet.id, &e).await;
+            }
+        }
+    }
+
+    Ok(transactions)
+}
+
+/// Charge the delivering peer for a rejection caused by the sender
+///
+/// Rejections without a [`SenderFault`] are not charged. Reporting errors
+/// are logged rather than returned: the rest of the batch has already been
+/// drained from the transport and must not be dropped.
+pub(crate) async fn report_rejection(
+    broadcast: &BroadcastEngine,
+    packet_id: uuid::Uuid,
+    error: &anyhow::Error,
+) {
+    let Some(fault) = error.downcast_ref::<SenderFault>() else {
+        return;
+    };
+
+    if let Err(e) = broadcast
+        .report_violation(packet_id, peer_violation(*fault))
+        .await
+    {
+        tracing::warn!(%packet_id, error = %e, "Failed to report peer violation");
+    }
+}
+
+/// Peer violation charged for a rejection caused by the sender
+fn peer_violation(fault: SenderFault) -> PeerViolation {
+    match fault {
+        SenderFault::RateLimited => PeerViolation::RateLimit,
+        SenderFault::TimestampOutOfWindow => PeerViolation::ClockSkew,
+        SenderFault::IntegrityFailed => PeerViolation::IntegrityFailure,
+        SenderFault::Replay => PeerViolation::Replay,
+    }
+}
+
 /// Network statistics
 #[derive(Debug, Clone)]
 pub struct NetworkStats {
@@ -303,6 +534,9 @@ pub struct NetworkStats {
     /// Discovery statistics
     pub discovery: DiscoveryStats,
 
+    /// Protocol packet metrics (acceptance and per-reason rejections)
+    pub protocol: PacketMetrics,
+
     /// Active node count
     pub active_nodes: usize,
 
@@ -310,9 +544,22 @@ pub struct NetworkStats {
     pub active_channels: usize,
 }
 
+impl NetworkStats {
+    /// Fraction of received packets accepted by the protocol
+    pub fn acceptance_rate(&self) -> f64 {
+        self.protocol.acceptance_rate()
+    }
+
+    /// Total packets rejected by the protocol
+    pub fn total_rejected(&self) -> usize {
+        self.protocol.total_rejected()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transport::bus::ScoredBus;
 
     #[test]
     fn test_ghost_network_creation() {
@@ -339,14 +586,14 @@ mod tests {
 
         // Network 1 announces
         let beacon_id = network1
-            .announce(Some(vec!["storage".to_string()]))
+            .announce(Some(vec![Capability::Storage]))
             .await
             .unwrap();
         assert_ne!(beacon_id, uuid::Uuid::nil(), "Beacon ID must not be nil");
 
         // Simulate beacon propagation to network2
         let resonance = network1.get_identity().unwrap().resonance;
-        let beacon = DiscoveryBeacon::new(resonance, 300, Some(vec!["storage".to_string()]));
+        let beacon = DiscoveryBeacon::new(resonance, 300, Some(vec![Capability::Storage]));
         network2.discovery.receive_beacon(beacon).unwrap();
 
         // Network 2 finds network 1
@@ -369,6 +616,382 @@ mod tests {
         assert_eq!(stats.broadcast.packets_sent, 1);
     }
 
+    #[tokio::test]
+    async fn test_transaction_stream_yields_sent_transaction() {
+        use futures::StreamExt;
+
+        let resonance = ResonanceState::new(0.5, 0.5, 0.5);
+        let network = GhostNetwork::default_with_identity(NodeIdentity::new(resonance, None));
+        network
+            .broadcast
+            .create_channel(resonance, 0.1, 300)
+            .unwrap();
+
+        let stream = network.transaction_stream();
+        futures::pin_mut!(stream);
+
+        let tx_id = network
+            .send_transaction(resonance, b"streamed".to_vec())
+            .await
+            .unwrap();
+
+        let tx = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .expect("transaction should arrive before timeout")
+            .expect("stream should not terminate");
+
+        assert_eq!(tx.id, tx_id);
+        assert_eq!(tx.action, b"streamed".to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_stream_adapts_epsilon() {
+        use futures::StreamExt;
+
+        let network = GhostNetwork::new(
+            ProtocolConfig {
+                adaptive_epsilon: Some(AdaptiveEpsilonConfig::default()),
+                ..ProtocolConfig::default()
+            },
+            NodeIdentity::new(ResonanceState::new(0.5, 0.5, 0.5), None),
+        );
+        assert_eq!(network.protocol.effective_epsilon(), 0.1);
+
+        let stream = network.transaction_stream();
+        futures::pin_mut!(stream);
+        let _ = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+
+        // No active nodes: the window widens just like in receive_transactions
+        assert!(network.protocol.effective_epsilon() > 0.1);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_stream_ignores_non_resonant_packets() {
+        use futures::StreamExt;
+
+        let resonance = ResonanceState::new(0.5, 0.5, 0.5);
+        let network = GhostNetwork::default_with_identity(NodeIdentity::new(resonance, None));
+        let far = ResonanceState::new(5.0, 5.0, 5.0);
+        network.broadcast.create_channel(far, 0.1, 300).unwrap();
+
+        let stream = network.transaction_stream();
+        futures::pin_mut!(stream);
+
+        network
+            .send_transaction(far, b"not for us".to_vec())
+            .await
+            .unwrap();
+
+        let result = tokio::time::timeout(Duration::from_millis(300), stream.next()).await;
+        assert!(
+            result.is_err(),
+            "Non-resonant transaction must not be yielded"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shared_bus_delivers_to_resonant_node_only() {
+        let bus = SharedBroadcastBus::new();
+        let node = |psi: f64| NodeIdentity::new(ResonanceState::new(psi, psi, psi), None);
+
+        let a = GhostNetwork::with_bus(ProtocolConfig::default(), node(0.0), &bus);
+        let b = GhostNetwork::with_bus(ProtocolConfig::default(), node(1.0), &bus);
+        let c = GhostNetwork::with_bus(ProtocolConfig::default(), node(-1.0), &bus);
+        assert_eq!(bus.member_count(), 3);
+
+        let target = b.get_identity().unwrap().resonance;
+        let tx_id = a
+            .send_transaction(target, b"over the bus".to_vec())
+            .await
+            .unwrap();
+
+        let received_b = b.receive_transactions().await.unwrap();
+        assert_eq!(received_b.len(), 1);
+        assert_eq!(received_b[0].id, tx_id);
+        assert_eq!(received_b[0].action, b"over the bus".to_vec());
+
+        let received_c = c.receive_transactions().await.unwrap();
+        assert!(received_c.is_empty());
+
+        // Sender does not receive its own packet
+        assert!(a.receive_transactions().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sparse_widening_delivers_packet_over_bus() {
+        let bus = SharedBroadcastBus::new();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let sender = GhostNetwork::with_bus(
+            ProtocolConfig::default(),
+            NodeIdentity::new(ResonanceState::new(0.0, 0.0, 0.0), None),
+            &bus,
+        );
+        let fixed = GhostNetwork::with_bus(
+            ProtocolConfig::default(),
+            NodeIdentity::new(resonance, None),
+            &bus,
+        );
+        let adaptive = GhostNetwork::with_bus(
+            ProtocolConfig {
+                adaptive_epsilon: Some(AdaptiveEpsilonConfig::default()),
+                ..ProtocolConfig::default()
+            },
+            NodeIdentity::new(resonance, None),
+            &bus,
+        );
+
+        // Masked for the receivers, but tagged 0.2 away from them
+        let protocol = &sender.protocol;
+        let sender_resonance = sender.get_identity().unwrap().resonance;
+        let tx = protocol
+            .create_transaction(sender_resonance, resonance, b"sparse".to_vec())
+            .unwrap();
+        let params = MaskingParams::from_resonance(&sender_resonance, &resonance);
+        let masked = protocol.mask_transaction(&tx, &params).unwrap();
+        let carrier = protocol
+            .embed_transaction(&masked, CarrierType::Raw)
+            .unwrap();
+        let mut packet = protocol
+            .create_packet(&tx, masked, carrier, CarrierType::Raw, &params)
+            .unwrap();
+        packet.resonance = ResonanceState::new(1.2, 1.0, 1.0);
+        packet.hash = packet.compute_hash();
+
+        sender.broadcast.broadcast(packet).await.unwrap();
+
+        // Fixed window of 0.1 drops the packet before the protocol sees it
+        assert!(fixed.receive_transactions().await.unwrap().is_empty());
+
+        // No active nodes: the window widens past 0.2 and the packet arrives
+        let received = adaptive.receive_transactions().await.unwrap();
+        assert!(adaptive.protocol.effective_epsilon() > 0.2);
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].id, tx.id);
+    }
+
+    #[tokio::test]
+    async fn test_stale_packet_charges_delivering_peer_for_clock_skew() {
+        let bus = SharedBroadcastBus::new();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let sender = GhostNetwork::with_bus(
+            ProtocolConfig::default(),
+            NodeIdentity::new(ResonanceState::new(0.0, 0.0, 0.0), None),
+            &bus,
+        );
+
+        let peers = Arc::new(std::sync::RwLock::new(PeerManager::new(300)));
+        let transport = ScoredBus {
+            inner: bus.attach(),
+            peers: peers.clone(),
+        };
+        let sender_id = transport.peers()[0];
+        let mut receiver = GhostNetwork::default_with_identity(NodeIdentity::new(resonance, None));
+        receiver.broadcast = Arc::new(BroadcastEngine::with_transport(
+            1000,
+            10.0,
+            60,
+            Arc::new(tokio::sync::Mutex::new(transport)),
+        ));
+
+        // Intact packet resent long after its timestamp window closed
+        let protocol = &sender.protocol;
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"replay".to_vec())
+            .unwrap();
+        let params = MaskingParams::from_resonance(&resonance, &resonance);
+        let masked = protocol.mask_transaction(&tx, &params).unwrap();
+        let carrier = protocol
+            .embed_transaction(&masked, CarrierType::Raw)
+            .unwrap();
+        let mut packet = protocol
+            .create_packet(&tx, masked, carrier, CarrierType::Raw, &params)
+            .unwrap();
+        packet.timestamp -= 7 * 86_400;
+        packet.hash = packet.compute_hash();
+
+        let initial_score = peers.read().unwrap().peer_score(sender_id);
+        sender.broadcast.broadcast(packet).await.unwrap();
+
+        assert!(receiver.receive_transactions().await.unwrap().is_empty());
+        assert_eq!(
+            peers.read().unwrap().peer_score(sender_id),
+            initial_score - PeerViolation::ClockSkew.penalty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decoys_do_not_drop_real_transactions() {
+        let resonance = ResonanceState::new(0.5, 0.5, 0.5);
+        let mut network = GhostNetwork::default_with_identity(NodeIdentity::new(resonance, None));
+        // Decoys land inside the real channel's window
+        network.broadcast = Arc::new(
+            BroadcastEngine::default()
+                .with_decoy_resonance(DecoyResonance::ActiveChannels { jitter: 0.01 }),
+        );
+        network
+            .broadcast
+            .create_channel(resonance, 0.1, 300)
+            .unwrap();
+
+        // Decoys sent later would also create decoy channels matching this
+        // node, so send the real traffic first to keep deliveries unique
+        let first = network
+            .send_transaction(resonance, b"first".to_vec())
+            .await
+            .unwrap();
+        let second = network
+            .send_transaction(resonance, b"second".to_vec())
+            .await
+            .unwrap();
+        network.generate_decoy_traffic(10).await.unwrap();
+
+        let received = network.receive_transactions().await.unwrap();
+        let ids: Vec<uuid::Uuid> = received.iter().map(|tx| tx.id).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&first));
+        assert!(ids.contains(&second));
+
+        let metrics = network.get_stats().protocol;
+        assert_eq!(metrics.packets_accepted, 2);
+        assert!(metrics.rejected_undecodable > 0);
+    }
+
+    #[test]
+    fn test_stats_surface_protocol_rejections() {
+        let resonance = ResonanceState::new(0.5, 0.5, 0.5);
+        let network = GhostNetwork::default_with_identity(NodeIdentity::new(resonance, None));
+        let protocol = &network.protocol;
+
+        let build_packet = || {
+            let tx = protocol
+                .create_transaction(resonance, resonance, b"stats".to_vec())
+                .unwrap();
+            let params = MaskingParams::from_resonance(&resonance, &resonance);
+            let masked = protocol.mask_transaction(&tx, &params).unwrap();
+            let carrier = protocol
+                .embed_transaction(&masked, CarrierType::Raw)
+                .unwrap();
+            protocol
+                .create_packet(&tx, masked, carrier, CarrierType::Raw, &params)
+                .unwrap()
+        };
+
+        let valid = build_packet();
+        let mut empty = build_packet();
+        empty.masked_payload.clear();
+        let mut non_finite = build_packet();
+        non_finite.resonance.psi = f64::NAN;
+
+        assert!(protocol
+            .receive_packet(&valid, &resonance)
+            .unwrap()
+            .is_some());
+        assert!(protocol.receive_packet(&empty, &resonance).is_err());
+        assert!(protocol.receive_packet(&non_finite, &resonance).is_err());
+
+        let stats = network.get_stats();
+        assert_eq!(stats.protocol.packets_received, 3);
+        assert_eq!(stats.protocol.packets_accepted, 1);
+        assert_eq!(stats.protocol.rejected_empty_payload, 1);
+        assert_eq!(stats.protocol.rejected_invalid_resonance, 1);
+        assert_eq!(stats.total_rejected(), 2);
+        assert!((stats.acceptance_rate() - 1.0 / 3.0).abs() < 1e-9);
+
+        network.reset_stats();
+        let stats = network.get_stats();
+        assert_eq!(stats.protocol.packets_received, 0);
+        assert_eq!(stats.total_rejected(), 0);
+        assert_eq!(stats.broadcast.packets_sent, 0);
+        assert_eq!(stats.discovery.beacons_sent, 0);
+    }
+
+    #[test]
+    fn test_seeded_identity_is_reproducible() {
+        let a = GhostNetwork::with_seeded_identity(42)
+            .get_identity()
+            .unwrap();
+        let b = GhostNetwork::with_seeded_identity(42)
+            .get_identity()
+            .unwrap();
+        let c = GhostNetwork::with_seeded_identity(43)
+            .get_identity()
+            .unwrap();
+
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.resonance, b.resonance);
+
+        assert_ne!(a.id, c.id);
+        assert_ne!(a.resonance, c.resonance);
+        assert_ne!(a.id, uuid::Uuid::nil(), "Seeded identity must not be nil");
+    }
+
+    #[tokio::test]
+    async fn test_forward_packet_stops_at_ttl() {
+        let network = GhostNetwork::new(
+            ProtocolConfig {
+                default_ttl: 1,
+                ..ProtocolConfig::default()
+            },
+            NodeIdentity::new(ResonanceState::new(0.5, 0.5, 0.5), None),
+        );
+        let resonance = ResonanceState::new(2.0, 2.0, 2.0);
+        let protocol = &network.protocol;
+
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"relay".to_vec())
+            .unwrap();
+        let params = MaskingParams::from_resonance(&resonance, &resonance);
+        let masked = protocol.mask_transaction(&tx, &params).unwrap();
+        let carrier = protocol
+            .embed_transaction(&masked, CarrierType::Raw)
+            .unwrap();
+        let packet = protocol
+            .create_packet(&tx, masked, carrier, CarrierType::Raw, &params)
+            .unwrap();
+        assert_eq!(packet.ttl, 1, "create_packet stamps config.default_ttl");
+
+        assert!(network.forward_packet(&packet).await.unwrap());
+        assert_eq!(network.get_stats().broadcast.packets_sent, 1);
+
+        let relayed = packet.clone().with_ttl(0);
+        assert!(!network.forward_packet(&relayed).await.unwrap());
+        assert_eq!(network.get_stats().broadcast.packets_sent, 1);
+        assert_eq!(network.get_stats().protocol.dropped_ttl_expired, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cleanup_task_reaps_until_cancelled() {
+        let mut network = GhostNetwork::with_seeded_identity(7);
+        // Zero timeouts: the channel and node are already expired at the first tick
+        network.discovery = Arc::new(DiscoveryEngine::new(0, 120, 0.2));
+
+        let resonance = ResonanceState::new(0.5, 0.5, 0.5);
+        let channel = network.broadcast.create_channel(resonance, 0.1, 0).unwrap();
+        network
+            .discovery
+            .receive_beacon(DiscoveryBeacon::new(resonance, 300, None))
+            .unwrap();
+        assert_eq!(network.discovery.tracked_node_count(), 1);
+
+        // The clock is paused, so sleeping advances virtual time instantly
+        let handle = network.start_cleanup_task(Duration::from_millis(100));
+        tokio::time::sleep(Duration::from_millis(250)).await;
+
+        // Expired channel and inactive node were reaped without a manual cleanup()
+        assert!(network.broadcast.get_buffer_size(channel).is_none());
+        assert_eq!(network.get_stats().broadcast.channels_dissolved, 1);
+        assert_eq!(network.discovery.tracked_node_count(), 0);
+
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+
+        // After cancellation nothing is reaped anymore
+        let late = network.broadcast.create_channel(resonance, 0.1, 0).unwrap();
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(network.broadcast.get_buffer_size(late).is_some());
+        assert_eq!(network.get_stats().broadcast.channels_dissolved, 1);
+    }
+
     #[test]
     fn test_regenerate_identity() {
         let network = GhostNetwork::with_random_identity();
@@ -403,12 +1026,12 @@ mod tests {
         let beacon = DiscoveryBeacon::new(
             resonance,
             300,
-            Some(vec!["compute".to_string(), "storage".to_string()]),
+            Some(vec![Capability::Compute, Capability::Storage]),
         );
         network.discovery.receive_beacon(beacon).unwrap();
 
         // Find nodes with capabilities
-        let found = network.find_nodes_with_capabilities(&["storage".to_string()]);
+        let found = network.find_nodes_with_capabilities(&[Capability::Storage]);
         assert_eq!(found.len(), 1);
     }
 
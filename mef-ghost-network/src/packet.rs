@@ -29,6 +29,16 @@ impl ResonanceState {
         Self { psi, rho, omega }
     }
 
+    /// Create resonance state, rejecting NaN or infinite components
+    pub fn try_new(psi: f64, rho: f64, omega: f64) -> mef_common::MefResult<Self> {
+        mef_common::ResonanceState::try_new(psi, rho, omega).map(Self::from)
+    }
+
+    /// Check that all components are finite
+    pub fn validate(&self) -> mef_common::MefResult<()> {
+        mef_common::ResonanceState::from(*self).validate()
+    }
+
     /// Create zero resonance state
     pub fn zero() -> Self {
         Self::new(0.0, 0.0, 0.0)
@@ -36,10 +46,21 @@ impl ResonanceState {
 
     /// Calculate Euclidean distance to another state
     pub fn distance_to(&self, other: &ResonanceState) -> f64 {
-        let dpsi = self.psi - other.psi;
-        let drho = self.rho - other.rho;
-        let domega = self.omega - other.omega;
-        (dpsi * dpsi + drho * drho + domega * domega).sqrt()
+        mef_common::ResonanceState::from(*self).distance(&(*other).into())
+    }
+
+    /// Calculate coherence with another state
+    pub fn coherence_with(&self, other: &ResonanceState) -> f64 {
+        mef_common::ResonanceState::from(*self).coherence_with(&(*other).into())
+    }
+
+    /// Calculate coherence with another state using a specific kernel
+    pub fn coherence_with_kernel(
+        &self,
+        other: &ResonanceState,
+        kernel: mef_common::CoherenceKernel,
+    ) -> f64 {
+        mef_common::ResonanceState::from(*self).coherence_with_kernel(&(*other).into(), kernel)
     }
 
     /// Check if within resonance window
@@ -48,6 +69,18 @@ impl ResonanceState {
     }
 }
 
+impl From<mef_common::ResonanceState> for ResonanceState {
+    fn from(state: mef_common::ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
+    }
+}
+
+impl From<ResonanceState> for mef_common::ResonanceState {
+    fn from(state: ResonanceState) -> Self {
+        Self::new(state.psi, state.rho, state.omega)
+    }
+}
+
 /// Ghost Packet - Core data structure for addressless communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GhostPacket {
@@ -91,10 +124,33 @@ pub struct GhostPacket {
     #[serde(with = "serde_bytes")]
     pub ephemeral_key: Option<Vec<u8>>,
 
+    /// Delivery priority (QoS class)
+    #[serde(default)]
+    pub priority: PacketPriority,
+
     /// Packet hash for integrity
     pub hash: [u8; 32],
 }
 
+/// Delivery priority of a packet
+///
+/// Higher priorities are delivered first; packets of equal priority keep
+/// FIFO order.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum PacketPriority {
+    /// Background, latency-tolerant traffic
+    Low = 1,
+
+    /// Regular transactions
+    #[default]
+    Normal = 2,
+
+    /// Urgent traffic (e.g. ledger commits)
+    High = 3,
+}
+
 /// Type of steganographic carrier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CarrierType {
@@ -139,6 +195,7 @@ impl GhostPacket {
             ttl: 32,             // Default TTL
             key_epoch: 0,        // Will be set by protocol
             ephemeral_key: None, // Will be set by protocol if forward secrecy enabled
+            priority: PacketPriority::Normal,
             hash: [0u8; 32],
         };
 
@@ -147,6 +204,7 @@ impl GhostPacket {
     }
 
     /// Create new ghost packet with key epoch and ephemeral key (R-03-001, R-03-002)
+    #[allow(clippy::too_many_arguments)]
     pub fn new_with_keys(
         resonance: ResonanceState,
         sender_resonance: ResonanceState,
@@ -157,29 +215,15 @@ impl GhostPacket {
         key_epoch: u64,
         ephemeral_key: Option<Vec<u8>>,
     ) -> Self {
-        let id = Uuid::new_v4();
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| anyhow::anyhow!("System time error: {}", e))
-            .expect("Failed to get system time");
-
-        let mut packet = Self {
-            id,
-            timestamp: timestamp.as_secs(),
+        Self::new(
             resonance,
             sender_resonance,
             masked_payload,
             stego_carrier,
             carrier_type,
             zk_proof,
-            ttl: 32, // Default TTL
-            key_epoch,
-            ephemeral_key,
-            hash: [0u8; 32],
-        };
-
-        packet.hash = packet.compute_hash();
-        packet
+        )
+        .with_keys(key_epoch, ephemeral_key)
     }
 
     /// Compute packet hash for integrity verification
@@ -196,9 +240,10 @@ impl GhostPacket {
         hasher.update(self.sender_resonance.omega.to_le_bytes());
         hasher.update(&self.masked_payload);
         hasher.update(&self.stego_carrier);
-        hasher.update(&[self.carrier_type as u8]);
-        hasher.update(&[self.ttl]);
+        hasher.update([self.carrier_type as u8]);
+        hasher.update([self.ttl]);
         hasher.update(self.key_epoch.to_le_bytes());
+        hasher.update([self.priority as u8]);
 
         if let Some(ref proof) = self.zk_proof {
             hasher.update(proof);
@@ -211,6 +256,28 @@ impl GhostPacket {
         hasher.finalize().into()
     }
 
+    /// Set delivery priority (recomputes hash)
+    pub fn with_priority(mut self, priority: PacketPriority) -> Self {
+        self.priority = priority;
+        self.hash = self.compute_hash();
+        self
+    }
+
+    /// Set key epoch and ephemeral key (R-03-001, R-03-002; recomputes hash)
+    pub fn with_keys(mut self, key_epoch: u64, ephemeral_key: Option<Vec<u8>>) -> Self {
+        self.key_epoch = key_epoch;
+        self.ephemeral_key = ephemeral_key;
+        self.hash = self.compute_hash();
+        self
+    }
+
+    /// Set remaining hop count (recomputes hash)
+    pub fn with_ttl(mut self, ttl: u8) -> Self {
+        self.ttl = ttl;
+        self.hash = self.compute_hash();
+        self
+    }
+
     /// Verify packet integrity
     pub fn verify_integrity(&self) -> bool {
         self.hash == self.compute_hash()
@@ -244,6 +311,7 @@ impl GhostPacket {
         1 +  // carrier_type
         1 +  // ttl
         8 +  // key_epoch
+        1 +  // priority
         32 + // hash
         self.zk_proof.as_ref().map_or(0, |p| p.len()) +
         self.ephemeral_key.as_ref().map_or(0, |k| k.len())
@@ -271,6 +339,20 @@ pub struct GhostTransaction {
 
     /// Timestamp
     pub timestamp: u64,
+
+    /// Expiry timestamp (None = no per-transaction expiry)
+    ///
+    /// Travels inside the masked payload, so it is covered by the packet
+    /// integrity hash together with `timestamp`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+
+    /// Logical height this transaction claims to be committed at
+    ///
+    /// Receivers with a fork healer commit claims per height and resolve
+    /// competing claims for the same height.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_height: Option<u64>,
 }
 
 impl GhostTransaction {
@@ -294,9 +376,43 @@ impl GhostTransaction {
             action,
             zk_data,
             timestamp,
+            expires_at: None,
+            commit_height: None,
         }
     }
 
+    /// Claim a logical commit height
+    pub fn with_commit_height(mut self, height: u64) -> Self {
+        self.commit_height = Some(height);
+        self
+    }
+
+    /// Set expiry relative to the creation timestamp
+    pub fn with_ttl(mut self, ttl_seconds: u64) -> Self {
+        self.expires_at = Some(self.timestamp.saturating_add(ttl_seconds));
+        self
+    }
+
+    /// Set absolute expiry timestamp
+    pub fn with_expires_at(mut self, expires_at: u64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Check if transaction has expired at the given time
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Check if transaction has expired
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.is_expired_at(now)
+    }
+
     /// Serialize to bytes
     pub fn to_bytes(&self) -> Vec<u8> {
         serde_json::to_vec(self).unwrap()
@@ -378,6 +494,20 @@ mod tests {
         assert!((distance - 5.0).abs() < 1e-10); // 3-4-5 triangle
     }
 
+    #[test]
+    fn test_resonance_state_matches_canonical() {
+        let a = ResonanceState::new(0.5, -1.0, 2.0);
+        let b = ResonanceState::new(1.5, 0.0, -0.5);
+
+        // Round-trip through the canonical type is lossless
+        let canonical: mef_common::ResonanceState = a.into();
+        assert_eq!(ResonanceState::from(canonical), a);
+
+        // Distance and coherence agree with the canonical formulas
+        assert_eq!(a.distance_to(&b), canonical.distance(&b.into()));
+        assert_eq!(a.coherence_with(&b), canonical.coherence_with(&b.into()));
+    }
+
     #[test]
     fn test_resonance_check() {
         let state1 = ResonanceState::new(1.0, 1.0, 1.0);
@@ -394,6 +524,7 @@ mod tests {
         let carrier = b"test carrier".to_vec();
 
         let packet = GhostPacket::new(
+            resonance,
             resonance,
             payload.clone(),
             carrier.clone(),
@@ -411,6 +542,7 @@ mod tests {
     fn test_packet_integrity() {
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
         let packet = GhostPacket::new(
+            resonance,
             resonance,
             b"payload".to_vec(),
             b"carrier".to_vec(),
@@ -431,6 +563,7 @@ mod tests {
     fn test_ttl_decrement() {
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
         let mut packet = GhostPacket::new(
+            resonance,
             resonance,
             b"payload".to_vec(),
             b"carrier".to_vec(),
@@ -454,6 +587,7 @@ mod tests {
     fn test_packet_resonance_matching() {
         let packet_resonance = ResonanceState::new(1.0, 1.0, 1.0);
         let packet = GhostPacket::new(
+            packet_resonance,
             packet_resonance,
             b"payload".to_vec(),
             b"carrier".to_vec(),
@@ -467,6 +601,50 @@ mod tests {
         assert!(!packet.matches_resonance(&node_state, 0.01));
     }
 
+    #[test]
+    fn test_packet_priority_ordering_and_integrity() {
+        assert!(PacketPriority::High > PacketPriority::Normal);
+        assert!(PacketPriority::Normal > PacketPriority::Low);
+
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let packet = GhostPacket::new(
+            resonance,
+            resonance,
+            b"payload".to_vec(),
+            b"carrier".to_vec(),
+            CarrierType::Raw,
+            None,
+        );
+        assert_eq!(packet.priority, PacketPriority::Normal);
+
+        let urgent = packet.with_priority(PacketPriority::High);
+        assert!(urgent.verify_integrity());
+
+        // Priority is covered by the integrity hash
+        let mut tampered = urgent.clone();
+        tampered.priority = PacketPriority::Low;
+        assert!(!tampered.verify_integrity());
+    }
+
+    #[test]
+    fn test_new_with_keys_sets_keys() {
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let packet = GhostPacket::new_with_keys(
+            resonance,
+            resonance,
+            b"payload".to_vec(),
+            b"carrier".to_vec(),
+            CarrierType::Raw,
+            None,
+            7,
+            Some(vec![1, 2, 3]),
+        );
+
+        assert_eq!(packet.key_epoch, 7);
+        assert_eq!(packet.ephemeral_key, Some(vec![1, 2, 3]));
+        assert!(packet.verify_integrity());
+    }
+
     #[test]
     fn test_ghost_transaction() {
         let sender = ResonanceState::new(1.0, 1.0, 1.0);
@@ -497,6 +675,7 @@ mod tests {
     fn test_packet_size() {
         let resonance = ResonanceState::new(1.0, 1.0, 1.0);
         let packet = GhostPacket::new(
+            resonance,
             resonance,
             vec![0u8; 100],
             vec![0u8; 200],
@@ -504,8 +683,15 @@ mod tests {
             None,
         );
 
-        let size = packet.size();
-        assert!(size > 300); // At least payload + carrier
-        assert!(size < 400); // Plus overhead
+        // id, timestamp, both resonances, carrier type, ttl, key epoch, priority
+        let header = std::mem::size_of::<Uuid>()
+            + std::mem::size_of::<u64>()
+            + 2 * 3 * std::mem::size_of::<f64>()
+            + 1
+            + 1
+            + std::mem::size_of::<u64>()
+            + 1;
+        assert_eq!(header, 83);
+        assert_eq!(packet.size(), 100 + 200 + header + packet.hash.len());
     }
 }
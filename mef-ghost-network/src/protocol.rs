@@ -13,11 +13,13 @@
 
 use crate::packet::{CarrierType, GhostPacket, GhostTransaction, ResonanceState};
 use anyhow::{Context, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use mef_common::time::{current_timestamp_or_default, jitter_timestamp};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, RwLock};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use tracing::{debug, error, info, warn};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{debug, error, info, info_span, warn};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // Import quantum operators from mef-quantum-ops
@@ -50,6 +52,65 @@ pub struct ProtocolConfig {
 
     /// Adaptive timestamp window configuration (R-03-003)
     pub adaptive_timestamps: bool,
+
+    /// Adaptive resonance epsilon (None = fixed `resonance_epsilon`)
+    #[serde(default)]
+    pub adaptive_epsilon: Option<AdaptiveEpsilonConfig>,
+
+    /// Number of recent rejections kept for inspection (0 disables)
+    #[serde(default = "default_rejection_log_capacity")]
+    pub rejection_log_capacity: usize,
+
+    /// Maximum random delay in seconds added to packet timestamps (0 disables)
+    ///
+    /// Hides the exact creation time from observers. Bounded by
+    /// [`MAX_TIMESTAMP_JITTER`] so receivers never reject jittered packets
+    /// as coming from the future.
+    #[serde(default)]
+    pub timestamp_jitter: u64,
+}
+
+/// Default number of recent rejections kept by [`GhostProtocol`]
+pub const DEFAULT_REJECTION_LOG_CAPACITY: usize = 64;
+
+fn default_rejection_log_capacity() -> usize {
+    DEFAULT_REJECTION_LOG_CAPACITY
+}
+
+/// Adaptive resonance epsilon configuration
+///
+/// Widens the matching window when few nodes are active and narrows it
+/// when many are, so the expected number of resonant nodes stays roughly
+/// constant. The window scales with the cube root of the density ratio
+/// since resonance space is three-dimensional.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdaptiveEpsilonConfig {
+    /// Lower bound for the effective epsilon
+    pub min_epsilon: f64,
+
+    /// Upper bound for the effective epsilon
+    pub max_epsilon: f64,
+
+    /// Active node count at which the effective epsilon equals `resonance_epsilon`
+    pub reference_nodes: usize,
+}
+
+impl AdaptiveEpsilonConfig {
+    /// Compute effective epsilon for an observed number of active nodes
+    pub fn epsilon_for(&self, base_epsilon: f64, active_nodes: usize) -> f64 {
+        let ratio = self.reference_nodes.max(1) as f64 / active_nodes.max(1) as f64;
+        (base_epsilon * ratio.cbrt()).clamp(self.min_epsilon, self.max_epsilon)
+    }
+}
+
+impl Default for AdaptiveEpsilonConfig {
+    fn default() -> Self {
+        Self {
+            min_epsilon: 0.02,
+            max_epsilon: 0.5,
+            reference_nodes: 10,
+        }
+    }
 }
 
 impl Default for ProtocolConfig {
@@ -63,7 +124,145 @@ impl Default for ProtocolConfig {
             enable_steganography: true,
             enable_forward_secrecy: true,
             adaptive_timestamps: true,
+            adaptive_epsilon: None,
+            rejection_log_capacity: DEFAULT_REJECTION_LOG_CAPACITY,
+            timestamp_jitter: 0,
+        }
+    }
+}
+
+/// Upper bound accepted for `max_packet_size` (64 MB)
+pub const MAX_PACKET_SIZE_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Upper bound accepted for `timestamp_jitter`, in seconds
+///
+/// Matches the smallest clock skew tolerance a receiver applies.
+pub const MAX_TIMESTAMP_JITTER: u64 = 30;
+
+impl ProtocolConfig {
+    /// Start building a validated configuration from the defaults
+    pub fn builder() -> ProtocolConfigBuilder {
+        ProtocolConfigBuilder::default()
+    }
+
+    /// Validate configuration
+    pub fn validate(&self) -> Result<()> {
+        if !self.resonance_epsilon.is_finite() || self.resonance_epsilon <= 0.0 {
+            anyhow::bail!("resonance_epsilon must be positive and finite");
+        }
+
+        if self.default_ttl == 0 {
+            anyhow::bail!("default_ttl must be greater than 0");
+        }
+
+        if self.max_packet_size == 0 || self.max_packet_size > MAX_PACKET_SIZE_LIMIT {
+            anyhow::bail!(
+                "max_packet_size must be between 1 and {}",
+                MAX_PACKET_SIZE_LIMIT
+            );
+        }
+
+        if !self.enable_steganography && self.default_carrier_type != CarrierType::Raw {
+            anyhow::bail!(
+                "default_carrier_type {:?} requires enable_steganography",
+                self.default_carrier_type
+            );
+        }
+
+        if let Some(adaptive) = &self.adaptive_epsilon {
+            if !(adaptive.min_epsilon > 0.0 && adaptive.min_epsilon <= adaptive.max_epsilon) {
+                anyhow::bail!("adaptive_epsilon requires 0 < min_epsilon <= max_epsilon");
+            }
+        }
+
+        if self.timestamp_jitter > MAX_TIMESTAMP_JITTER {
+            anyhow::bail!(
+                "timestamp_jitter must be at most {} seconds",
+                MAX_TIMESTAMP_JITTER
+            );
         }
+
+        Ok(())
+    }
+}
+
+/// Builder for [`ProtocolConfig`] that validates on `build()`
+#[derive(Debug, Clone, Default)]
+pub struct ProtocolConfigBuilder {
+    config: ProtocolConfig,
+}
+
+impl ProtocolConfigBuilder {
+    /// Set resonance window epsilon
+    pub fn resonance_epsilon(mut self, epsilon: f64) -> Self {
+        self.config.resonance_epsilon = epsilon;
+        self
+    }
+
+    /// Set default packet TTL
+    pub fn default_ttl(mut self, ttl: u8) -> Self {
+        self.config.default_ttl = ttl;
+        self
+    }
+
+    /// Set maximum packet size in bytes
+    pub fn max_packet_size(mut self, size: usize) -> Self {
+        self.config.max_packet_size = size;
+        self
+    }
+
+    /// Set default carrier type
+    pub fn default_carrier_type(mut self, carrier_type: CarrierType) -> Self {
+        self.config.default_carrier_type = carrier_type;
+        self
+    }
+
+    /// Enable or disable zero-knowledge proofs
+    pub fn enable_zk_proofs(mut self, enabled: bool) -> Self {
+        self.config.enable_zk_proofs = enabled;
+        self
+    }
+
+    /// Enable or disable steganography
+    pub fn enable_steganography(mut self, enabled: bool) -> Self {
+        self.config.enable_steganography = enabled;
+        self
+    }
+
+    /// Enable or disable forward secrecy
+    pub fn enable_forward_secrecy(mut self, enabled: bool) -> Self {
+        self.config.enable_forward_secrecy = enabled;
+        self
+    }
+
+    /// Enable or disable adaptive timestamp windows
+    pub fn adaptive_timestamps(mut self, enabled: bool) -> Self {
+        self.config.adaptive_timestamps = enabled;
+        self
+    }
+
+    /// Set adaptive resonance epsilon (None = fixed epsilon)
+    pub fn adaptive_epsilon(mut self, adaptive: Option<AdaptiveEpsilonConfig>) -> Self {
+        self.config.adaptive_epsilon = adaptive;
+        self
+    }
+
+    /// Set number of recent rejections kept (0 disables)
+    pub fn rejection_log_capacity(mut self, capacity: usize) -> Self {
+        self.config.rejection_log_capacity = capacity;
+        self
+    }
+
+    /// Set maximum packet timestamp jitter in seconds (0 disables)
+    pub fn timestamp_jitter(mut self, seconds: u64) -> Self {
+        self.config.timestamp_jitter = seconds;
+        self
+    }
+
+    /// Validate and return the configuration
+    pub fn build(self) -> Result<ProtocolConfig> {
+        self.config.validate()?;
+        Ok(self.config)
     }
 }
 
@@ -221,6 +420,85 @@ struct TimestampFailureRecord {
     last_failure: u64,
 }
 
+/// Record of a rejected packet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RejectionRecord {
+    /// ID of the rejected packet
+    pub packet_id: uuid::Uuid,
+    /// Rejection reason (same value as the `reason` log field)
+    pub reason: String,
+    /// Unix time of the rejection in seconds
+    pub timestamp: u64,
+}
+
+/// Signed proof that a transaction was committed at a given block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitReceipt {
+    /// ID of the committed transaction
+    pub transaction_id: uuid::Uuid,
+    /// Hash of the committed block
+    pub block_hash: Vec<u8>,
+    /// Height of that block
+    pub height: u64,
+    /// Unix time of the commit in seconds
+    pub timestamp: u64,
+    /// Ed25519 signature of the committing node over the fields above
+    pub signature: Vec<u8>,
+}
+
+impl CommitReceipt {
+    /// Bytes covered by the signature
+    fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(16 + self.block_hash.len() + 16);
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.extend_from_slice(&self.block_hash);
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes
+    }
+}
+
+/// Verify a commit receipt against the committing node's public key
+///
+/// Returns `false` if any field was modified after signing.
+pub fn verify_commit_receipt(receipt: &CommitReceipt, pubkey: &VerifyingKey) -> bool {
+    let Ok(signature) = Signature::from_slice(&receipt.signature) else {
+        return false;
+    };
+    pubkey.verify(&receipt.signing_bytes(), &signature).is_ok()
+}
+
+/// Latest block of the local commit chain
+#[derive(Debug, Clone)]
+struct LedgerTip {
+    height: u64,
+    block_hash: Vec<u8>,
+}
+
+/// Rejection caused by the peer that sent a packet
+///
+/// Attached to the error returned by [`GhostProtocol::receive_packet`] so the
+/// receive path can charge the delivering peer; recover it with
+/// `err.downcast_ref::<SenderFault>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum SenderFault {
+    /// Sender exceeded the timestamp failure rate limit
+    #[error("sender is rate limited")]
+    RateLimited,
+
+    /// Timestamp outside the accepted window (stale or replayed packet)
+    #[error("timestamp outside the accepted window")]
+    TimestampOutOfWindow,
+
+    /// Packet hash does not match its contents
+    #[error("packet integrity check failed")]
+    IntegrityFailed,
+
+    /// Byte-identical copy of a packet that was already accepted
+    #[error("packet was already accepted")]
+    Replay,
+}
+
 /// Metrics for packet processing
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PacketMetrics {
@@ -254,6 +532,22 @@ pub struct PacketMetrics {
     /// Transactions rejected due to timestamp validation
     pub rejected_transaction_timestamp: usize,
 
+    /// Transactions rejected because their `expires_at` has passed
+    #[serde(default)]
+    pub rejected_transaction_expired: usize,
+
+    /// Packets dropped on forward because their TTL was exhausted
+    #[serde(default)]
+    pub dropped_ttl_expired: usize,
+
+    /// Packets that could not be unmasked or deserialized (e.g. decoys)
+    #[serde(default)]
+    pub rejected_undecodable: usize,
+
+    /// Packets rejected because a packet with the same ID was already accepted
+    #[serde(default)]
+    pub rejected_replay: usize,
+
     /// R-03-003: Adaptive timestamp window tracking
     /// Sum of timestamp deltas for computing average network latency
     pub timestamp_delta_sum: u64,
@@ -262,6 +556,137 @@ pub struct PacketMetrics {
     pub valid_timestamp_count: usize,
 }
 
+impl PacketMetrics {
+    /// Fraction of received packets that were accepted (0.0 if none received)
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.packets_received == 0 {
+            return 0.0;
+        }
+        self.packets_accepted as f64 / self.packets_received as f64
+    }
+
+    /// Total packets rejected for any reason (resonance mismatches excluded)
+    pub fn total_rejected(&self) -> usize {
+        self.rejected_rate_limited
+            + self.rejected_timestamp_invalid
+            + self.rejected_invalid_resonance
+            + self.rejected_empty_payload
+            + self.rejected_integrity_failed
+            + self.rejected_zk_proof_failed
+            + self.rejected_transaction_timestamp
+            + self.rejected_transaction_expired
+            + self.rejected_undecodable
+            + self.rejected_replay
+    }
+
+    /// Render metrics in Prometheus text exposition format
+    ///
+    /// Every counter is emitted with the given metric name prefix
+    /// (e.g. `ghost_protocol`), followed by the derived acceptance rate gauge.
+    pub fn to_prometheus(&self, prefix: &str) -> String {
+        let counters: [(&str, &str, u64); 16] = [
+            (
+                "packets_received_total",
+                "Total packets received",
+                self.packets_received as u64,
+            ),
+            (
+                "packets_accepted_total",
+                "Packets accepted (successfully processed)",
+                self.packets_accepted as u64,
+            ),
+            (
+                "rejected_rate_limited_total",
+                "Packets rejected due to rate limiting",
+                self.rejected_rate_limited as u64,
+            ),
+            (
+                "rejected_timestamp_invalid_total",
+                "Packets rejected due to timestamp validation",
+                self.rejected_timestamp_invalid as u64,
+            ),
+            (
+                "rejected_invalid_resonance_total",
+                "Packets rejected due to invalid resonance values",
+                self.rejected_invalid_resonance as u64,
+            ),
+            (
+                "rejected_empty_payload_total",
+                "Packets rejected due to empty payload",
+                self.rejected_empty_payload as u64,
+            ),
+            (
+                "rejected_integrity_failed_total",
+                "Packets rejected due to integrity check failure",
+                self.rejected_integrity_failed as u64,
+            ),
+            (
+                "rejected_zk_proof_failed_total",
+                "Packets rejected due to ZK proof failure",
+                self.rejected_zk_proof_failed as u64,
+            ),
+            (
+                "packets_ignored_resonance_mismatch_total",
+                "Packets ignored due to resonance mismatch",
+                self.packets_ignored_resonance_mismatch as u64,
+            ),
+            (
+                "rejected_transaction_timestamp_total",
+                "Transactions rejected due to timestamp validation",
+                self.rejected_transaction_timestamp as u64,
+            ),
+            (
+                "rejected_transaction_expired_total",
+                "Transactions rejected due to expiry",
+                self.rejected_transaction_expired as u64,
+            ),
+            (
+                "dropped_ttl_expired_total",
+                "Packets dropped on forward due to exhausted TTL",
+                self.dropped_ttl_expired as u64,
+            ),
+            (
+                "rejected_undecodable_total",
+                "Packets rejected because they could not be decoded",
+                self.rejected_undecodable as u64,
+            ),
+            (
+                "rejected_replay_total",
+                "Packets rejected as replays of an accepted packet",
+                self.rejected_replay as u64,
+            ),
+            (
+                "timestamp_delta_seconds_total",
+                "Sum of observed timestamp deltas in seconds",
+                self.timestamp_delta_sum,
+            ),
+            (
+                "valid_timestamp_total",
+                "Count of valid timestamps observed",
+                self.valid_timestamp_count as u64,
+            ),
+        ];
+
+        let mut out = String::new();
+        for (name, help, value) in counters {
+            out.push_str(&format!("# HELP {prefix}_{name} {help}\n"));
+            out.push_str(&format!("# TYPE {prefix}_{name} counter\n"));
+            out.push_str(&format!("{prefix}_{name} {value}\n"));
+        }
+
+        out.push_str(&format!(
+            "# HELP {prefix}_acceptance_rate Fraction of received packets accepted\n"
+        ));
+        out.push_str(&format!("# TYPE {prefix}_acceptance_rate gauge\n"));
+        out.push_str(&format!(
+            "{prefix}_acceptance_rate {}\n",
+            self.acceptance_rate()
+        ));
+
+        out
+    }
+}
+
 /// Network condition tracker for adaptive timestamp windows (R-03-003)
 #[derive(Debug, Clone)]
 struct NetworkConditions {
@@ -278,7 +703,11 @@ struct NetworkConditions {
     sample_count: usize,
 }
 
+/// Accepted packets by ID: (packet hash, packet timestamp)
+type SeenPackets = HashMap<uuid::Uuid, ([u8; 32], u64)>;
+
 /// Ghost Protocol - Core protocol implementation
+#[derive(Debug)]
 pub struct GhostProtocol {
     config: ProtocolConfig,
     /// Rate limiter for timestamp failures (key: sender resonance hash)
@@ -287,6 +716,16 @@ pub struct GhostProtocol {
     metrics: Arc<RwLock<PacketMetrics>>,
     /// Network condition tracker for adaptive timestamp windows (R-03-003)
     network_conditions: Arc<RwLock<NetworkConditions>>,
+    /// Current resonance epsilon (adjusted by observed node density)
+    effective_epsilon: Arc<RwLock<f64>>,
+    /// Most recent rejections, oldest first
+    rejections: Arc<RwLock<VecDeque<RejectionRecord>>>,
+    /// Accepted packets still inside the timestamp window
+    seen_packets: Arc<RwLock<SeenPackets>>,
+    /// Key used to sign commit receipts
+    node_key: Arc<SigningKey>,
+    /// Tip of the local commit chain (until mef-ledger is integrated)
+    ledger_tip: Arc<RwLock<Option<LedgerTip>>>,
 }
 
 impl NetworkConditions {
@@ -359,17 +798,56 @@ impl NetworkConditions {
 impl GhostProtocol {
     /// Create new protocol instance
     pub fn new(config: ProtocolConfig) -> Self {
+        let effective_epsilon = Arc::new(RwLock::new(config.resonance_epsilon));
         Self {
+            effective_epsilon,
             config,
             timestamp_failure_tracker: Arc::new(RwLock::new(HashMap::new())),
             metrics: Arc::new(RwLock::new(PacketMetrics::default())),
             network_conditions: Arc::new(RwLock::new(NetworkConditions::new())),
+            rejections: Arc::new(RwLock::new(VecDeque::new())),
+            seen_packets: Arc::new(RwLock::new(HashMap::new())),
+            node_key: Arc::new(SigningKey::from_bytes(&rand::random::<[u8; 32]>())),
+            ledger_tip: Arc::new(RwLock::new(None)),
         }
     }
 
-    /// Create with default configuration
-    pub fn default() -> Self {
-        Self::new(ProtocolConfig::default())
+    /// Use a fixed node key for signing commit receipts
+    ///
+    /// By default a random key is generated per instance.
+    pub fn with_node_key(mut self, key: SigningKey) -> Self {
+        self.node_key = Arc::new(key);
+        self
+    }
+
+    /// Public key that verifies this node's commit receipts
+    pub fn node_public_key(&self) -> VerifyingKey {
+        self.node_key.verifying_key()
+    }
+
+    /// Sign a receipt for a transaction committed in `block_hash` at `height`
+    pub(crate) fn sign_commit_receipt(
+        &self,
+        transaction_id: uuid::Uuid,
+        block_hash: Vec<u8>,
+        height: u64,
+    ) -> CommitReceipt {
+        let mut receipt = CommitReceipt {
+            transaction_id,
+            block_hash,
+            height,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_secs(),
+            signature: Vec::new(),
+        };
+        receipt.signature = self
+            .node_key
+            .sign(&receipt.signing_bytes())
+            .to_bytes()
+            .to_vec();
+        receipt
     }
 
     /// Get current metrics
@@ -383,6 +861,93 @@ impl GhostProtocol {
             .clone()
     }
 
+    /// Get a point-in-time copy of the metrics for scraping
+    ///
+    /// The snapshot is detached from the live counters, so it stays
+    /// consistent while being exported.
+    pub fn metrics_snapshot(&self) -> PacketMetrics {
+        self.get_metrics()
+    }
+
+    /// Export current metrics in Prometheus text exposition format
+    pub fn metrics_prometheus(&self) -> String {
+        self.metrics_snapshot().to_prometheus("ghost_protocol")
+    }
+
+    /// Get the resonance epsilon currently used for matching
+    pub fn effective_epsilon(&self) -> f64 {
+        *self.effective_epsilon.read().unwrap_or_else(|e| {
+            warn!("Failed to acquire epsilon lock: {}", e);
+            e.into_inner()
+        })
+    }
+
+    /// Update the effective epsilon from the observed number of active nodes
+    ///
+    /// Has no effect unless `adaptive_epsilon` is configured.
+    ///
+    /// # Returns
+    /// * The effective epsilon after the update
+    pub fn observe_node_density(&self, active_nodes: usize) -> f64 {
+        let Some(adaptive) = &self.config.adaptive_epsilon else {
+            return self.config.resonance_epsilon;
+        };
+
+        let epsilon = adaptive.epsilon_for(self.config.resonance_epsilon, active_nodes);
+        let mut effective = self.effective_epsilon.write().unwrap_or_else(|e| {
+            warn!("Failed to acquire epsilon lock: {}", e);
+            e.into_inner()
+        });
+        *effective = epsilon;
+
+        debug!(active_nodes, epsilon, "Adaptive resonance epsilon updated");
+        epsilon
+    }
+
+    /// Update the effective epsilon from a discovery engine's active nodes
+    pub fn update_epsilon_from_discovery(&self, discovery: &crate::DiscoveryEngine) -> f64 {
+        self.observe_node_density(discovery.active_node_count())
+    }
+
+    /// Get the most recent rejections, oldest first
+    ///
+    /// Holds at most `rejection_log_capacity` records; older ones are dropped.
+    pub fn recent_rejections(&self) -> Vec<RejectionRecord> {
+        self.rejections
+            .read()
+            .unwrap_or_else(|e| {
+                warn!("Failed to acquire rejections lock: {}", e);
+                e.into_inner()
+            })
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Append a rejection to the ring buffer
+    fn record_rejection(&self, packet_id: uuid::Uuid, reason: &str) {
+        let capacity = self.config.rejection_log_capacity;
+        if capacity == 0 {
+            return;
+        }
+
+        let mut rejections = self.rejections.write().unwrap_or_else(|e| {
+            warn!("Failed to acquire rejections lock: {}", e);
+            e.into_inner()
+        });
+        while rejections.len() >= capacity {
+            rejections.pop_front();
+        }
+        rejections.push_back(RejectionRecord {
+            packet_id,
+            reason: reason.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        });
+    }
+
     /// Reset metrics
     pub fn reset_metrics(&self) {
         let mut metrics = self.metrics.write().unwrap_or_else(|e| {
@@ -507,6 +1072,47 @@ impl GhostProtocol {
         expired.len()
     }
 
+    /// Maximum accepted timestamp age in seconds (R-03-003)
+    fn max_timestamp_age(&self) -> u64 {
+        if self.config.adaptive_timestamps {
+            let conditions = self.network_conditions.read().unwrap_or_else(|e| {
+                warn!("Failed to acquire network conditions lock: {}", e);
+                e.into_inner()
+            });
+            conditions.get_max_age()
+        } else {
+            24 * 3600 // Default 24 hours
+        }
+    }
+
+    /// Remember an accepted packet so later copies are rejected as replays
+    fn record_seen_packet(&self, packet: &GhostPacket) {
+        self.seen_packets
+            .write()
+            .unwrap_or_else(|e| {
+                warn!("Failed to acquire seen packets lock: {}", e);
+                e.into_inner()
+            })
+            .insert(packet.id, (packet.hash, packet.timestamp));
+    }
+
+    /// Forget accepted packets older than the timestamp window (should be
+    /// called periodically)
+    ///
+    /// Copies that old already fail timestamp validation, so they no longer
+    /// need replay tracking.
+    pub fn cleanup_seen_packets(&self) -> usize {
+        let cutoff = current_timestamp_or_default().saturating_sub(self.max_timestamp_age());
+
+        let mut seen = self.seen_packets.write().unwrap_or_else(|e| {
+            warn!("Failed to acquire seen packets lock: {}", e);
+            e.into_inner()
+        });
+        let before = seen.len();
+        seen.retain(|_, (_, timestamp)| *timestamp >= cutoff);
+        before - seen.len()
+    }
+
     /// Validate timestamp safety with adaptive windows (R-03-003)
     ///
     /// Checks that a timestamp is:
@@ -546,7 +1152,7 @@ impl GhostProtocol {
             60 // Default 60 seconds
         };
 
-        if timestamp > now + clock_skew_tolerance {
+        if timestamp > now.saturating_add(clock_skew_tolerance) {
             warn!(
                 event = "timestamp_validation_failed",
                 reason = "future_timestamp",
@@ -564,17 +1170,9 @@ impl GhostProtocol {
         }
 
         // R-03-003: Adaptive maximum age based on network conditions
-        let max_age = if self.config.adaptive_timestamps {
-            let conditions = self.network_conditions.read().unwrap_or_else(|e| {
-                warn!("Failed to acquire network conditions lock: {}", e);
-                e.into_inner()
-            });
-            conditions.get_max_age()
-        } else {
-            24 * 3600 // Default 24 hours
-        };
+        let max_age = self.max_timestamp_age();
 
-        if timestamp + max_age < now {
+        if timestamp.saturating_add(max_age) < now {
             warn!(
                 event = "timestamp_validation_failed",
                 reason = "expired_timestamp",
@@ -723,20 +1321,46 @@ impl GhostProtocol {
         masking_params: &MaskingParams,
     ) -> Result<GhostPacket> {
         // R-03-001 & R-03-002: Include key epoch and ephemeral key in packet
-        let packet = GhostPacket::new_with_keys(
+        let mut packet = GhostPacket::new(
             transaction.target_resonance,
             transaction.sender_resonance,
             masked_data,
             stego_carrier,
             carrier_type,
             transaction.zk_data.clone(),
-            masking_params.epoch,
-            masking_params.ephemeral_key.clone(),
-        );
+        )
+        .with_keys(masking_params.epoch, masking_params.ephemeral_key.clone())
+        .with_ttl(self.config.default_ttl);
+
+        if self.config.timestamp_jitter > 0 {
+            packet.timestamp = jitter_timestamp(
+                packet.timestamp,
+                self.config.timestamp_jitter,
+                &mut rand::thread_rng(),
+            );
+            packet.hash = packet.compute_hash();
+        }
 
         Ok(packet)
     }
 
+    /// Record a packet dropped on forward because its TTL was exhausted
+    ///
+    /// Called by the relay path (see `BroadcastEngine::forward`); counted in
+    /// `dropped_ttl_expired`.
+    pub fn record_ttl_expired(&self, packet: &GhostPacket) {
+        if let Ok(mut metrics) = self.metrics.write() {
+            metrics.dropped_ttl_expired += 1;
+        }
+
+        debug!(
+            event = "packet_dropped",
+            reason = "ttl_expired",
+            packet_id = %packet.id,
+            "Packet dropped: TTL exhausted"
+        );
+    }
+
     /// Step 5: Receive and process packet
     ///
     /// Checks resonance, extracts, unmasks, and verifies packet.
@@ -748,10 +1372,42 @@ impl GhostProtocol {
     ///
     /// # Returns
     /// * Recovered transaction if resonance matches, None otherwise
+    ///
+    /// All events emitted while processing the packet nest under a
+    /// `receive_packet` span carrying `packet_id`; the span records the
+    /// processing time in `elapsed_us`.
     pub fn receive_packet(
         &self,
         packet: &GhostPacket,
         node_state: &ResonanceState,
+    ) -> Result<Option<GhostTransaction>> {
+        let span = info_span!(
+            "receive_packet",
+            packet_id = %packet.id,
+            elapsed_us = tracing::field::Empty
+        );
+        let _guard = span.enter();
+
+        let started = Instant::now();
+        let result = self.process_packet(packet, node_state);
+        let elapsed_us = started.elapsed().as_micros() as u64;
+        span.record("elapsed_us", elapsed_us);
+
+        debug!(
+            event = "packet_processed",
+            accepted = matches!(result, Ok(Some(_))),
+            elapsed_us,
+            "Packet processing finished"
+        );
+
+        result
+    }
+
+    /// Receive path body, run inside the `receive_packet` span
+    fn process_packet(
+        &self,
+        packet: &GhostPacket,
+        node_state: &ResonanceState,
     ) -> Result<Option<GhostTransaction>> {
         // Increment total packets received
         if let Ok(mut metrics) = self.metrics.write() {
@@ -765,6 +1421,8 @@ impl GhostProtocol {
                 metrics.rejected_rate_limited += 1;
             }
 
+            self.record_rejection(packet.id, "rate_limited");
+
             error!(
                 event = "packet_rejected",
                 reason = "rate_limited",
@@ -772,7 +1430,8 @@ impl GhostProtocol {
                 sender_resonance = ?(packet.sender_resonance.psi, packet.sender_resonance.rho, packet.sender_resonance.omega),
                 "Security: Packet rejected due to rate limiting (too many timestamp failures)"
             );
-            anyhow::bail!("Rate limit exceeded for timestamp validation failures");
+            return Err(anyhow::Error::new(SenderFault::RateLimited)
+                .context("Rate limit exceeded for timestamp validation failures"));
         }
 
         // Runtime Invariant: Validate packet timestamp safety (R-01-003)
@@ -785,6 +1444,8 @@ impl GhostProtocol {
                 metrics.rejected_timestamp_invalid += 1;
             }
 
+            self.record_rejection(packet.id, "timestamp_invalid");
+
             error!(
                 event = "packet_rejected",
                 reason = "timestamp_invalid",
@@ -793,19 +1454,19 @@ impl GhostProtocol {
                 error = %e,
                 "Security: Packet rejected due to timestamp validation failure"
             );
-            return Err(e).context("Packet timestamp validation failed");
+            return Err(e.context(SenderFault::TimestampOutOfWindow))
+                .context("Packet timestamp validation failed");
         }
 
         // Runtime Invariant: Resonance values must be finite (R-01-002)
-        if !packet.resonance.psi.is_finite()
-            || !packet.resonance.rho.is_finite()
-            || !packet.resonance.omega.is_finite()
-        {
+        if let Err(e) = packet.resonance.validate() {
             // Increment metric
             if let Ok(mut metrics) = self.metrics.write() {
                 metrics.rejected_invalid_resonance += 1;
             }
 
+            self.record_rejection(packet.id, "invalid_resonance");
+
             error!(
                 event = "packet_rejected",
                 reason = "invalid_resonance",
@@ -813,20 +1474,20 @@ impl GhostProtocol {
                 psi = packet.resonance.psi,
                 rho = packet.resonance.rho,
                 omega = packet.resonance.omega,
+                error = %e,
                 "Security: Packet rejected due to non-finite resonance values"
             );
-            anyhow::bail!("Invalid packet: resonance values must be finite");
+            return Err(e).context("Invalid packet: resonance values must be finite");
         }
 
-        if !packet.sender_resonance.psi.is_finite()
-            || !packet.sender_resonance.rho.is_finite()
-            || !packet.sender_resonance.omega.is_finite()
-        {
+        if let Err(e) = packet.sender_resonance.validate() {
             // Increment metric
             if let Ok(mut metrics) = self.metrics.write() {
                 metrics.rejected_invalid_resonance += 1;
             }
 
+            self.record_rejection(packet.id, "invalid_sender_resonance");
+
             error!(
                 event = "packet_rejected",
                 reason = "invalid_sender_resonance",
@@ -834,9 +1495,10 @@ impl GhostProtocol {
                 psi = packet.sender_resonance.psi,
                 rho = packet.sender_resonance.rho,
                 omega = packet.sender_resonance.omega,
+                error = %e,
                 "Security: Packet rejected due to non-finite sender resonance values"
             );
-            anyhow::bail!("Invalid packet: sender resonance values must be finite");
+            return Err(e).context("Invalid packet: sender resonance values must be finite");
         }
 
         // Runtime Invariant: Payload must not be empty (R-01-002)
@@ -846,6 +1508,8 @@ impl GhostProtocol {
                 metrics.rejected_empty_payload += 1;
             }
 
+            self.record_rejection(packet.id, "empty_payload");
+
             warn!(
                 event = "packet_rejected",
                 reason = "empty_payload",
@@ -856,7 +1520,8 @@ impl GhostProtocol {
         }
 
         // Step 5a: Check resonance R_ε(ψ_node, ψ_pkt)
-        if !packet.matches_resonance(node_state, self.config.resonance_epsilon) {
+        let epsilon = self.effective_epsilon();
+        if !packet.matches_resonance(node_state, epsilon) {
             // Increment metric
             if let Ok(mut metrics) = self.metrics.write() {
                 metrics.packets_ignored_resonance_mismatch += 1;
@@ -869,7 +1534,7 @@ impl GhostProtocol {
                 packet_id = %packet.id,
                 packet_resonance = ?(packet.resonance.psi, packet.resonance.rho, packet.resonance.omega),
                 node_resonance = ?(node_state.psi, node_state.rho, node_state.omega),
-                epsilon = self.config.resonance_epsilon,
+                epsilon,
                 "Packet ignored due to resonance mismatch"
             );
             return Ok(None);
@@ -882,73 +1547,73 @@ impl GhostProtocol {
                 metrics.rejected_integrity_failed += 1;
             }
 
+            self.record_rejection(packet.id, "integrity_check_failed");
+
             error!(
                 event = "packet_rejected",
                 reason = "integrity_check_failed",
                 packet_id = %packet.id,
                 "Security: Packet rejected due to integrity check failure"
             );
-            anyhow::bail!("Packet integrity check failed");
+            return Err(anyhow::Error::new(SenderFault::IntegrityFailed)
+                .context("Packet integrity check failed"));
         }
 
-        // Step 5c: Derive masking parameters with key rotation support (R-03-001)
-        // The receiver can compute the same params as the sender using:
-        // sender_resonance (from packet) and target_resonance (node's own state)
-        // Try the packet's epoch first, then fall back to current epoch if needed
-        let mut masking_params = MaskingParams::from_resonance_with_epoch(
-            &packet.sender_resonance,
-            node_state,
-            packet.key_epoch,
-        );
+        // Reject packets that were already accepted. Only a byte-identical
+        // copy is charged to the sender; a relayed copy differs in its TTL.
+        let seen_hash = self
+            .seen_packets
+            .read()
+            .unwrap_or_else(|e| {
+                warn!("Failed to acquire seen packets lock: {}", e);
+                e.into_inner()
+            })
+            .get(&packet.id)
+            .map(|(hash, _)| *hash);
+        if let Some(seen_hash) = seen_hash {
+            if let Ok(mut metrics) = self.metrics.write() {
+                metrics.rejected_replay += 1;
+            }
 
-        // R-03-002: Add ephemeral key for forward secrecy if present
-        if let Some(ref ephemeral) = packet.ephemeral_key {
-            masking_params = masking_params.with_ephemeral_key(ephemeral.clone());
+            self.record_rejection(packet.id, "replay");
+
+            warn!(
+                event = "packet_rejected",
+                reason = "replay",
+                packet_id = %packet.id,
+                "Security: Packet rejected as a replay of an accepted packet"
+            );
+            if seen_hash == packet.hash {
+                return Err(
+                    anyhow::Error::new(SenderFault::Replay).context("Packet was already accepted")
+                );
+            }
+            anyhow::bail!("Packet {} was already accepted", packet.id);
         }
 
-        // Step 5d: Extract from steganographic carrier: a' = T⁻¹(t)
-        let extracted = if self.config.enable_steganography {
-            self.extract_from_carrier(&packet.stego_carrier, packet.carrier_type)?
-        } else {
-            packet.masked_payload.clone()
-        };
-
-        // Step 5e: Unmask: a* = M⁻¹_{θ,σ}(a')
-        // R-03-001: Try current epoch, then previous epoch during rotation
-        let unmasked = match self.unmask_data(&extracted, &masking_params) {
-            Ok(data) => data,
-            Err(_) => {
-                // Key rotation: Try previous epoch
-                let current_epoch = MaskingParams::current_epoch();
-                if packet.key_epoch < current_epoch && current_epoch - packet.key_epoch <= 1 {
-                    debug!(
-                        event = "key_rotation_fallback",
-                        packet_epoch = packet.key_epoch,
-                        current_epoch = current_epoch,
-                        "Trying previous epoch key during rotation"
-                    );
-
-                    let mut fallback_params = MaskingParams::from_resonance_with_epoch(
-                        &packet.sender_resonance,
-                        node_state,
-                        current_epoch,
-                    );
+        // Steps 5c-5f: Decode the transaction. Decoys and packets for other
+        // senders that share our resonance fail here; they are counted and
+        // rejected like any other invalid packet.
+        let transaction = match self.decode_transaction(packet, node_state) {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                if let Ok(mut metrics) = self.metrics.write() {
+                    metrics.rejected_undecodable += 1;
+                }
 
-                    if let Some(ref ephemeral) = packet.ephemeral_key {
-                        fallback_params = fallback_params.with_ephemeral_key(ephemeral.clone());
-                    }
+                self.record_rejection(packet.id, "undecodable");
 
-                    self.unmask_data(&extracted, &fallback_params)?
-                } else {
-                    anyhow::bail!("Failed to unmask packet with any known epoch");
-                }
+                debug!(
+                    event = "packet_rejected",
+                    reason = "undecodable",
+                    packet_id = %packet.id,
+                    error = %e,
+                    "Packet could not be decoded (decoy or not addressed to this node)"
+                );
+                return Err(e);
             }
         };
 
-        // Step 5f: Deserialize transaction
-        let transaction =
-            GhostTransaction::from_bytes(&unmasked).context("Failed to deserialize transaction")?;
-
         // Runtime Invariant: Validate transaction timestamp (R-01-003)
         if let Err(e) = self.validate_timestamp(transaction.timestamp) {
             // Record the failure for rate limiting
@@ -959,6 +1624,8 @@ impl GhostProtocol {
                 metrics.rejected_transaction_timestamp += 1;
             }
 
+            self.record_rejection(packet.id, "timestamp_invalid");
+
             error!(
                 event = "transaction_rejected",
                 reason = "timestamp_invalid",
@@ -968,7 +1635,28 @@ impl GhostProtocol {
                 error = %e,
                 "Security: Transaction rejected due to timestamp validation failure"
             );
-            return Err(e).context("Transaction timestamp validation failed");
+            return Err(e.context(SenderFault::TimestampOutOfWindow))
+                .context("Transaction timestamp validation failed");
+        }
+
+        // Reject transactions past their own expiry, even if the
+        // timestamp is still inside the general window
+        if transaction.is_expired() {
+            if let Ok(mut metrics) = self.metrics.write() {
+                metrics.rejected_transaction_expired += 1;
+            }
+
+            self.record_rejection(packet.id, "expired");
+
+            warn!(
+                event = "transaction_rejected",
+                reason = "expired",
+                packet_id = %packet.id,
+                transaction_id = %transaction.id,
+                expires_at = ?transaction.expires_at,
+                "Transaction rejected: past its expiry"
+            );
+            anyhow::bail!("Transaction {} has expired", transaction.id);
         }
 
         // Step 5g: Verify ZK proof if present
@@ -980,6 +1668,8 @@ impl GhostProtocol {
                         metrics.rejected_zk_proof_failed += 1;
                     }
 
+                    self.record_rejection(packet.id, "zk_proof_invalid");
+
                     error!(
                         event = "transaction_rejected",
                         reason = "zk_proof_invalid",
@@ -993,6 +1683,8 @@ impl GhostProtocol {
             }
         }
 
+        self.record_seen_packet(packet);
+
         // Increment packets accepted metric
         if let Ok(mut metrics) = self.metrics.write() {
             metrics.packets_accepted += 1;
@@ -1010,21 +1702,120 @@ impl GhostProtocol {
 
     /// Step 6: Commit to ledger (interface for integration)
     ///
-    /// This would integrate with mef-ledger to commit the transaction.
+    /// This would integrate with mef-ledger to commit the transaction. Until
+    /// then each commit appends a block to a local hash chain. Commits that
+    /// seal a height through fork resolution go through
+    /// [`GhostNetworkNode::commit_transactions`](crate::GhostNetworkNode::commit_transactions).
     ///
     /// # Arguments
     /// * `transaction` - Verified transaction to commit
     ///
     /// # Returns
-    /// * Block ID (placeholder - actual integration needed)
-    pub fn commit_to_ledger(&self, transaction: &GhostTransaction) -> Result<Vec<u8>> {
+    /// * Receipt signed with the node key, verifiable via
+    ///   [`verify_commit_receipt`] and [`GhostProtocol::node_public_key`]
+    pub fn commit_to_ledger(&self, transaction: &GhostTransaction) -> Result<CommitReceipt> {
+        use sha2::{Digest, Sha256};
+
         // TODO: Integrate with mef-ledger
-        // For now, return transaction ID as placeholder
-        Ok(transaction.id.as_bytes().to_vec())
+        let mut tip = self
+            .ledger_tip
+            .write()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire ledger lock: {}", e))?;
+
+        let (height, prev_hash) = match tip.as_ref() {
+            Some(tip) => (tip.height + 1, tip.block_hash.clone()),
+            None => (0, vec![0u8; 32]),
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(&prev_hash);
+        hasher.update(height.to_le_bytes());
+        hasher.update(transaction.id.as_bytes());
+        let block_hash = hasher.finalize().to_vec();
+
+        *tip = Some(LedgerTip {
+            height,
+            block_hash: block_hash.clone(),
+        });
+
+        let receipt = self.sign_commit_receipt(transaction.id, block_hash, height);
+
+        debug!(
+            event = "transaction_committed",
+            transaction_id = %transaction.id,
+            height,
+            "Transaction committed"
+        );
+
+        Ok(receipt)
     }
 
     // ==================== Private Helper Methods ====================
 
+    /// Derive masking parameters, extract, unmask and deserialize a packet
+    fn decode_transaction(
+        &self,
+        packet: &GhostPacket,
+        node_state: &ResonanceState,
+    ) -> Result<GhostTransaction> {
+        // Step 5c: Derive masking parameters with key rotation support (R-03-001)
+        // The receiver can compute the same params as the sender using:
+        // sender_resonance (from packet) and target_resonance (node's own state)
+        // Try the packet's epoch first, then fall back to current epoch if needed
+        let mut masking_params = MaskingParams::from_resonance_with_epoch(
+            &packet.sender_resonance,
+            node_state,
+            packet.key_epoch,
+        );
+
+        // R-03-002: Add ephemeral key for forward secrecy if present
+        if let Some(ref ephemeral) = packet.ephemeral_key {
+            masking_params = masking_params.with_ephemeral_key(ephemeral.clone());
+        }
+
+        // Step 5d: Extract from steganographic carrier: a' = T⁻¹(t)
+        let extracted = if self.config.enable_steganography {
+            self.extract_from_carrier(&packet.stego_carrier, packet.carrier_type)?
+        } else {
+            packet.masked_payload.clone()
+        };
+
+        // Step 5e: Unmask: a* = M⁻¹_{θ,σ}(a')
+        // R-03-001: Try current epoch, then previous epoch during rotation
+        let unmasked = match self.unmask_data(&extracted, &masking_params) {
+            Ok(data) => data,
+            Err(_) => {
+                // Key rotation: Try previous epoch
+                let current_epoch = MaskingParams::current_epoch();
+                if packet.key_epoch < current_epoch && current_epoch - packet.key_epoch <= 1 {
+                    debug!(
+                        event = "key_rotation_fallback",
+                        packet_epoch = packet.key_epoch,
+                        current_epoch = current_epoch,
+                        "Trying previous epoch key during rotation"
+                    );
+
+                    let mut fallback_params = MaskingParams::from_resonance_with_epoch(
+                        &packet.sender_resonance,
+                        node_state,
+                        current_epoch,
+                    );
+
+                    if let Some(ref ephemeral) = packet.ephemeral_key {
+                        fallback_params = fallback_params.with_ephemeral_key(ephemeral.clone());
+                    }
+
+                    self.unmask_data(&extracted, &fallback_params)?
+                } else {
+                    anyhow::bail!("Failed to unmask packet with any known epoch");
+                }
+            }
+        };
+
+        // Step 5f: Deserialize transaction
+        GhostTransaction::from_bytes(&unmasked).context("Failed to deserialize transaction")
+    }
+
     /// Create ZK proof for action
     fn create_zk_proof(&self, action: &[u8]) -> Result<Vec<u8>> {
         use sha2::{Digest, Sha256};
@@ -1168,6 +1959,13 @@ impl GhostProtocol {
     }
 }
 
+impl Default for GhostProtocol {
+    /// Create with default configuration
+    fn default() -> Self {
+        Self::new(ProtocolConfig::default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1253,9 +2051,10 @@ mod tests {
             .create_packet(&tx, masked, carrier, CarrierType::Raw, &params)
             .unwrap();
 
-        // Step 5: Receive (matching resonance)
-        // The receiver's resonance should be close to target
-        let node_state = ResonanceState::new(2.05, 2.05, 2.05);
+        // Step 5: Receive at the target resonance
+        // The unmasking key derives from the receiver's own state, so only the
+        // exact target can decode; nearby nodes merely match the window
+        let node_state = target;
         let received = protocol.receive_packet(&packet, &node_state).unwrap();
 
         assert!(received.is_some(), "Packet should be received");
@@ -1294,6 +2093,333 @@ mod tests {
         assert!(received.is_none(), "Non-resonant packet should be ignored");
     }
 
+    fn build_packet(protocol: &GhostProtocol, resonance: ResonanceState) -> GhostPacket {
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"metrics".to_vec())
+            .unwrap();
+        build_packet_for(protocol, &tx, resonance)
+    }
+
+    fn build_packet_for(
+        protocol: &GhostProtocol,
+        tx: &GhostTransaction,
+        resonance: ResonanceState,
+    ) -> GhostPacket {
+        let params = MaskingParams::from_resonance(&resonance, &resonance);
+        let masked = protocol.mask_transaction(tx, &params).unwrap();
+        let carrier = protocol
+            .embed_transaction(&masked, CarrierType::Raw)
+            .unwrap();
+        protocol
+            .create_packet(tx, masked, carrier, CarrierType::Raw, &params)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_non_finite_resonance_rejected() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"finite".to_vec())
+            .unwrap();
+
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let mut packet = build_packet_for(&protocol, &tx, resonance);
+            packet.resonance.omega = bad;
+            let err = protocol.receive_packet(&packet, &resonance).unwrap_err();
+            assert!(format!("{:#}", err).contains("component 2 is not finite"));
+
+            let mut packet = build_packet_for(&protocol, &tx, resonance);
+            packet.sender_resonance.psi = bad;
+            let err = protocol.receive_packet(&packet, &resonance).unwrap_err();
+            assert!(format!("{:#}", err).contains("sender resonance"));
+        }
+
+        assert_eq!(protocol.get_metrics().rejected_invalid_resonance, 6);
+        let reasons: Vec<String> = protocol
+            .recent_rejections()
+            .into_iter()
+            .map(|r| r.reason)
+            .collect();
+        assert_eq!(
+            reasons[..2],
+            ["invalid_resonance", "invalid_sender_resonance"]
+        );
+    }
+
+    #[test]
+    fn test_sender_faults_attached_to_rejections() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"faults".to_vec())
+            .unwrap();
+        let fault = |err: anyhow::Error| err.downcast_ref::<SenderFault>().copied();
+
+        // Tampered payload
+        let mut tampered = build_packet_for(&protocol, &tx, resonance);
+        tampered.masked_payload.push(0);
+        let err = protocol.receive_packet(&tampered, &resonance).unwrap_err();
+        assert_eq!(fault(err), Some(SenderFault::IntegrityFailed));
+
+        // Intact packet replayed long after it was sent
+        let mut replayed = build_packet_for(&protocol, &tx, resonance);
+        replayed.timestamp -= 7 * 86_400;
+        replayed.hash = replayed.compute_hash();
+        let err = protocol.receive_packet(&replayed, &resonance).unwrap_err();
+        assert_eq!(fault(err), Some(SenderFault::TimestampOutOfWindow));
+
+        // Repeated timestamp failures trip the rate limit
+        let rate_limited = (0..20)
+            .map(|_| protocol.receive_packet(&replayed, &resonance).unwrap_err())
+            .any(|err| fault(err) == Some(SenderFault::RateLimited));
+        assert!(rate_limited);
+
+        // Other rejections are not charged to the sender
+        let mut empty = build_packet_for(&protocol, &tx, resonance);
+        empty.masked_payload.clear();
+        empty.hash = empty.compute_hash();
+        let err = GhostProtocol::default()
+            .receive_packet(&empty, &resonance)
+            .unwrap_err();
+        assert_eq!(fault(err), None);
+    }
+
+    #[test]
+    fn test_replayed_packet_charged_as_replay() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let packet = build_packet(&protocol, resonance);
+        let fault = |err: anyhow::Error| err.downcast_ref::<SenderFault>().copied();
+
+        assert!(protocol
+            .receive_packet(&packet, &resonance)
+            .unwrap()
+            .is_some());
+
+        // Byte-identical copy inside the timestamp window
+        let err = protocol.receive_packet(&packet, &resonance).unwrap_err();
+        assert_eq!(fault(err), Some(SenderFault::Replay));
+
+        // Relayed copy with a lowered TTL is dropped without blame
+        let mut relayed = packet.clone();
+        assert!(relayed.decrement_ttl());
+        let err = protocol.receive_packet(&relayed, &resonance).unwrap_err();
+        assert_eq!(fault(err), None);
+
+        let metrics = protocol.get_metrics();
+        assert_eq!(metrics.packets_accepted, 1);
+        assert_eq!(metrics.rejected_replay, 2);
+    }
+
+    #[test]
+    fn test_cleanup_seen_packets_drops_entries_outside_window() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let packet = build_packet(&protocol, resonance);
+        protocol.receive_packet(&packet, &resonance).unwrap();
+
+        // Entry whose packet would already fail timestamp validation
+        let stale_id = uuid::Uuid::new_v4();
+        protocol
+            .seen_packets
+            .write()
+            .unwrap()
+            .insert(stale_id, ([0; 32], 1));
+
+        assert_eq!(protocol.cleanup_seen_packets(), 1);
+        let seen = protocol.seen_packets.read().unwrap();
+        assert!(seen.contains_key(&packet.id));
+        assert!(!seen.contains_key(&stale_id));
+    }
+
+    #[test]
+    fn test_expired_transaction_rejected() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+
+        // Fresh timestamp (inside the general window) but already expired
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"expiring".to_vec())
+            .unwrap();
+        let expires_at = tx.timestamp.saturating_sub(1);
+        let tx = tx.with_expires_at(expires_at);
+        assert!(tx.is_expired());
+
+        let packet = build_packet_for(&protocol, &tx, resonance);
+        assert!(protocol.receive_packet(&packet, &resonance).is_err());
+
+        let metrics = protocol.get_metrics();
+        assert_eq!(metrics.rejected_transaction_expired, 1);
+        assert_eq!(metrics.rejected_transaction_timestamp, 0);
+        assert_eq!(metrics.packets_accepted, 0);
+    }
+
+    #[test]
+    fn test_transaction_expiry_unset_or_future_accepted() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"no expiry".to_vec())
+            .unwrap();
+        assert_eq!(tx.expires_at, None);
+        let packet = build_packet_for(&protocol, &tx, resonance);
+        assert!(protocol
+            .receive_packet(&packet, &resonance)
+            .unwrap()
+            .is_some());
+
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"long lived".to_vec())
+            .unwrap()
+            .with_ttl(3600);
+        let packet = build_packet_for(&protocol, &tx, resonance);
+        let received = protocol
+            .receive_packet(&packet, &resonance)
+            .unwrap()
+            .unwrap();
+        assert_eq!(received.expires_at, tx.expires_at);
+
+        assert_eq!(protocol.get_metrics().rejected_transaction_expired, 0);
+    }
+
+    fn adaptive_protocol() -> GhostProtocol {
+        GhostProtocol::new(ProtocolConfig {
+            adaptive_epsilon: Some(AdaptiveEpsilonConfig::default()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_adaptive_epsilon_stays_within_bounds() {
+        let protocol = adaptive_protocol();
+        let bounds = AdaptiveEpsilonConfig::default();
+
+        // Reference density keeps the configured epsilon
+        assert!((protocol.observe_node_density(10) - 0.1).abs() < 1e-9);
+
+        // Sparse field widens the window, dense field narrows it
+        let sparse = protocol.observe_node_density(2);
+        let dense = protocol.observe_node_density(200);
+        assert!(sparse > 0.1 && sparse <= bounds.max_epsilon);
+        assert!(dense < 0.1 && dense >= bounds.min_epsilon);
+
+        // Extremes are clamped
+        assert_eq!(protocol.observe_node_density(0), 0.1 * 10f64.cbrt());
+        assert_eq!(protocol.observe_node_density(1_000_000), bounds.min_epsilon);
+    }
+
+    #[test]
+    fn test_fixed_epsilon_ignores_density() {
+        let protocol = GhostProtocol::default();
+
+        protocol.observe_node_density(1);
+        assert_eq!(protocol.effective_epsilon(), 0.1);
+    }
+
+    #[test]
+    fn test_adaptive_epsilon_changes_matching() {
+        let protocol = adaptive_protocol();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let packet = build_packet(&protocol, resonance);
+
+        // Node sits 0.15 away: outside the base window of 0.1
+        let node = ResonanceState::new(1.15, 1.0, 1.0);
+
+        // Sparse field: window widens past 0.15, packet is not ignored
+        protocol.observe_node_density(2);
+        let _ = protocol.receive_packet(&packet, &node);
+        assert_eq!(protocol.get_metrics().packets_ignored_resonance_mismatch, 0);
+
+        // Dense field: window narrows, packet is ignored
+        protocol.observe_node_density(200);
+        assert!(protocol.receive_packet(&packet, &node).unwrap().is_none());
+        assert_eq!(protocol.get_metrics().packets_ignored_resonance_mismatch, 1);
+    }
+
+    #[test]
+    fn test_acceptance_rate_zero_when_nothing_received() {
+        let metrics = PacketMetrics::default();
+        assert_eq!(metrics.acceptance_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_acceptance_rate_mixed_packets() {
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+
+        // Three valid packets
+        for _ in 0..3 {
+            let packet = build_packet(&protocol, resonance);
+            assert!(protocol
+                .receive_packet(&packet, &resonance)
+                .unwrap()
+                .is_some());
+        }
+
+        // One tampered packet (integrity failure)
+        let mut tampered = build_packet(&protocol, resonance);
+        tampered.stego_carrier.push(0xFF);
+        assert!(protocol.receive_packet(&tampered, &resonance).is_err());
+
+        let snapshot = protocol.metrics_snapshot();
+        assert_eq!(snapshot.packets_received, 4);
+        assert_eq!(snapshot.packets_accepted, 3);
+        assert_eq!(snapshot.rejected_integrity_failed, 1);
+        assert_eq!(snapshot.total_rejected(), 1);
+        assert!((snapshot.acceptance_rate() - 0.75).abs() < 1e-12);
+
+        // Snapshot is detached from live counters
+        let packet = build_packet(&protocol, resonance);
+        protocol.receive_packet(&packet, &resonance).unwrap();
+        assert_eq!(snapshot.packets_received, 4);
+        assert_eq!(protocol.metrics_snapshot().packets_received, 5);
+    }
+
+    #[test]
+    fn test_prometheus_exposition_contains_counters() {
+        let metrics = PacketMetrics {
+            packets_received: 10,
+            packets_accepted: 7,
+            rejected_empty_payload: 2,
+            packets_ignored_resonance_mismatch: 1,
+            ..Default::default()
+        };
+
+        let text = metrics.to_prometheus("ghost_protocol");
+
+        for name in [
+            "packets_received_total",
+            "packets_accepted_total",
+            "rejected_rate_limited_total",
+            "rejected_timestamp_invalid_total",
+            "rejected_invalid_resonance_total",
+            "rejected_empty_payload_total",
+            "rejected_integrity_failed_total",
+            "rejected_zk_proof_failed_total",
+            "packets_ignored_resonance_mismatch_total",
+            "rejected_transaction_timestamp_total",
+            "rejected_transaction_expired_total",
+            "dropped_ttl_expired_total",
+            "rejected_undecodable_total",
+            "rejected_replay_total",
+            "timestamp_delta_seconds_total",
+            "valid_timestamp_total",
+        ] {
+            assert!(
+                text.contains(&format!("# TYPE ghost_protocol_{name} counter")),
+                "missing TYPE line for {name}"
+            );
+        }
+
+        assert!(text.contains("ghost_protocol_packets_received_total 10\n"));
+        assert!(text.contains("ghost_protocol_packets_accepted_total 7\n"));
+        assert!(text.contains("ghost_protocol_rejected_empty_payload_total 2\n"));
+        assert!(text.contains("# TYPE ghost_protocol_acceptance_rate gauge"));
+        assert!(text.contains("ghost_protocol_acceptance_rate 0.7\n"));
+    }
+
     #[test]
     fn test_zk_proof_verification() {
         let protocol = GhostProtocol::default();
@@ -1353,8 +2479,8 @@ mod tests {
             .create_packet(&tx, masked, carrier, CarrierType::Raw, &sender_params)
             .unwrap();
 
-        // Receiver with matching resonance
-        let receiver_resonance = ResonanceState::new(2.05, 2.05, 2.05); // Close to target
+        // Receiver at the target resonance (the key derives from its own state)
+        let receiver_resonance = target_resonance;
         let received = protocol
             .receive_packet(&packet, &receiver_resonance)
             .unwrap();
@@ -1368,4 +2494,301 @@ mod tests {
         );
         assert_eq!(recovered_tx.id, tx.id, "Transaction ID should be preserved");
     }
+
+    /// Tracing layer recording `receive_packet` span entries and the
+    /// `event` field of everything emitted inside or outside that span
+    mod capture {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id};
+        use tracing::{Event, Subscriber};
+        use tracing_subscriber::layer::{Context, Layer};
+        use tracing_subscriber::registry::LookupSpan;
+
+        #[derive(Debug, Default)]
+        pub(super) struct Captured {
+            pub(super) spans_created: usize,
+            pub(super) spans_entered: usize,
+            pub(super) events_in_span: Vec<String>,
+            pub(super) events_outside_span: Vec<String>,
+        }
+
+        #[derive(Clone, Default)]
+        pub(super) struct CaptureLayer(pub(super) Arc<Mutex<Captured>>);
+
+        struct EventName(Option<String>);
+
+        impl Visit for EventName {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "event" {
+                    self.0 = Some(value.to_string());
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl<S> Layer<S> for CaptureLayer
+        where
+            S: Subscriber + for<'a> LookupSpan<'a>,
+        {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+                if attrs.metadata().name() == "receive_packet" {
+                    self.0.lock().unwrap().spans_created += 1;
+                }
+            }
+
+            fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+                if ctx.span(id).map(|s| s.name()) == Some("receive_packet") {
+                    self.0.lock().unwrap().spans_entered += 1;
+                }
+            }
+
+            fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+                let mut name = EventName(None);
+                event.record(&mut name);
+                let Some(name) = name.0 else {
+                    return;
+                };
+
+                let in_span = ctx
+                    .event_scope(event)
+                    .map(|mut scope| scope.any(|span| span.name() == "receive_packet"))
+                    .unwrap_or(false);
+
+                let mut captured = self.0.lock().unwrap();
+                if in_span {
+                    captured.events_in_span.push(name);
+                } else {
+                    captured.events_outside_span.push(name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_receive_span_wraps_each_packet() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let protocol = GhostProtocol::default();
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"traced".to_vec())
+            .unwrap();
+        let accepted = build_packet_for(&protocol, &tx, resonance);
+        let mut rejected = build_packet_for(&protocol, &tx, resonance);
+        rejected.masked_payload.clear();
+
+        let layer = capture::CaptureLayer::default();
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(protocol
+                .receive_packet(&accepted, &resonance)
+                .unwrap()
+                .is_some());
+            assert!(protocol.receive_packet(&rejected, &resonance).is_err());
+        });
+
+        let captured = layer.0.lock().unwrap();
+
+        // One span per packet, entered exactly once each
+        assert_eq!(captured.spans_created, 2);
+        assert_eq!(captured.spans_entered, 2);
+
+        // Rejection and completion events nest under the span
+        assert!(captured
+            .events_in_span
+            .contains(&"packet_rejected".to_string()));
+        assert!(captured
+            .events_in_span
+            .contains(&"transaction_accepted".to_string()));
+        assert_eq!(
+            captured
+                .events_in_span
+                .iter()
+                .filter(|e| *e == "packet_processed")
+                .count(),
+            2
+        );
+        assert!(captured.events_outside_span.is_empty());
+    }
+
+    #[test]
+    fn test_rejections_recorded_in_order_and_wrap() {
+        let protocol = GhostProtocol::new(ProtocolConfig {
+            rejection_log_capacity: 2,
+            ..ProtocolConfig::default()
+        });
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+        let tx = protocol
+            .create_transaction(resonance, resonance, b"rejections".to_vec())
+            .unwrap();
+
+        let accepted = build_packet_for(&protocol, &tx, resonance);
+        let mut empty = build_packet_for(&protocol, &tx, resonance);
+        empty.masked_payload.clear();
+        let mut non_finite = build_packet_for(&protocol, &tx, resonance);
+        non_finite.resonance.rho = f64::INFINITY;
+        let mut tampered = build_packet_for(&protocol, &tx, resonance);
+        tampered.stego_carrier.push(0);
+
+        assert!(protocol.receive_packet(&accepted, &resonance).is_ok());
+        assert!(protocol.recent_rejections().is_empty());
+
+        assert!(protocol.receive_packet(&empty, &resonance).is_err());
+        assert!(protocol.receive_packet(&non_finite, &resonance).is_err());
+        let recorded = protocol.recent_rejections();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].packet_id, empty.id);
+        assert_eq!(recorded[0].reason, "empty_payload");
+        assert_eq!(recorded[1].packet_id, non_finite.id);
+        assert_eq!(recorded[1].reason, "invalid_resonance");
+
+        // At capacity the oldest record is dropped
+        assert!(protocol.receive_packet(&tampered, &resonance).is_err());
+        let recorded = protocol.recent_rejections();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].packet_id, non_finite.id);
+        assert_eq!(recorded[1].packet_id, tampered.id);
+        assert_eq!(recorded[1].reason, "integrity_check_failed");
+        assert!(recorded.iter().all(|r| r.packet_id != accepted.id));
+    }
+
+    #[test]
+    fn test_config_builder_valid() {
+        let config = ProtocolConfig::builder()
+            .resonance_epsilon(0.2)
+            .default_ttl(8)
+            .max_packet_size(4096)
+            .enable_steganography(false)
+            .default_carrier_type(CarrierType::Raw)
+            .rejection_log_capacity(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.resonance_epsilon, 0.2);
+        assert_eq!(config.default_ttl, 8);
+        assert_eq!(config.max_packet_size, 4096);
+        assert!(!config.enable_steganography);
+        assert_eq!(config.rejection_log_capacity, 0);
+        assert!(ProtocolConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_builder_rejects_invalid() {
+        let err = |builder: ProtocolConfigBuilder| builder.build().unwrap_err().to_string();
+
+        assert!(err(ProtocolConfig::builder().resonance_epsilon(0.0))
+            .contains("resonance_epsilon must be positive"));
+        assert!(err(ProtocolConfig::builder().resonance_epsilon(f64::NAN))
+            .contains("resonance_epsilon must be positive"));
+        assert!(err(ProtocolConfig::builder().default_ttl(0))
+            .contains("default_ttl must be greater than 0"));
+        assert!(err(ProtocolConfig::builder().max_packet_size(0))
+            .contains("max_packet_size must be between"));
+        assert!(
+            err(ProtocolConfig::builder().max_packet_size(MAX_PACKET_SIZE_LIMIT + 1))
+                .contains("max_packet_size must be between")
+        );
+        assert!(err(ProtocolConfig::builder()
+            .enable_steganography(false)
+            .default_carrier_type(CarrierType::Audio))
+        .contains("Audio requires enable_steganography"));
+        assert!(err(
+            ProtocolConfig::builder().adaptive_epsilon(Some(AdaptiveEpsilonConfig {
+                min_epsilon: 0.5,
+                max_epsilon: 0.1,
+                reference_nodes: 10,
+            }))
+        )
+        .contains("min_epsilon <= max_epsilon"));
+        assert!(
+            err(ProtocolConfig::builder().timestamp_jitter(MAX_TIMESTAMP_JITTER + 1))
+                .contains("timestamp_jitter must be at most")
+        );
+    }
+
+    #[test]
+    fn test_packet_timestamp_jitter_within_bounds() {
+        let protocol = GhostProtocol::new(
+            ProtocolConfig::builder()
+                .timestamp_jitter(MAX_TIMESTAMP_JITTER)
+                .build()
+                .unwrap(),
+        );
+        let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+
+        for _ in 0..20 {
+            let before = mef_common::current_timestamp().unwrap();
+            let packet = build_packet(&protocol, resonance);
+            let after = mef_common::current_timestamp().unwrap();
+
+            assert!(packet.timestamp >= before);
+            assert!(packet.timestamp <= after + MAX_TIMESTAMP_JITTER);
+            assert!(packet.verify_integrity());
+
+            // Jittered packets stay inside the receiver's skew tolerance
+            assert!(protocol
+                .receive_packet(&packet, &resonance)
+                .unwrap()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_timestamp_validation_near_max_does_not_wrap() {
+        let protocol = GhostProtocol::default();
+        assert!(protocol.validate_timestamp(u64::MAX).is_err());
+        assert!(protocol.validate_timestamp(u64::MAX - 30).is_err());
+    }
+
+    #[test]
+    fn test_commit_receipt_verifies() {
+        let protocol = GhostProtocol::default().with_node_key(SigningKey::from_bytes(&[1u8; 32]));
+        let other = SigningKey::from_bytes(&[2u8; 32]);
+
+        let sender = ResonanceState::new(1.0, 1.0, 1.0);
+        let target = ResonanceState::new(2.0, 2.0, 2.0);
+        let first = protocol
+            .create_transaction(sender, target, b"first".to_vec())
+            .unwrap();
+        let second = protocol
+            .create_transaction(sender, target, b"second".to_vec())
+            .unwrap();
+
+        let receipt = protocol.commit_to_ledger(&first).unwrap();
+        assert_eq!(receipt.transaction_id, first.id);
+        assert_eq!(receipt.height, 0);
+        assert!(verify_commit_receipt(&receipt, &protocol.node_public_key()));
+        assert!(!verify_commit_receipt(&receipt, &other.verifying_key()));
+
+        let next = protocol.commit_to_ledger(&second).unwrap();
+        assert_eq!(next.height, 1);
+        assert_ne!(next.block_hash, receipt.block_hash);
+        assert!(verify_commit_receipt(&next, &protocol.node_public_key()));
+    }
+
+    #[test]
+    fn test_forged_commit_receipt_rejected() {
+        let protocol = GhostProtocol::default();
+        let sender = ResonanceState::new(1.0, 1.0, 1.0);
+        let tx = protocol
+            .create_transaction(sender, sender, b"action".to_vec())
+            .unwrap();
+        let receipt = protocol.commit_to_ledger(&tx).unwrap();
+
+        let mut forged = receipt.clone();
+        forged.block_hash[0] ^= 0xff;
+        assert!(!verify_commit_receipt(&forged, &protocol.node_public_key()));
+
+        let mut forged = receipt.clone();
+        forged.height += 1;
+        assert!(!verify_commit_receipt(&forged, &protocol.node_public_key()));
+
+        let mut forged = receipt;
+        forged.signature.truncate(10);
+        assert!(!verify_commit_receipt(&forged, &protocol.node_public_key()));
+    }
 }
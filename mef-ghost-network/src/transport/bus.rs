@@ -0,0 +1,291 @@
+/*!
+ * Shared In-Memory Broadcast Bus
+ *
+ * Connects several Ghost nodes inside one process without a real network.
+ * Every node attaches to the bus and gets a [`BusTransport`]; a packet
+ * broadcast by one member is delivered immediately to all other members,
+ * so multi-node tests are deterministic.
+ *
+ * # Usage
+ *
+ * ```rust,no_run
+ * use mef_ghost_network::transport::SharedBroadcastBus;
+ * use mef_ghost_network::{GhostNetwork, NodeIdentity, ProtocolConfig, ResonanceState};
+ *
+ * let bus = SharedBroadcastBus::new();
+ * let identity = NodeIdentity::new(ResonanceState::new(1.0, 1.0, 1.0), None);
+ * let network = GhostNetwork::with_bus(ProtocolConfig::default(), identity, &bus);
+ * ```
+ */
+
+use super::{PeerId, Transport, TransportStats};
+use crate::packet::GhostPacket;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::mpsc;
+
+type Inbox = mpsc::UnboundedSender<(PeerId, GhostPacket)>;
+
+/// In-memory bus shared by all attached transports
+#[derive(Debug, Clone, Default)]
+pub struct SharedBroadcastBus {
+    /// Inboxes of attached members
+    members: Arc<RwLock<HashMap<PeerId, Inbox>>>,
+}
+
+impl SharedBroadcastBus {
+    /// Create new empty bus
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a new member and return its transport
+    pub fn attach(&self) -> BusTransport {
+        let peer_id = PeerId::random();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        self.members
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(peer_id, tx);
+
+        BusTransport {
+            bus: self.clone(),
+            peer_id,
+            inbox: rx,
+            stats: TransportStats::new(),
+        }
+    }
+
+    /// Number of attached members
+    pub fn member_count(&self) -> usize {
+        self.members.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Deliver packet to one member
+    fn deliver(&self, from: PeerId, to: PeerId, packet: GhostPacket) -> Result<()> {
+        let members = self
+            .members
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock on bus members: {}", e))?;
+        let inbox = members
+            .get(&to)
+            .ok_or_else(|| anyhow!("Peer {} is not attached to the bus", to))?;
+        inbox
+            .send((from, packet))
+            .map_err(|_| anyhow!("Peer {} inbox is closed", to))
+    }
+
+    /// Deliver packet to every member except the sender
+    ///
+    /// # Returns
+    /// * Number of members the packet was delivered to
+    fn deliver_all(&self, from: PeerId, packet: &GhostPacket) -> Result<usize> {
+        let members = self
+            .members
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock on bus members: {}", e))?;
+
+        let mut delivered = 0;
+        for (peer_id, inbox) in members.iter() {
+            if *peer_id != from && inbox.send((from, packet.clone())).is_ok() {
+                delivered += 1;
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Detach a member
+    fn detach(&self, peer_id: PeerId) {
+        self.members
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&peer_id);
+    }
+
+    /// Members other than the given one
+    fn others(&self, peer_id: PeerId) -> Vec<PeerId> {
+        self.members
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .filter(|id| **id != peer_id)
+            .copied()
+            .collect()
+    }
+}
+
+/// Transport endpoint attached to a [`SharedBroadcastBus`]
+#[derive(Debug)]
+pub struct BusTransport {
+    /// Bus this transport is attached to
+    bus: SharedBroadcastBus,
+
+    /// Local peer ID on the bus
+    peer_id: PeerId,
+
+    /// Packets delivered to this member
+    inbox: mpsc::UnboundedReceiver<(PeerId, GhostPacket)>,
+
+    /// Statistics
+    stats: TransportStats,
+}
+
+impl BusTransport {
+    /// Get statistics
+    pub fn stats(&self) -> TransportStats {
+        self.stats.clone()
+    }
+}
+
+#[async_trait]
+impl Transport for BusTransport {
+    async fn listen(&mut self, _addr: String) -> Result<()> {
+        // Attached members are always reachable
+        Ok(())
+    }
+
+    async fn dial(&mut self, _addr: String) -> Result<PeerId> {
+        Err(anyhow!("Bus transport does not support dialing"))
+    }
+
+    async fn send(&mut self, peer: PeerId, packet: GhostPacket) -> Result<()> {
+        let size = packet.size();
+        self.bus.deliver(self.peer_id, peer, packet)?;
+        self.stats.record_sent(size);
+        Ok(())
+    }
+
+    async fn broadcast(&mut self, packet: GhostPacket) -> Result<()> {
+        let size = packet.size();
+        let delivered = self.bus.deliver_all(self.peer_id, &packet)?;
+        for _ in 0..delivered {
+            self.stats.record_sent(size);
+        }
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> Result<(PeerId, GhostPacket)> {
+        let (from, packet) = self
+            .inbox
+            .recv()
+            .await
+            .ok_or_else(|| anyhow!("Bus inbox closed"))?;
+        self.stats.record_received(packet.size());
+        Ok((from, packet))
+    }
+
+    fn peers(&self) -> Vec<PeerId> {
+        self.bus.others(self.peer_id)
+    }
+
+    fn local_peer_id(&self) -> PeerId {
+        self.peer_id
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        self.bus.detach(self.peer_id);
+        Ok(())
+    }
+}
+
+/// Bus transport that scores delivering peers like `Libp2pTransport` (test double)
+#[cfg(test)]
+pub(crate) struct ScoredBus {
+    pub(crate) inner: BusTransport,
+    pub(crate) peers: Arc<RwLock<super::PeerManager>>,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl Transport for ScoredBus {
+    async fn listen(&mut self, addr: String) -> Result<()> {
+        self.inner.listen(addr).await
+    }
+
+    async fn dial(&mut self, addr: String) -> Result<PeerId> {
+        self.inner.dial(addr).await
+    }
+
+    async fn send(&mut self, peer: PeerId, packet: GhostPacket) -> Result<()> {
+        self.inner.send(peer, packet).await
+    }
+
+    async fn broadcast(&mut self, packet: GhostPacket) -> Result<()> {
+        self.inner.broadcast(packet).await
+    }
+
+    async fn receive(&mut self) -> Result<(PeerId, GhostPacket)> {
+        self.inner.receive().await
+    }
+
+    fn peers(&self) -> Vec<PeerId> {
+        self.inner.peers()
+    }
+
+    fn local_peer_id(&self) -> PeerId {
+        self.inner.local_peer_id()
+    }
+
+    fn report_violation(&self, peer: PeerId, violation: super::PeerViolation) -> bool {
+        self.peers.read().unwrap().record_violation(peer, violation)
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        self.inner.shutdown().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet::{CarrierType, ResonanceState};
+
+    fn test_packet() -> GhostPacket {
+        GhostPacket::new(
+            ResonanceState::new(1.0, 1.0, 1.0),
+            ResonanceState::new(0.0, 0.0, 0.0),
+            b"bus".to_vec(),
+            vec![],
+            CarrierType::Raw,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_reaches_other_members_only() {
+        let bus = SharedBroadcastBus::new();
+        let mut a = bus.attach();
+        let mut b = bus.attach();
+        let mut c = bus.attach();
+        assert_eq!(bus.member_count(), 3);
+        assert_eq!(a.peer_count(), 2);
+
+        let packet = test_packet();
+        a.broadcast(packet.clone()).await.unwrap();
+
+        let (from_b, got_b) = b.receive().await.unwrap();
+        let (from_c, got_c) = c.receive().await.unwrap();
+        assert_eq!(from_b, a.local_peer_id());
+        assert_eq!(from_c, a.local_peer_id());
+        assert_eq!(got_b.id, packet.id);
+        assert_eq!(got_c.id, packet.id);
+
+        // Sender does not receive its own broadcast
+        assert!(a.inbox.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_detaches_member() {
+        let bus = SharedBroadcastBus::new();
+        let mut a = bus.attach();
+        let mut b = bus.attach();
+
+        b.shutdown().await.unwrap();
+        assert_eq!(bus.member_count(), 1);
+        assert!(a.send(b.local_peer_id(), test_packet()).await.is_err());
+    }
+}
@@ -5,37 +5,53 @@
  * Supports multiple wire formats:
  * - JSON (human-readable, debugging)
  * - Bincode (compact, production)
+ *
+ * Every encoded packet is framed with a 4-byte big-endian length prefix.
+ * Frames announcing more than `max_frame_bytes` are rejected from the
+ * header alone, before the payload is deserialized.
  */
 
 use crate::packet::GhostPacket;
 use anyhow::{Context, Result};
+use bincode::Options;
 use serde::{Deserialize, Serialize};
 
+/// Size of the frame length prefix in bytes
+pub const FRAME_HEADER_LEN: usize = 4;
+
+/// Default maximum frame payload size (2 MB)
+pub const DEFAULT_MAX_FRAME_BYTES: usize = 2 * 1024 * 1024;
+
 /// Wire format for packet serialization
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WireFormat {
     /// JSON format (human-readable, larger size)
     Json,
-    /// Bincode format (binary, compact)
+    /// Bincode format (binary, compact; the default)
+    #[default]
     Bincode,
 }
 
-impl Default for WireFormat {
-    fn default() -> Self {
-        Self::Bincode // Use compact format by default
-    }
-}
-
 /// Packet codec for serialization/deserialization
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct PacketCodec {
     format: WireFormat,
+    max_frame_bytes: usize,
 }
 
 impl PacketCodec {
     /// Create new codec with specified format
     pub fn new(format: WireFormat) -> Self {
-        Self { format }
+        Self {
+            format,
+            max_frame_bytes: DEFAULT_MAX_FRAME_BYTES,
+        }
+    }
+
+    /// Set maximum frame payload size
+    pub fn with_max_frame_bytes(mut self, max_frame_bytes: usize) -> Self {
+        self.max_frame_bytes = max_frame_bytes;
+        self
     }
 
     /// Create codec with JSON format
@@ -48,42 +64,96 @@ impl PacketCodec {
         Self::new(WireFormat::Bincode)
     }
 
-    /// Encode packet to bytes
+    /// Encode packet to a length-prefixed frame
     ///
     /// # Arguments
     /// * `packet` - Ghost packet to encode
     ///
     /// # Returns
-    /// * `Ok(Vec<u8>)` with serialized bytes
-    /// * `Err` if serialization failed
+    /// * `Ok(Vec<u8>)` with framed bytes
+    /// * `Err` if serialization failed or the payload exceeds `max_frame_bytes`
     pub fn encode(&self, packet: &GhostPacket) -> Result<Vec<u8>> {
-        match self.format {
+        let payload = match self.format {
             WireFormat::Json => {
-                serde_json::to_vec(packet).context("Failed to serialize packet as JSON")
+                serde_json::to_vec(packet).context("Failed to serialize packet as JSON")?
             }
             WireFormat::Bincode => {
-                bincode::serialize(packet).context("Failed to serialize packet as Bincode")
+                bincode::serialize(packet).context("Failed to serialize packet as Bincode")?
             }
+        };
+
+        if payload.len() > self.max_frame_bytes {
+            anyhow::bail!(
+                "Frame of {} bytes exceeds maximum of {} bytes",
+                payload.len(),
+                self.max_frame_bytes
+            );
         }
+
+        let len = u32::try_from(payload.len()).context("Frame length does not fit in header")?;
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        frame.extend_from_slice(&len.to_be_bytes());
+        frame.extend_from_slice(&payload);
+        Ok(frame)
     }
 
-    /// Decode packet from bytes
+    /// Decode packet from a length-prefixed frame
     ///
     /// # Arguments
-    /// * `bytes` - Serialized packet bytes
+    /// * `bytes` - Framed packet bytes
     ///
     /// # Returns
     /// * `Ok(GhostPacket)` with deserialized packet
-    /// * `Err` if deserialization failed
+    /// * `Err` if the frame is malformed, oversized or deserialization failed
     pub fn decode(&self, bytes: &[u8]) -> Result<GhostPacket> {
+        let len = self.frame_length(bytes)?;
+        let payload = &bytes[FRAME_HEADER_LEN..];
+
+        if payload.len() != len {
+            anyhow::bail!(
+                "Frame length mismatch: header announces {} bytes, got {}",
+                len,
+                payload.len()
+            );
+        }
+
         match self.format {
             WireFormat::Json => {
-                serde_json::from_slice(bytes).context("Failed to deserialize packet from JSON")
-            }
-            WireFormat::Bincode => {
-                bincode::deserialize(bytes).context("Failed to deserialize packet from Bincode")
+                serde_json::from_slice(payload).context("Failed to deserialize packet from JSON")
             }
+            WireFormat::Bincode => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .with_limit(self.max_frame_bytes as u64)
+                .deserialize(payload)
+                .context("Failed to deserialize packet from Bincode"),
+        }
+    }
+
+    /// Read and validate the payload length announced by a frame header
+    ///
+    /// # Arguments
+    /// * `bytes` - Frame bytes (at least the header)
+    ///
+    /// # Returns
+    /// * `Ok(usize)` with the announced payload length
+    /// * `Err` if the header is truncated or announces more than `max_frame_bytes`
+    pub fn frame_length(&self, bytes: &[u8]) -> Result<usize> {
+        let header: [u8; FRAME_HEADER_LEN] = bytes
+            .get(..FRAME_HEADER_LEN)
+            .and_then(|h| h.try_into().ok())
+            .ok_or_else(|| anyhow::anyhow!("Frame too short: {} bytes", bytes.len()))?;
+
+        let len = u32::from_be_bytes(header) as usize;
+        if len > self.max_frame_bytes {
+            anyhow::bail!(
+                "Frame of {} bytes exceeds maximum of {} bytes",
+                len,
+                self.max_frame_bytes
+            );
         }
+
+        Ok(len)
     }
 
     /// Get wire format
@@ -91,6 +161,11 @@ impl PacketCodec {
         self.format
     }
 
+    /// Get maximum frame payload size
+    pub fn max_frame_bytes(&self) -> usize {
+        self.max_frame_bytes
+    }
+
     /// Estimate encoded size (approximate)
     ///
     /// # Arguments
@@ -103,11 +178,11 @@ impl PacketCodec {
         match self.format {
             WireFormat::Json => {
                 // JSON is larger due to field names and formatting
-                std::mem::size_of::<GhostPacket>() * 3
+                FRAME_HEADER_LEN + std::mem::size_of::<GhostPacket>() * 3
             }
             WireFormat::Bincode => {
                 // Bincode is more compact
-                std::mem::size_of::<GhostPacket>()
+                FRAME_HEADER_LEN + std::mem::size_of::<GhostPacket>()
             }
         }
     }
@@ -132,28 +207,17 @@ pub fn decode(bytes: &[u8]) -> Result<GhostPacket> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::packet::{GhostTransaction, NodeIdentity, ResonanceState};
-    use uuid::Uuid;
+    use crate::packet::{CarrierType, ResonanceState};
 
     fn create_test_packet() -> GhostPacket {
-        GhostPacket {
-            id: Uuid::new_v4(),
-            sender: NodeIdentity {
-                id: Uuid::new_v4(),
-                resonance: ResonanceState::new(1.0, 1.0, 1.0),
-            },
-            target: None,
-            resonance: ResonanceState::new(2.0, 2.0, 2.0),
-            transaction: GhostTransaction {
-                action: b"test action".to_vec(),
-                zk_proof: Some(b"test proof".to_vec()),
-                timestamp: 1234567890,
-            },
-            stego_carrier: vec![1, 2, 3, 4, 5],
-            timestamp: 1234567890,
-            key_epoch: 42,
-            ephemeral_key: Some(vec![6, 7, 8, 9]),
-        }
+        GhostPacket::new(
+            ResonanceState::new(2.0, 2.0, 2.0),
+            ResonanceState::new(1.0, 1.0, 1.0),
+            b"test action".to_vec(),
+            vec![1, 2, 3, 4, 5],
+            CarrierType::Raw,
+            Some(b"test proof".to_vec()),
+        )
     }
 
     #[test]
@@ -166,7 +230,7 @@ mod tests {
 
         assert_eq!(packet.id, decoded.id);
         assert_eq!(packet.timestamp, decoded.timestamp);
-        assert_eq!(packet.transaction.action, decoded.transaction.action);
+        assert_eq!(packet.masked_payload, decoded.masked_payload);
     }
 
     #[test]
@@ -179,7 +243,7 @@ mod tests {
 
         assert_eq!(packet.id, decoded.id);
         assert_eq!(packet.timestamp, decoded.timestamp);
-        assert_eq!(packet.transaction.action, decoded.transaction.action);
+        assert_eq!(packet.masked_payload, decoded.masked_payload);
     }
 
     #[test]
@@ -211,6 +275,48 @@ mod tests {
         assert_eq!(packet.id, decoded.id);
     }
 
+    #[test]
+    fn test_frame_at_limit_roundtrips() {
+        let packet = create_test_packet();
+        let payload_len = PacketCodec::bincode().encode(&packet).unwrap().len() - FRAME_HEADER_LEN;
+
+        let codec = PacketCodec::bincode().with_max_frame_bytes(payload_len);
+        let encoded = codec.encode(&packet).unwrap();
+        assert_eq!(codec.frame_length(&encoded).unwrap(), payload_len);
+
+        let decoded = codec.decode(&encoded).unwrap();
+        assert_eq!(packet.id, decoded.id);
+
+        // One byte less and the same packet no longer fits
+        let tight = PacketCodec::bincode().with_max_frame_bytes(payload_len - 1);
+        assert!(tight.encode(&packet).is_err());
+        assert!(tight.decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_oversized_frame_rejected_from_header() {
+        let codec = PacketCodec::bincode().with_max_frame_bytes(1024);
+
+        // Header announces ~4 GB but carries only a few bytes
+        let mut frame = u32::MAX.to_be_bytes().to_vec();
+        frame.extend_from_slice(&[0u8; 8]);
+
+        let err = codec.decode(&frame).unwrap_err();
+        assert!(err.to_string().contains("exceeds maximum"));
+    }
+
+    #[test]
+    fn test_malformed_frames_rejected() {
+        let codec = PacketCodec::bincode();
+        let encoded = codec.encode(&create_test_packet()).unwrap();
+
+        // Truncated header
+        assert!(codec.decode(&encoded[..2]).is_err());
+
+        // Truncated payload
+        assert!(codec.decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+
     #[test]
     fn test_estimate_size() {
         let packet = create_test_packet();
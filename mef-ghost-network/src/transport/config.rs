@@ -16,6 +16,9 @@ pub struct TransportConfig {
     /// Maximum packet size in bytes
     pub max_packet_size: usize,
 
+    /// Maximum wire frame payload in bytes (larger frames are rejected)
+    pub max_frame_bytes: usize,
+
     /// Connection timeout in seconds
     pub connection_timeout_secs: u64,
 
@@ -25,6 +28,12 @@ pub struct TransportConfig {
     /// Maximum number of concurrent connections
     pub max_connections: usize,
 
+    /// Dial timeout in seconds (dials not established in time fail)
+    pub dial_timeout_secs: u64,
+
+    /// Idle timeout in seconds after which quiet connections are closed
+    pub idle_connection_timeout_secs: u64,
+
     /// Enable gossipsub protocol (for broadcasting)
     pub enable_gossipsub: bool,
 
@@ -61,9 +70,12 @@ impl TransportConfig {
         Self {
             wire_format: WireFormat::Json,     // Use JSON for debugging
             max_packet_size: 10 * 1024 * 1024, // 10 MB (generous for testing)
+            max_frame_bytes: 10 * 1024 * 1024,
             connection_timeout_secs: 10,
             keepalive_interval_secs: 30,
             max_connections: 10, // Small network
+            dial_timeout_secs: 5,
+            idle_connection_timeout_secs: 60,
             enable_gossipsub: true,
             gossipsub_topic: "ghost-protocol-local".to_string(),
             enable_identify: true,
@@ -79,10 +91,13 @@ impl TransportConfig {
     pub fn production() -> Self {
         Self {
             wire_format: WireFormat::Bincode, // Compact
-            max_packet_size: 1 * 1024 * 1024, // 1 MB
+            max_packet_size: 1024 * 1024,     // 1 MB
+            max_frame_bytes: 1024 * 1024,
             connection_timeout_secs: 30,
             keepalive_interval_secs: 60,
             max_connections: 1000, // Large network
+            dial_timeout_secs: 20,
+            idle_connection_timeout_secs: 300,
             enable_gossipsub: true,
             gossipsub_topic: "ghost-protocol".to_string(),
             enable_identify: true,
@@ -100,10 +115,22 @@ impl TransportConfig {
             anyhow::bail!("max_packet_size must be greater than 0");
         }
 
+        if self.max_frame_bytes == 0 || self.max_frame_bytes > u32::MAX as usize {
+            anyhow::bail!("max_frame_bytes must be between 1 and {}", u32::MAX);
+        }
+
         if self.max_connections == 0 {
             anyhow::bail!("max_connections must be greater than 0");
         }
 
+        if self.dial_timeout_secs == 0 {
+            anyhow::bail!("dial_timeout_secs must be greater than 0");
+        }
+
+        if self.idle_connection_timeout_secs == 0 {
+            anyhow::bail!("idle_connection_timeout_secs must be greater than 0");
+        }
+
         if !self.enable_tcp && !self.enable_quic {
             anyhow::bail!("At least one transport (TCP or QUIC) must be enabled");
         }
@@ -117,9 +144,12 @@ impl Default for TransportConfig {
         Self {
             wire_format: WireFormat::Bincode,
             max_packet_size: 2 * 1024 * 1024, // 2 MB default
+            max_frame_bytes: 2 * 1024 * 1024,
             connection_timeout_secs: 30,
             keepalive_interval_secs: 60,
             max_connections: 100,
+            dial_timeout_secs: 10,
+            idle_connection_timeout_secs: 300,
             enable_gossipsub: true,
             gossipsub_topic: "ghost-protocol".to_string(),
             enable_identify: true,
@@ -166,6 +196,21 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_invalid_config_zero_timeouts() {
+        let config = TransportConfig {
+            dial_timeout_secs: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = TransportConfig {
+            idle_connection_timeout_secs: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_invalid_config_no_transport() {
         let config = TransportConfig {
@@ -11,9 +11,16 @@
  * - Gossipsub for broadcasting
  * - Identify for peer info exchange
  * - Ping for connection health
+ *
+ * Connection count, dial timeout and idle timeout are bounded by
+ * `TransportConfig` (`max_connections`, `dial_timeout_secs`,
+ * `idle_connection_timeout_secs`).
  */
 
-use super::{PacketCodec, PeerManager, Transport, TransportConfig, TransportStats};
+use super::{
+    ConnectionLimiter, PacketCodec, PeerManager, PeerViolation, Transport, TransportConfig,
+    TransportStats,
+};
 use crate::packet::GhostPacket;
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
@@ -21,16 +28,17 @@ use libp2p::core::{transport::Transport as Libp2pCoreTrait, upgrade};
 use libp2p::futures::StreamExt;
 use libp2p::{
     gossipsub, identify, noise, ping,
-    swarm::{Config, NetworkBehaviour, SwarmEvent},
+    swarm::{dial_opts::DialOpts, Config, ConnectionId, NetworkBehaviour, SwarmEvent},
     tcp, yamux, Multiaddr, PeerId as Libp2pPeerId, Swarm,
 };
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use tokio::sync::{mpsc, Mutex};
 use tracing::{debug, error, info, warn};
 
-/// Maximum size for receive channel
-const RX_CHANNEL_SIZE: usize = 1000;
+/// How often the event loop checks pending dials for expiry
+const DIAL_SWEEP_INTERVAL: Duration = Duration::from_millis(250);
 
 /// libp2p Network Behaviour for Ghost Protocol
 #[derive(NetworkBehaviour)]
@@ -71,6 +79,7 @@ impl From<ping::Event> for GhostBehaviourEvent {
 }
 
 /// libp2p-based transport implementation
+#[derive(Debug)]
 pub struct Libp2pTransport {
     /// Transport configuration
     config: TransportConfig,
@@ -84,15 +93,12 @@ pub struct Libp2pTransport {
     /// Statistics
     stats: Arc<RwLock<TransportStats>>,
 
+    /// Connection limiter (enforces `max_connections`)
+    limiter: Arc<ConnectionLimiter>,
+
     /// Local peer ID
     local_peer_id: super::PeerId,
 
-    /// libp2p peer ID
-    libp2p_peer_id: Libp2pPeerId,
-
-    /// Gossipsub topic
-    topic: gossipsub::IdentTopic,
-
     /// Packet receive channel (using tokio::Mutex for Send-safe async)
     rx_channel: Arc<Mutex<mpsc::UnboundedReceiver<(super::PeerId, GhostPacket)>>>,
 
@@ -106,6 +112,7 @@ pub struct Libp2pTransport {
 /// Commands to send to Swarm event loop
 #[derive(Debug)]
 enum SwarmCommand {
+    Listen(Multiaddr, tokio::sync::oneshot::Sender<Result<()>>),
     Dial(
         Multiaddr,
         tokio::sync::oneshot::Sender<Result<super::PeerId>>,
@@ -114,6 +121,26 @@ enum SwarmCommand {
     Shutdown,
 }
 
+/// State shared between the transport handle and the swarm event loop
+struct SwarmContext {
+    rx_commands: mpsc::UnboundedReceiver<SwarmCommand>,
+    tx_packets: mpsc::UnboundedSender<(super::PeerId, GhostPacket)>,
+    peer_manager: Arc<RwLock<PeerManager>>,
+    stats: Arc<RwLock<TransportStats>>,
+    running: Arc<RwLock<bool>>,
+    topic: gossipsub::IdentTopic,
+    codec: PacketCodec,
+    limiter: Arc<ConnectionLimiter>,
+    dial_timeout: Duration,
+}
+
+/// Dial awaiting connection establishment
+struct PendingDial {
+    addr: Multiaddr,
+    response_tx: tokio::sync::oneshot::Sender<Result<super::PeerId>>,
+    deadline: tokio::time::Instant,
+}
+
 impl Libp2pTransport {
     /// Create new libp2p transport
     pub async fn new(config: TransportConfig) -> Result<Self> {
@@ -148,7 +175,10 @@ impl Libp2pTransport {
         // Create Gossipsub
         let gossipsub_config = gossipsub::ConfigBuilder::default()
             .heartbeat_interval(Duration::from_secs(10))
-            .validation_mode(gossipsub::ValidationMode::Permissive)
+            // Strict: every message carries its signed author in `source`
+            .validation_mode(gossipsub::ValidationMode::Strict)
+            // Forward only messages the event loop has accepted
+            .validate_messages()
             .max_transmit_size(config.max_packet_size)
             .build()
             .context("Failed to create Gossipsub config")?;
@@ -182,38 +212,39 @@ impl Libp2pTransport {
         };
 
         // Build swarm using new libp2p 0.53 API
-        let swarm = Swarm::new(transport, behaviour, libp2p_peer_id, Config::with_tokio_executor());
+        let swarm_config = Config::with_tokio_executor()
+            .with_idle_connection_timeout(Duration::from_secs(config.idle_connection_timeout_secs));
+        let swarm = Swarm::new(transport, behaviour, libp2p_peer_id, swarm_config);
 
-        let codec = PacketCodec::new(config.wire_format);
+        let codec =
+            PacketCodec::new(config.wire_format).with_max_frame_bytes(config.max_frame_bytes);
         let peer_manager = Arc::new(RwLock::new(PeerManager::default()));
         let stats = Arc::new(RwLock::new(TransportStats::default()));
         let running = Arc::new(RwLock::new(false));
+        let limiter = Arc::new(ConnectionLimiter::new(config.max_connections));
 
         // Spawn swarm event loop
-        let peer_manager_clone = peer_manager.clone();
-        let stats_clone = stats.clone();
-        let running_clone = running.clone();
-        let topic_clone = topic.clone();
-
-        tokio::spawn(Self::swarm_event_loop(
-            swarm,
-            rx_from_transport,
+        let context = SwarmContext {
+            rx_commands: rx_from_transport,
             tx_packets,
-            peer_manager_clone,
-            stats_clone,
-            running_clone,
-            topic_clone,
-            codec.clone(),
-        ));
+            peer_manager: peer_manager.clone(),
+            stats: stats.clone(),
+            running: running.clone(),
+            topic,
+            codec: codec.clone(),
+            limiter: limiter.clone(),
+            dial_timeout: Duration::from_secs(config.dial_timeout_secs),
+        };
+
+        tokio::spawn(Self::swarm_event_loop(swarm, context));
 
         Ok(Self {
             config,
             codec,
             peer_manager,
             stats,
+            limiter,
             local_peer_id,
-            libp2p_peer_id,
-            topic,
             rx_channel: Arc::new(Mutex::new(rx_packets)),
             tx_to_swarm,
             running,
@@ -233,32 +264,57 @@ impl Libp2pTransport {
     }
 
     /// Swarm event loop (runs in background task)
-    async fn swarm_event_loop(
-        mut swarm: Swarm<GhostBehaviour>,
-        mut rx_commands: mpsc::UnboundedReceiver<SwarmCommand>,
-        tx_packets: mpsc::UnboundedSender<(super::PeerId, GhostPacket)>,
-        peer_manager: Arc<RwLock<PeerManager>>,
-        stats: Arc<RwLock<TransportStats>>,
-        running: Arc<RwLock<bool>>,
-        topic: gossipsub::IdentTopic,
-        codec: PacketCodec,
-    ) {
+    async fn swarm_event_loop(mut swarm: Swarm<GhostBehaviour>, context: SwarmContext) {
+        let SwarmContext {
+            mut rx_commands,
+            tx_packets,
+            peer_manager,
+            stats,
+            running,
+            topic,
+            codec,
+            limiter,
+            dial_timeout,
+        } = context;
+
         info!("Swarm event loop started");
 
+        // Dials awaiting connection establishment
+        let mut pending_dials: HashMap<ConnectionId, PendingDial> = HashMap::new();
+
+        // Dials that timed out before their connection was established
+        let mut timed_out: HashSet<ConnectionId> = HashSet::new();
+
+        // Connections closed without holding a limiter slot
+        let mut rejected: HashSet<ConnectionId> = HashSet::new();
+
+        let mut dial_sweep = tokio::time::interval(DIAL_SWEEP_INTERVAL);
+
         loop {
             tokio::select! {
                 // Handle commands from transport
                 Some(cmd) = rx_commands.recv() => {
                     match cmd {
+                        SwarmCommand::Listen(addr, response_tx) => {
+                            debug!("Swarm: Listening on {}", addr);
+                            let result = swarm
+                                .listen_on(addr.clone())
+                                .map(|_| ())
+                                .map_err(|e| anyhow!("Failed to listen on {}: {}", addr, e));
+                            let _ = response_tx.send(result);
+                        }
                         SwarmCommand::Dial(addr, response_tx) => {
                             debug!("Swarm: Dialing {}", addr);
-                            match swarm.dial(addr.clone()) {
+                            let opts = DialOpts::unknown_peer_id().address(addr.clone()).build();
+                            let connection_id = opts.connection_id();
+                            match swarm.dial(opts) {
                                 Ok(_) => {
-                                    // We'll send the peer ID once connection is established
-                                    // For now, extract from multiaddr or generate
-                                    let peer_id = super::PeerId::random();
-                                    let _ = response_tx.send(Ok(peer_id));
-                                    stats.write().unwrap().record_dial_success();
+                                    // Resolved once the connection is established, fails or times out
+                                    pending_dials.insert(connection_id, PendingDial {
+                                        addr,
+                                        response_tx,
+                                        deadline: tokio::time::Instant::now() + dial_timeout,
+                                    });
                                 }
                                 Err(e) => {
                                     error!("Failed to dial: {}", e);
@@ -283,31 +339,89 @@ impl Libp2pTransport {
                     }
                 }
 
+                // Expire dials that outlived the dial timeout
+                _ = dial_sweep.tick() => {
+                    let now = tokio::time::Instant::now();
+                    let expired: Vec<ConnectionId> = pending_dials
+                        .iter()
+                        .filter(|(_, dial)| dial.deadline <= now)
+                        .map(|(connection_id, _)| *connection_id)
+                        .collect();
+
+                    for connection_id in expired {
+                        let Some(dial) = pending_dials.remove(&connection_id) else {
+                            continue;
+                        };
+
+                        warn!("Dial to {} timed out after {:?}", dial.addr, dial_timeout);
+
+                        // Still pending in the swarm: close it once it is established
+                        timed_out.insert(connection_id);
+                        swarm.close_connection(connection_id);
+
+                        let _ = dial.response_tx.send(Err(anyhow!(
+                            "Dial to {} timed out after {:?}",
+                            dial.addr,
+                            dial_timeout
+                        )));
+                        stats.write().unwrap().record_dial_failure();
+                    }
+                }
+
                 // Handle swarm events
                 event = swarm.select_next_some() => {
                     match event {
                         SwarmEvent::Behaviour(GhostBehaviourEvent::Gossipsub(gossipsub::Event::Message {
                             propagation_source,
+                            message_id,
                             message,
-                            ..
                         })) => {
                             debug!("Received gossipsub message from {}", propagation_source);
 
-                            // Decode packet using configured codec (respects wire format from config)
-                            match codec.decode(&message.data) {
-                                Ok(packet) => {
-                                    let peer_id = Self::libp2p_to_peer_id(&propagation_source);
-                                    peer_manager.read().unwrap().record_received(peer_id);
-                                    stats.write().unwrap().record_received(message.data.len());
-
-                                    // Send to receive channel
-                                    if let Err(e) = tx_packets.send((peer_id, packet)) {
-                                        error!("Failed to send packet to receive channel: {}", e);
+                            // Violations are charged to the signed author, not to
+                            // the relay that happened to forward the message
+                            let author = message.source.unwrap_or(propagation_source);
+                            let peer_id = Self::libp2p_to_peer_id(&author);
+                            let relay_id = Self::libp2p_to_peer_id(&propagation_source);
+
+                            let acceptance = {
+                                let manager = peer_manager.read().unwrap();
+                                if !manager.should_accept(peer_id) || !manager.should_accept(relay_id) {
+                                    debug!("Dropping message from banned peer {}", peer_id);
+                                    gossipsub::MessageAcceptance::Ignore
+                                } else {
+                                    // Decode packet using configured codec (respects wire format from config)
+                                    match codec.decode(&message.data) {
+                                        Ok(packet) if !packet.verify_integrity() => {
+                                            warn!("Packet from {} failed integrity check", peer_id);
+                                            manager.record_violation(peer_id, PeerViolation::IntegrityFailure);
+                                            gossipsub::MessageAcceptance::Reject
+                                        }
+                                        Ok(packet) => {
+                                            manager.record_received(peer_id);
+                                            stats.write().unwrap().record_received(message.data.len());
+
+                                            // Send to receive channel
+                                            if let Err(e) = tx_packets.send((peer_id, packet)) {
+                                                error!("Failed to send packet to receive channel: {}", e);
+                                            }
+                                            gossipsub::MessageAcceptance::Accept
+                                        }
+                                        Err(e) => {
+                                            warn!("Failed to decode packet: {}", e);
+                                            manager.record_violation(peer_id, PeerViolation::InvalidPacket);
+                                            gossipsub::MessageAcceptance::Reject
+                                        }
                                     }
                                 }
-                                Err(e) => {
-                                    warn!("Failed to decode packet: {}", e);
-                                }
+                            };
+
+                            if let Err(e) = swarm.behaviour_mut().gossipsub.report_message_validation_result(
+                                &message_id,
+                                &propagation_source,
+                                acceptance,
+                            ) {
+                                debug!("Failed to report validation result for {}: {}", message_id, e);
                             }
                         }
 
@@ -344,18 +458,70 @@ impl Libp2pTransport {
 
                         SwarmEvent::ConnectionEstablished {
                             peer_id,
+                            connection_id,
                             endpoint,
                             ..
                         } => {
+                            if timed_out.remove(&connection_id) {
+                                debug!("Closing connection with {} established after its dial timed out", peer_id);
+                                rejected.insert(connection_id);
+                                swarm.close_connection(connection_id);
+                                continue;
+                            }
+
+                            let pending = pending_dials.remove(&connection_id);
+
+                            if let Err(e) = limiter.try_acquire() {
+                                warn!("Rejecting connection with {}: {}", peer_id, e);
+                                rejected.insert(connection_id);
+                                swarm.close_connection(connection_id);
+
+                                if let Some(dial) = pending {
+                                    if dial.response_tx.send(Err(anyhow!("Dial failed: {}", e))).is_ok() {
+                                        stats.write().unwrap().record_dial_failure();
+                                    }
+                                }
+                                continue;
+                            }
+
                             info!("Connection established with {}: {}", peer_id, endpoint.get_remote_address());
                             stats.write().unwrap().record_connection_accepted();
+
+                            if let Some(dial) = pending {
+                                let our_peer_id = Self::libp2p_to_peer_id(&peer_id);
+                                if dial.response_tx.send(Ok(our_peer_id)).is_ok() {
+                                    stats.write().unwrap().record_dial_success();
+                                }
+                            }
+                        }
+
+                        SwarmEvent::OutgoingConnectionError {
+                            connection_id,
+                            error,
+                            ..
+                        } => {
+                            warn!("Outgoing connection failed: {}", error);
+                            timed_out.remove(&connection_id);
+                            if let Some(dial) = pending_dials.remove(&connection_id) {
+                                // A dropped receiver means the caller went away
+                                if dial.response_tx.send(Err(anyhow!("Dial failed: {}", error))).is_ok() {
+                                    stats.write().unwrap().record_dial_failure();
+                                }
+                            }
                         }
 
                         SwarmEvent::ConnectionClosed {
                             peer_id,
+                            connection_id,
                             cause,
                             ..
                         } => {
+                            if rejected.remove(&connection_id) {
+                                debug!("Rejected connection with {} closed", peer_id);
+                                continue;
+                            }
+
+                            limiter.release();
                             info!("Connection closed with {}: {:?}", peer_id, cause);
                             let our_peer_id = Self::libp2p_to_peer_id(&peer_id);
                             let _ = peer_manager.write().unwrap().remove_peer(our_peer_id);
@@ -385,10 +551,22 @@ impl Libp2pTransport {
         self.stats.read().unwrap().clone()
     }
 
+    /// Number of active connections
+    pub fn connection_count(&self) -> usize {
+        self.limiter.active()
+    }
+
     /// Check if running
     pub fn is_running(&self) -> bool {
         *self.running.read().unwrap()
     }
+
+    /// Shared handle to the peer manager
+    ///
+    /// Lets callers score peers without holding the transport itself.
+    pub fn peer_manager(&self) -> Arc<RwLock<PeerManager>> {
+        self.peer_manager.clone()
+    }
 }
 
 #[async_trait]
@@ -398,9 +576,11 @@ impl Transport for Libp2pTransport {
 
         let multiaddr: Multiaddr = addr.parse().context("Invalid multiaddr")?;
 
-        // Send listen command via swarm (we'd need to extend SwarmCommand for this)
-        // For now, we'll note this is a simplified version
-        // In a full implementation, we'd add a Listen variant to SwarmCommand
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.tx_to_swarm
+            .send(SwarmCommand::Listen(multiaddr.clone(), tx))
+            .context("Failed to send listen command")?;
+        rx.await.context("Failed to receive listen response")??;
 
         *self.running.write().unwrap() = true;
 
@@ -413,11 +593,20 @@ impl Transport for Libp2pTransport {
 
         let multiaddr: Multiaddr = addr.parse().context("Invalid multiaddr")?;
 
+        if self.limiter.is_full() {
+            self.stats.write().unwrap().record_dial_failure();
+            return Err(anyhow!(
+                "Connection limit reached ({} connections)",
+                self.limiter.max_connections()
+            ));
+        }
+
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.tx_to_swarm
             .send(SwarmCommand::Dial(multiaddr.clone(), tx))
             .context("Failed to send dial command")?;
 
+        // The event loop enforces `dial_timeout_secs` and cleans up the dial
         let peer_id = rx.await.context("Failed to receive dial response")??;
 
         info!("Successfully dialed, peer_id: {}", peer_id);
@@ -488,6 +677,13 @@ impl Transport for Libp2pTransport {
         self.local_peer_id
     }
 
+    fn report_violation(&self, peer: super::PeerId, violation: PeerViolation) -> bool {
+        self.peer_manager
+            .read()
+            .unwrap()
+            .record_violation(peer, violation)
+    }
+
     async fn shutdown(&mut self) -> Result<()> {
         info!("Shutting down transport");
 
@@ -515,6 +711,24 @@ mod tests {
         assert_eq!(transport.peers().len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_dial_unreachable_fails_within_timeout() {
+        let config = TransportConfig {
+            dial_timeout_secs: 1,
+            ..TransportConfig::local()
+        };
+        let mut transport = Libp2pTransport::new(config).await.unwrap();
+
+        // Non-routable address: either refused quickly or hits the dial timeout
+        let started = std::time::Instant::now();
+        let result = transport.dial("/ip4/10.255.255.1/tcp/9".to_string()).await;
+
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(transport.stats().dials_failed, 1);
+        assert_eq!(transport.connection_count(), 0);
+    }
+
     #[tokio::test]
     async fn test_peer_id_conversion() {
         let libp2p_id = Libp2pPeerId::random();
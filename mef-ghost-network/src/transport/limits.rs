@@ -0,0 +1,104 @@
+/*!
+ * Connection Limits
+ *
+ * Tracks active connections against the configured maximum so that a
+ * flood of dials or inbound connections cannot exhaust resources.
+ */
+
+use anyhow::Result;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counting limiter for concurrent connections
+#[derive(Debug)]
+pub struct ConnectionLimiter {
+    /// Maximum concurrent connections
+    max_connections: usize,
+
+    /// Currently active connections
+    active: AtomicUsize,
+}
+
+impl ConnectionLimiter {
+    /// Create new limiter
+    pub fn new(max_connections: usize) -> Self {
+        Self {
+            max_connections,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reserve a connection slot
+    ///
+    /// # Returns
+    /// * `Ok(())` if a slot was reserved
+    /// * `Err` if the connection cap is reached
+    pub fn try_acquire(&self) -> Result<()> {
+        self.active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |active| {
+                (active < self.max_connections).then_some(active + 1)
+            })
+            .map(|_| ())
+            .map_err(|active| {
+                anyhow::anyhow!(
+                    "Connection limit reached ({}/{})",
+                    active,
+                    self.max_connections
+                )
+            })
+    }
+
+    /// Release a previously reserved slot
+    pub fn release(&self) {
+        let _ = self
+            .active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |active| {
+                active.checked_sub(1)
+            });
+    }
+
+    /// Check if no slot is available
+    pub fn is_full(&self) -> bool {
+        self.active() >= self.max_connections
+    }
+
+    /// Number of active connections
+    pub fn active(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    /// Configured maximum
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_cap_refused() {
+        let limiter = ConnectionLimiter::new(2);
+
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.is_full());
+
+        // Third connection exceeds the cap
+        assert!(limiter.try_acquire().is_err());
+        assert_eq!(limiter.active(), 2);
+
+        // Freeing a slot allows a new connection
+        limiter.release();
+        assert!(limiter.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn test_release_never_underflows() {
+        let limiter = ConnectionLimiter::new(1);
+
+        limiter.release();
+        assert_eq!(limiter.active(), 0);
+        assert!(limiter.try_acquire().is_ok());
+    }
+}
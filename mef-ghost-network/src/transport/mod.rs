@@ -6,7 +6,7 @@
  *
  * # Architecture
  *
- * ```
+ * ```text
  * Ghost Protocol
  *       ↓
  * Broadcasting/Discovery
@@ -20,7 +20,7 @@
  *
  * ```rust,no_run
  * use mef_ghost_network::transport::{Transport, TransportConfig, Libp2pTransport};
- * use mef_ghost_network::packet::GhostPacket;
+ * use mef_ghost_network::packet::{CarrierType, GhostPacket, ResonanceState};
  *
  * # async fn example() -> anyhow::Result<()> {
  * // Create transport
@@ -34,7 +34,8 @@
  * let peer_id = transport.dial("/ip4/127.0.0.1/tcp/9001".to_string()).await?;
  *
  * // Send packet
- * let packet = GhostPacket::default();
+ * let resonance = ResonanceState::new(1.0, 1.0, 1.0);
+ * let packet = GhostPacket::new(resonance, resonance, vec![], vec![], CarrierType::Raw, None);
  * transport.send(peer_id, packet).await?;
  *
  * // Receive packet
@@ -50,18 +51,22 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod bus;
 pub mod codec;
 pub mod config;
 pub mod libp2p_transport;
+pub mod limits;
 pub mod peer;
 
+pub use bus::{BusTransport, SharedBroadcastBus};
 pub use codec::PacketCodec;
 pub use config::TransportConfig;
 pub use libp2p_transport::Libp2pTransport;
-pub use peer::{PeerIdProvider, PeerInfo, PeerManager};
+pub use limits::ConnectionLimiter;
+pub use peer::{PeerIdProvider, PeerInfo, PeerManager, PeerViolation};
 
 /// Peer identifier (abstraction over libp2p PeerId)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PeerId(pub [u8; 32]);
 
 impl PeerId {
@@ -90,12 +95,6 @@ impl fmt::Display for PeerId {
     }
 }
 
-impl Default for PeerId {
-    fn default() -> Self {
-        Self([0u8; 32])
-    }
-}
-
 /// Transport trait for network communication
 ///
 /// Provides abstract interface for sending/receiving Ghost packets
@@ -184,6 +183,21 @@ pub trait Transport: Send + Sync {
         self.peers().len()
     }
 
+    /// Charge a protocol violation detected above the transport to a peer
+    ///
+    /// Used for rejections only the protocol can detect (e.g. replays or
+    /// rate-limit hits). Transports without peer scoring ignore it.
+    ///
+    /// # Arguments
+    /// * `peer` - Peer that delivered the offending packet
+    /// * `violation` - Kind of violation
+    ///
+    /// # Returns
+    /// * `true` if this violation got the peer banned
+    fn report_violation(&self, _peer: PeerId, _violation: PeerViolation) -> bool {
+        false
+    }
+
     /// Shutdown transport
     ///
     /// Closes all connections and stops listening.
@@ -2,6 +2,10 @@
  * Peer Management
  *
  * Tracks connected peers and their metadata.
+ *
+ * Peers also carry a reputation score. Protocol violations lower the
+ * score; once it drops to the ban threshold the peer is banned and its
+ * packets are refused until the cooldown elapses.
  */
 
 use super::PeerId;
@@ -9,7 +13,47 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Score assigned to peers without recorded violations
+pub const INITIAL_PEER_SCORE: f64 = 100.0;
+
+/// Default score at or below which a peer is banned
+pub const DEFAULT_BAN_THRESHOLD: f64 = 0.0;
+
+/// Default ban duration
+pub const DEFAULT_BAN_COOLDOWN: Duration = Duration::from_secs(600);
+
+/// Protocol violations that lower a peer's score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PeerViolation {
+    /// Packet failed integrity verification
+    IntegrityFailure,
+    /// Packet was a replay of an already seen packet
+    Replay,
+    /// Peer exceeded the rate limit
+    RateLimit,
+    /// Packet could not be decoded
+    InvalidPacket,
+    /// Packet timestamp was outside the accepted window
+    ///
+    /// Usually a skewed clock rather than an attack, so it costs less than
+    /// the other violations.
+    ClockSkew,
+}
+
+impl PeerViolation {
+    /// Score penalty for this violation
+    pub fn penalty(&self) -> f64 {
+        match self {
+            PeerViolation::IntegrityFailure => 25.0,
+            PeerViolation::Replay => 20.0,
+            PeerViolation::InvalidPacket => 15.0,
+            PeerViolation::RateLimit => 10.0,
+            PeerViolation::ClockSkew => 5.0,
+        }
+    }
+}
 
 /// Peer connection information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,12 +139,25 @@ impl PeerInfo {
 }
 
 /// Peer manager for tracking connections
+#[derive(Debug)]
 pub struct PeerManager {
     /// Connected peers
     peers: Arc<RwLock<HashMap<PeerId, PeerInfo>>>,
 
     /// Maximum idle time before considering peer stale (seconds)
     max_idle_time: u64,
+
+    /// Peer reputation scores (kept across reconnects)
+    scores: Arc<RwLock<HashMap<PeerId, f64>>>,
+
+    /// Banned peers and when their ban lifts
+    bans: Arc<RwLock<HashMap<PeerId, Instant>>>,
+
+    /// Score at or below which a peer is banned
+    ban_threshold: f64,
+
+    /// How long a ban lasts
+    ban_cooldown: Duration,
 }
 
 impl PeerManager {
@@ -109,11 +166,28 @@ impl PeerManager {
         Self {
             peers: Arc::new(RwLock::new(HashMap::new())),
             max_idle_time,
+            scores: Arc::new(RwLock::new(HashMap::new())),
+            bans: Arc::new(RwLock::new(HashMap::new())),
+            ban_threshold: DEFAULT_BAN_THRESHOLD,
+            ban_cooldown: DEFAULT_BAN_COOLDOWN,
         }
     }
 
+    /// Set ban threshold and cooldown
+    pub fn with_ban_policy(mut self, ban_threshold: f64, ban_cooldown: Duration) -> Self {
+        self.ban_threshold = ban_threshold;
+        self.ban_cooldown = ban_cooldown;
+        self
+    }
+
     /// Add a peer
+    ///
+    /// Fails if the peer is currently banned.
     pub fn add_peer(&self, peer_id: PeerId, address: String) -> Result<()> {
+        if self.is_banned(peer_id) {
+            anyhow::bail!("Peer {} is banned", peer_id);
+        }
+
         let mut peers = self.peers.write().unwrap();
         let info = PeerInfo::new(peer_id, address);
         peers.insert(peer_id, info);
@@ -167,6 +241,75 @@ impl PeerManager {
         }
     }
 
+    /// Get reputation score of a peer
+    pub fn peer_score(&self, peer_id: PeerId) -> f64 {
+        // Lifts an expired ban (and its score penalty) first
+        self.is_banned(peer_id);
+
+        let scores = self.scores.read().unwrap();
+        scores.get(&peer_id).copied().unwrap_or(INITIAL_PEER_SCORE)
+    }
+
+    /// Check if a peer is banned
+    ///
+    /// Expired bans are lifted and the peer's score is reset.
+    pub fn is_banned(&self, peer_id: PeerId) -> bool {
+        let mut bans = self.bans.write().unwrap();
+
+        match bans.get(&peer_id) {
+            Some(until) if Instant::now() < *until => true,
+            Some(_) => {
+                bans.remove(&peer_id);
+                self.scores.write().unwrap().remove(&peer_id);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Check if packets from a peer should be accepted
+    pub fn should_accept(&self, peer_id: PeerId) -> bool {
+        !self.is_banned(peer_id)
+    }
+
+    /// Record a protocol violation by a peer
+    ///
+    /// # Returns
+    /// * `true` if this violation got the peer banned
+    pub fn record_violation(&self, peer_id: PeerId, violation: PeerViolation) -> bool {
+        if self.is_banned(peer_id) {
+            return false;
+        }
+
+        let score = {
+            let mut scores = self.scores.write().unwrap();
+            let score = scores.entry(peer_id).or_insert(INITIAL_PEER_SCORE);
+            *score -= violation.penalty();
+            *score
+        };
+
+        if score > self.ban_threshold {
+            return false;
+        }
+
+        self.bans
+            .write()
+            .unwrap()
+            .insert(peer_id, Instant::now() + self.ban_cooldown);
+        self.peers.write().unwrap().remove(&peer_id);
+        true
+    }
+
+    /// Get currently banned peers
+    pub fn banned_peers(&self) -> Vec<PeerId> {
+        let now = Instant::now();
+        let bans = self.bans.read().unwrap();
+        bans.iter()
+            .filter(|(_, until)| now < **until)
+            .map(|(peer_id, _)| *peer_id)
+            .collect()
+    }
+
     /// Clean up stale peers
     pub fn cleanup_stale_peers(&self) -> Vec<PeerId> {
         let mut peers = self.peers.write().unwrap();
@@ -223,9 +366,13 @@ impl Default for PeerManager {
 /// Peer manager statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PeerManagerStats {
+    /// Number of connected peers
     pub peer_count: usize,
+    /// Packets sent across all peers
     pub total_packets_sent: u64,
+    /// Packets received across all peers
     pub total_packets_received: u64,
+    /// Mean connection quality of connected peers
     pub average_quality: f64,
 }
 
@@ -235,6 +382,7 @@ pub trait PeerIdProvider: Send + Sync {
     fn to_peer_id(&self, libp2p_peer_id: &[u8]) -> PeerId;
 
     /// Get libp2p peer ID from our peer ID
+    #[allow(clippy::wrong_self_convention)]
     fn from_peer_id(&self, peer_id: PeerId) -> Vec<u8>;
 }
 
@@ -303,6 +451,59 @@ mod tests {
         assert!(all_peers.contains(&peer2));
     }
 
+    #[test]
+    fn test_repeated_violations_ban_peer() {
+        let manager = PeerManager::default();
+        let peer_id = PeerId::random();
+
+        manager
+            .add_peer(peer_id, "/ip4/127.0.0.1/tcp/9000".to_string())
+            .unwrap();
+        assert_eq!(manager.peer_score(peer_id), INITIAL_PEER_SCORE);
+
+        // 100 - 25 * 3 = 25, still above threshold
+        for _ in 0..3 {
+            assert!(!manager.record_violation(peer_id, PeerViolation::IntegrityFailure));
+        }
+        assert_eq!(manager.peer_score(peer_id), 25.0);
+        assert!(!manager.is_banned(peer_id));
+
+        // Fourth violation reaches the threshold
+        assert!(manager.record_violation(peer_id, PeerViolation::IntegrityFailure));
+        assert!(manager.is_banned(peer_id));
+        assert!(!manager.should_accept(peer_id));
+        assert!(!manager.is_connected(peer_id));
+        assert_eq!(manager.banned_peers(), vec![peer_id]);
+
+        // Banned peers cannot reconnect
+        assert!(manager
+            .add_peer(peer_id, "/ip4/127.0.0.1/tcp/9000".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_ban_lifts_after_cooldown() {
+        let manager = PeerManager::default().with_ban_policy(50.0, Duration::from_millis(50));
+        let peer_id = PeerId::random();
+        let other = PeerId::random();
+
+        manager.record_violation(peer_id, PeerViolation::Replay);
+        manager.record_violation(peer_id, PeerViolation::Replay);
+        assert!(manager.record_violation(peer_id, PeerViolation::RateLimit));
+        assert!(manager.is_banned(peer_id));
+
+        // Other peers are unaffected
+        assert!(manager.should_accept(other));
+
+        std::thread::sleep(Duration::from_millis(80));
+
+        assert!(!manager.is_banned(peer_id));
+        assert_eq!(manager.peer_score(peer_id), INITIAL_PEER_SCORE);
+        assert!(manager
+            .add_peer(peer_id, "/ip4/127.0.0.1/tcp/9000".to_string())
+            .is_ok());
+    }
+
     #[test]
     fn test_peer_manager_stats() {
         let manager = PeerManager::default();
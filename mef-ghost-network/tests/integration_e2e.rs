@@ -6,11 +6,11 @@
  */
 
 use anyhow::Result;
+use mef_ghost_network::discovery::Capability;
 use mef_ghost_network::integration::GhostNetworkNode;
 use mef_ghost_network::packet::ResonanceState;
 use mef_ghost_network::protocol::ProtocolConfig;
 use mef_ghost_network::transport::TransportConfig;
-use tokio::time::{sleep, timeout, Duration};
 
 #[tokio::test]
 async fn test_e2e_single_node_initialization() -> Result<()> {
@@ -44,8 +44,10 @@ async fn test_e2e_node_listen_and_announce() -> Result<()> {
     node.listen("/ip4/127.0.0.1/tcp/0".to_string()).await?;
 
     // Announce presence
-    let beacon_id = node.announce(Some(vec!["test-node".to_string()])).await?;
-    assert!(beacon_id.to_string().len() > 0);
+    let beacon_id = node
+        .announce(Some(vec![Capability::Custom("test-node".to_string())]))
+        .await?;
+    assert!(!beacon_id.to_string().is_empty());
 
     // Verify stats
     let stats = node.discovery_stats();
@@ -84,7 +86,7 @@ async fn test_e2e_transaction_send_receive() -> Result<()> {
         .send_transaction(target_resonance, action.clone())
         .await?;
     assert!(
-        channel_ids.len() > 0,
+        !channel_ids.is_empty(),
         "Transaction should match at least one channel"
     );
 
@@ -186,7 +188,7 @@ async fn test_e2e_protocol_6_steps() -> Result<()> {
 
     // Verify all steps completed
     assert!(
-        channel_ids.len() > 0,
+        !channel_ids.is_empty(),
         "6-step protocol should complete successfully"
     );
 
@@ -195,7 +197,7 @@ async fn test_e2e_protocol_6_steps() -> Result<()> {
     // packets_sent is updated in lib.rs, packets_accepted in protocol.rs receive_packet
     // For send_transaction, we only increment packets_sent via broadcast
     assert!(
-        metrics.packets_received >= 0,
+        metrics.packets_accepted <= metrics.packets_received,
         "Protocol metrics should be tracked"
     );
 
@@ -287,7 +289,7 @@ async fn test_e2e_adaptive_timestamps() -> Result<()> {
     let metrics = node.protocol_metrics();
     // Metrics will track timestamp validation
     assert!(
-        metrics.packets_received >= 0,
+        metrics.packets_accepted <= metrics.packets_received,
         "Adaptive timestamps should be tracked"
     );
 
@@ -301,7 +303,6 @@ async fn test_e2e_adaptive_timestamps() -> Result<()> {
 async fn test_e2e_discovery_and_find_nodes() -> Result<()> {
     // Test node discovery and finding nodes by resonance
     let node1_resonance = ResonanceState::new(5.0, 5.0, 5.0);
-    let node2_resonance = ResonanceState::new(5.05, 5.05, 5.05);
 
     let transport_config = TransportConfig::local();
     let protocol_config = ProtocolConfig::default();
@@ -317,13 +318,13 @@ async fn test_e2e_discovery_and_find_nodes() -> Result<()> {
     node1.listen("/ip4/127.0.0.1/tcp/50001".to_string()).await?;
 
     // Announce node1
-    node1.announce(Some(vec!["storage".to_string()])).await?;
+    node1.announce(Some(vec![Capability::Storage])).await?;
 
     // Poll for discovery (would receive beacons from other nodes)
     node1.poll_discovery().await?;
 
     // Find nodes by resonance
-    let found_nodes = node1.find_nodes(&node1_resonance);
+    let _found_nodes = node1.find_nodes(&node1_resonance);
     // In a single-node test, we won't find other nodes, but the API works
 
     // Cleanup
@@ -6,11 +6,12 @@
  */
 
 use anyhow::Result;
+use mef_ghost_network::discovery::Capability;
 use mef_ghost_network::integration::GhostNetworkNode;
 use mef_ghost_network::packet::ResonanceState;
 use mef_ghost_network::protocol::ProtocolConfig;
 use mef_ghost_network::transport::TransportConfig;
-use tokio::time::{sleep, timeout, Duration};
+use tokio::time::{sleep, Duration};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_two_nodes_dial_and_connect() -> Result<()> {
@@ -46,7 +47,7 @@ async fn test_two_nodes_dial_and_connect() -> Result<()> {
     // Node 2 connects to node 1
     let peer_id = node2.dial(node1_addr).await?;
     assert!(
-        peer_id.to_string().len() > 0,
+        !peer_id.to_string().is_empty(),
         "Should have connected to node1"
     );
 
@@ -104,7 +105,7 @@ async fn test_two_nodes_transaction_exchange() -> Result<()> {
         .send_transaction(receiver_resonance, action.clone())
         .await?;
 
-    assert!(channel_ids.len() > 0, "Transaction should match channels");
+    assert!(!channel_ids.is_empty(), "Transaction should match channels");
 
     // Give time for packet to propagate
     sleep(Duration::from_millis(2000)).await;
@@ -234,8 +235,8 @@ async fn test_discovery_across_nodes() -> Result<()> {
     sleep(Duration::from_millis(1000)).await;
 
     // Both nodes announce themselves
-    node1.announce(Some(vec!["storage".to_string()])).await?;
-    node2.announce(Some(vec!["compute".to_string()])).await?;
+    node1.announce(Some(vec![Capability::Storage])).await?;
+    node2.announce(Some(vec![Capability::Compute])).await?;
 
     // Give time for beacon propagation
     sleep(Duration::from_millis(2000)).await;
@@ -327,7 +328,7 @@ async fn test_resonance_isolation() -> Result<()> {
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_five_nodes_mesh_network() -> Result<()> {
     // Test a mesh network of 5 nodes
-    let resonances = vec![
+    let resonances = [
         ResonanceState::new(5.0, 5.0, 5.0),
         ResonanceState::new(5.05, 5.05, 5.05),
         ResonanceState::new(5.02, 5.02, 5.02),
@@ -360,9 +361,9 @@ async fn test_five_nodes_mesh_network() -> Result<()> {
     sleep(Duration::from_millis(1000)).await;
 
     // Create mesh: each node connects to all previous nodes
-    for i in 1..nodes.len() {
-        for j in 0..i {
-            nodes[i].dial(addresses[j].clone()).await?;
+    for (i, node) in nodes.iter_mut().enumerate().skip(1) {
+        for addr in &addresses[..i] {
+            node.dial(addr.clone()).await?;
         }
         sleep(Duration::from_millis(200)).await;
     }
@@ -427,15 +428,13 @@ async fn test_concurrent_transactions() -> Result<()> {
     sleep(Duration::from_millis(1000)).await;
 
     // Send concurrent transactions
-    let mut handles = Vec::new();
-
     for i in 0..5 {
         let action = format!("Concurrent transaction {}", i).into_bytes();
         let resonance = node2_resonance;
 
         // Clone node1 Arc if possible, or just send sequentially
         let channel_ids = node1.send_transaction(resonance, action).await?;
-        assert!(channel_ids.len() > 0);
+        assert!(!channel_ids.is_empty());
     }
 
     // Give time for all transactions to propagate
@@ -40,7 +40,9 @@ mod tests {
 
     #[test]
     fn test_quantum_ops_available() {
-        // Basic smoke test
-        assert!(true, "Quantum ops module loaded successfully");
+        // Basic smoke test: operators are usable through the common trait
+        let params = MaskingParams::from_seed(b"smoke");
+        let output = MaskingOperator::new().apply(Vec::new(), &params).unwrap();
+        assert!(output.is_empty());
     }
 }
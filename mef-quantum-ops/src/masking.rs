@@ -51,11 +51,14 @@ impl MaskingParams {
         let mut hasher = Hasher::new();
         hasher.update(seed);
         hasher.update(b"masking_params");
-        let hash = hasher.finalize();
 
-        let sigma: [u8; 32] = hash.as_bytes()[0..32].try_into().unwrap();
-        let theta_bytes: [u8; 8] = hash.as_bytes()[32..40].try_into().unwrap();
-        let theta = f64::from_le_bytes(theta_bytes) % std::f64::consts::TAU;
+        // 32 bytes for σ plus 8 for θ: more than a plain BLAKE3 hash yields
+        let mut output = [0u8; 40];
+        hasher.finalize_xof().fill(&mut output);
+
+        let sigma: [u8; 32] = output[0..32].try_into().unwrap();
+        let theta_bits = u64::from_le_bytes(output[32..40].try_into().unwrap());
+        let theta = (theta_bits as f64 / u64::MAX as f64) * std::f64::consts::TAU;
 
         Self { theta, sigma }
     }
@@ -77,9 +80,28 @@ impl MaskingOperator {
     }
 
     /// Unmask a message with given parameters
+    ///
+    /// Undoes both steps of `mask` in reverse order: the phase XOR is
+    /// self-inverse, the permutation is not.
     pub fn unmask(&self, masked: &[u8], params: &MaskingParams) -> Result<Vec<u8>> {
-        // Masking ist symmetrisch (Involution), daher gleiche Operation
-        self.apply(masked.to_vec(), params)
+        if masked.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let phase_key = self.phase_key(params.theta, masked.len());
+        let mut dephased: Vec<u8> = masked
+            .iter()
+            .zip(phase_key.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+
+        let perm = self.permutation(&params.sigma, masked.len());
+        let unmasked = self.apply_permutation(&dephased, &self.invert_permutation(&perm));
+
+        // Zeroize sensitive data
+        dephased.zeroize();
+
+        Ok(unmasked)
     }
 
     /// Compute phase key from theta
@@ -117,7 +139,6 @@ impl MaskingOperator {
     }
 
     /// Invert permutation
-    #[allow(dead_code)]
     fn invert_permutation(&self, perm: &[usize]) -> Vec<usize> {
         let mut inv = vec![0; perm.len()];
         for (i, &p) in perm.iter().enumerate() {
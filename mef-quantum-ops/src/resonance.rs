@@ -19,6 +19,9 @@ pub struct ResonanceWindow {
     pub epsilon: f64,
     /// Gewichte für multidimensionale Resonanz [psi, rho, omega]
     pub weights: [f64; 3],
+    /// Toleranz pro Dimension [psi, rho, omega] (None = ε in jeder Dimension)
+    #[serde(default)]
+    pub tolerances: Option<[f64; 3]>,
 }
 
 impl ResonanceWindow {
@@ -26,11 +29,37 @@ impl ResonanceWindow {
         Self {
             epsilon,
             weights: [1.0, 1.0, 1.0], // Gleichgewichtig
+            tolerances: None,
         }
     }
 
     pub fn with_weights(epsilon: f64, weights: [f64; 3]) -> Self {
-        Self { epsilon, weights }
+        Self {
+            epsilon,
+            weights,
+            tolerances: None,
+        }
+    }
+
+    /// Eigene Toleranz pro Dimension [psi, rho, omega] setzen
+    pub fn with_tolerances(mut self, tolerances: [f64; 3]) -> Self {
+        self.tolerances = Some(tolerances);
+        self
+    }
+
+    /// Effektive Toleranzen pro Dimension
+    pub fn tolerances(&self) -> [f64; 3] {
+        self.tolerances.unwrap_or([self.epsilon; 3])
+    }
+
+    /// Prüfe jede gewichtete Dimension gegen ihre eigene Toleranz
+    pub fn within_tolerances(&self, a: &ResonanceState, b: &ResonanceState) -> bool {
+        let tolerances = self.tolerances();
+        a.as_vector()
+            .iter()
+            .zip(b.as_vector())
+            .zip(self.weights.iter().zip(tolerances))
+            .all(|((x, y), (w, tol))| ((x - y) * w).abs() < tol)
     }
 
     /// Standard-Resonanzfenster (wie im Blueprint)
@@ -122,7 +151,7 @@ impl ResonanceOperator {
         window: &ResonanceWindow,
     ) -> bool {
         let distance = node_state.weighted_distance(packet_state, &window.weights);
-        distance < window.epsilon
+        distance < window.epsilon && window.within_tolerances(node_state, packet_state)
     }
 
     /// Berechne Resonanzstärke (0.0 = keine Resonanz, 1.0 = perfekte Resonanz)
@@ -133,7 +162,7 @@ impl ResonanceOperator {
         window: &ResonanceWindow,
     ) -> f64 {
         let distance = node_state.weighted_distance(packet_state, &window.weights);
-        if distance >= window.epsilon {
+        if distance >= window.epsilon || !window.within_tolerances(node_state, packet_state) {
             0.0
         } else {
             1.0 - (distance / window.epsilon)
@@ -271,6 +300,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_per_dimension_tolerance() {
+        let op = ResonanceOperator::new();
+        let state1 = ResonanceState::new(1.0, 1.0, 1.0);
+        let state2 = ResonanceState::new(1.0, 1.0, 1.05); // Only omega differs
+
+        // Uniform tolerance: omega offset is within ε
+        let uniform = ResonanceWindow::standard();
+        assert_eq!(uniform.tolerances(), [0.1; 3]);
+        assert!(op.is_resonant(&state1, &state2, &uniform));
+
+        // Tightened omega tolerance rejects the same offset
+        let tight_omega = ResonanceWindow::standard().with_tolerances([0.1, 0.1, 0.01]);
+        assert!(!op.is_resonant(&state1, &state2, &tight_omega));
+        assert_eq!(op.resonance_strength(&state1, &state2, &tight_omega), 0.0);
+
+        // Offsets in other dimensions are still judged against their own bound
+        let state3 = ResonanceState::new(1.05, 1.0, 1.0);
+        assert!(op.is_resonant(&state1, &state3, &tight_omega));
+    }
+
     #[test]
     fn test_collective_resonance() {
         let op = ResonanceOperator::new();
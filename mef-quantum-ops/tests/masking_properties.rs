@@ -0,0 +1,63 @@
+//! Property-based tests for masking with resonance-derived parameters
+//!
+//! Payloads shrink towards the empty vector, so a broken invariant is
+//! reported with a minimal failing payload.
+
+use mef_common::proptest_support::masking_case;
+use mef_quantum_ops::{MaskingOperator, MaskingParams};
+use proptest::prelude::*;
+
+/// Helper: Derive masking parameters from a sender/receiver resonance pair
+fn params_from_resonance(
+    sender: &mef_common::types::ResonanceTriplet,
+    receiver: &mef_common::types::ResonanceTriplet,
+) -> MaskingParams {
+    let mut seed = Vec::with_capacity(48);
+    for component in [
+        sender.psi,
+        sender.rho,
+        sender.omega,
+        receiver.psi,
+        receiver.rho,
+        receiver.omega,
+    ] {
+        seed.extend_from_slice(&component.to_le_bytes());
+    }
+    MaskingParams::from_seed(&seed)
+}
+
+proptest! {
+    /// INVARIANT: Masking with resonance-derived params is reversible
+    ///
+    /// For all payloads m and resonance pairs (s, r):
+    ///   unmask(mask(m, P(s, r)), P(s, r)) = m
+    #[test]
+    fn resonance_masking_round_trips(
+        (payload, sender, receiver) in masking_case(1000)
+    ) {
+        let masker = MaskingOperator::new();
+        let params = params_from_resonance(&sender, &receiver);
+
+        let masked = masker.mask(&payload, &params)?;
+        let unmasked = masker.unmask(&masked, &params)?;
+
+        prop_assert_eq!(payload, unmasked,
+            "Masking not reversible for resonance-derived params");
+    }
+
+    /// INVARIANT: Resonance-derived masking is deterministic
+    ///
+    /// Same payload + same resonance pair → same masked output
+    #[test]
+    fn resonance_masking_is_deterministic(
+        (payload, sender, receiver) in masking_case(500)
+    ) {
+        let masker = MaskingOperator::new();
+
+        let masked1 = masker.mask(&payload, &params_from_resonance(&sender, &receiver))?;
+        let masked2 = masker.mask(&payload, &params_from_resonance(&sender, &receiver))?;
+
+        prop_assert_eq!(masked1, masked2,
+            "Resonance-derived masking not deterministic");
+    }
+}
@@ -4,6 +4,7 @@
 //! randomly-generated test cases.
 
 use mef_common::proptest_support::*;
+use mef_quantum_ops::resonance::ResonanceState;
 use mef_quantum_ops::*;
 use proptest::prelude::*;
 
@@ -123,32 +124,31 @@ proptest! {
 // Resonance Operator Property Tests
 // ============================================================================
 
-/// Helper: Generate arbitrary resonance params
-fn arb_resonance_params() -> impl Strategy<Value = ResonanceParams> {
-    (
-        0.0..=1.0, // epsilon (threshold)
-        arb_bytes(32).prop_map(|v| {
-            let mut arr = [0u8; 32];
-            arr.copy_from_slice(&v[..32.min(v.len())]);
-            arr
-        }), // seed
-    )
-        .prop_map(|(epsilon, seed)| ResonanceParams::new(epsilon, seed))
+/// Helper: Generate arbitrary resonance states
+fn arb_resonance_state() -> impl Strategy<Value = ResonanceState> {
+    (-10.0..10.0, -10.0..10.0, -10.0..10.0)
+        .prop_map(|(psi, rho, omega)| ResonanceState::new(psi, rho, omega))
+}
+
+/// Helper: Generate arbitrary resonance windows
+fn arb_resonance_window() -> impl Strategy<Value = ResonanceWindow> {
+    (0.01..5.0, prop::array::uniform3(0.1..2.0))
+        .prop_map(|(epsilon, weights)| ResonanceWindow::with_weights(epsilon, weights))
 }
 
 proptest! {
-    /// INVARIANT: Resonance values are in [0, 1]
+    /// INVARIANT: Resonance strength is in [0, 1]
     #[test]
     fn resonance_in_valid_range(
-        data1 in arb_nonempty_bytes(100),
-        data2 in arb_nonempty_bytes(100),
-        params in arb_resonance_params()
+        node in arb_resonance_state(),
+        packet in arb_resonance_state(),
+        window in arb_resonance_window()
     ) {
         let resonator = ResonanceOperator::new();
-        let resonance = resonator.compute_resonance(&data1, &data2, &params)?;
+        let strength = resonator.resonance_strength(&node, &packet, &window);
 
-        prop_assert!(resonance >= 0.0 && resonance <= 1.0,
-            "Resonance {} not in [0, 1]", resonance);
+        prop_assert!((0.0..=1.0).contains(&strength),
+            "Resonance {} not in [0, 1]", strength);
     }
 
     /// INVARIANT: Resonance is symmetric
@@ -156,14 +156,14 @@ proptest! {
     /// R(a, b) = R(b, a)
     #[test]
     fn resonance_is_symmetric(
-        data1 in arb_nonempty_bytes(100),
-        data2 in arb_nonempty_bytes(100),
-        params in arb_resonance_params()
+        a in arb_resonance_state(),
+        b in arb_resonance_state(),
+        window in arb_resonance_window()
     ) {
         let resonator = ResonanceOperator::new();
 
-        let r_ab = resonator.compute_resonance(&data1, &data2, &params)?;
-        let r_ba = resonator.compute_resonance(&data2, &data1, &params)?;
+        let r_ab = resonator.resonance_strength(&a, &b, &window);
+        let r_ba = resonator.resonance_strength(&b, &a, &window);
 
         prop_assert!((r_ab - r_ba).abs() < 1e-10,
             "Resonance not symmetric: {} != {}", r_ab, r_ba);
@@ -174,11 +174,11 @@ proptest! {
     /// R(x, x) = 1.0
     #[test]
     fn self_resonance_is_maximum(
-        data in arb_nonempty_bytes(100),
-        params in arb_resonance_params()
+        state in arb_resonance_state(),
+        window in arb_resonance_window()
     ) {
         let resonator = ResonanceOperator::new();
-        let self_resonance = resonator.compute_resonance(&data, &data, &params)?;
+        let self_resonance = resonator.resonance_strength(&state, &state, &window);
 
         prop_assert!((self_resonance - 1.0).abs() < 1e-10,
             "Self-resonance {} != 1.0", self_resonance);
@@ -186,22 +186,21 @@ proptest! {
 
     /// INVARIANT: Resonance check is consistent
     ///
-    /// check_resonance returns true iff computed resonance >= epsilon
+    /// is_resonant returns true iff the resonance strength is positive
     #[test]
     fn resonance_check_consistent(
-        data1 in arb_nonempty_bytes(100),
-        data2 in arb_nonempty_bytes(100),
-        params in arb_resonance_params()
+        node in arb_resonance_state(),
+        packet in arb_resonance_state(),
+        window in arb_resonance_window()
     ) {
         let resonator = ResonanceOperator::new();
 
-        let resonance = resonator.compute_resonance(&data1, &data2, &params)?;
-        let check = resonator.check_resonance(&data1, &data2, &params)?;
+        let strength = resonator.resonance_strength(&node, &packet, &window);
+        let check = resonator.is_resonant(&node, &packet, &window);
 
-        let expected_check = resonance >= params.epsilon;
-        prop_assert_eq!(check, expected_check,
-            "Check inconsistent: resonance={}, epsilon={}, check={}, expected={}",
-            resonance, params.epsilon, check, expected_check);
+        prop_assert_eq!(check, strength > 0.0,
+            "Check inconsistent: strength={}, epsilon={}, check={}",
+            strength, window.epsilon, check);
     }
 }
 
@@ -209,55 +208,75 @@ proptest! {
 // Steganography Operator Property Tests
 // ============================================================================
 
+/// Helper: Generate payloads without zero bytes (LSB extraction stops at 0x00)
+fn arb_lsb_payload(max_len: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(1u8..=255, 1..=max_len)
+}
+
 proptest! {
-    /// INVARIANT: Steganography is reversible
+    /// INVARIANT: Zero-width steganography is reversible
     ///
-    /// extract(hide(message, carrier)) = message
+    /// extract(embed(message, text)) = message
     #[test]
-    fn steganography_is_reversible(
-        secret in arb_nonempty_bytes(100),
-        carrier_size in 200usize..1000
+    fn zero_width_steganography_is_reversible(
+        secret in arb_bytes(100),
+        text in "[a-zA-Z ]{0,50}"
     ) {
-        // Create carrier (must be larger than secret)
-        let carrier: Vec<u8> = (0..carrier_size).map(|i| (i % 256) as u8).collect();
+        let stego_op = SteganographyOperator::new();
+
+        let stego_data = stego_op.embed(&secret, CarrierType::ZeroWidth(text))?;
+        let extracted = stego_op.extract(&stego_data, "zero_width")?;
+
+        prop_assert_eq!(secret, extracted,
+            "Steganography not reversible");
+    }
 
+    /// INVARIANT: LSB steganography is reversible
+    ///
+    /// The carrier's low bits are cleared, so the payload is followed by a
+    /// null terminator.
+    #[test]
+    fn lsb_steganography_is_reversible(
+        secret in arb_lsb_payload(50),
+        extra in 8usize..200
+    ) {
+        let carrier = vec![0xAAu8; secret.len() * 8 + extra];
         let stego_op = SteganographyOperator::new();
 
-        let stego_data = stego_op.hide(&secret, &carrier)?;
-        let extracted = stego_op.extract(&stego_data, secret.len())?;
+        let stego_data = stego_op.embed(&secret, CarrierType::Image(carrier))?;
+        let extracted = stego_op.extract(&stego_data, "image")?;
 
         prop_assert_eq!(secret, extracted,
             "Steganography not reversible");
     }
 
-    /// INVARIANT: Steganography preserves carrier size
+    /// INVARIANT: LSB steganography preserves carrier size
     ///
-    /// |hide(m, c)| = |c|
+    /// |embed(m, c)| = |c|
     #[test]
     fn steganography_preserves_carrier_size(
         secret in arb_bytes(50),
-        carrier_size in 100usize..500
+        carrier_size in 400usize..1000
     ) {
         let carrier: Vec<u8> = (0..carrier_size).map(|i| (i % 256) as u8).collect();
         let stego_op = SteganographyOperator::new();
 
-        let stego_data = stego_op.hide(&secret, &carrier)?;
+        let stego_data = stego_op.embed(&secret, CarrierType::Image(carrier.clone()))?;
 
         prop_assert_eq!(carrier.len(), stego_data.len(),
             "Carrier size not preserved");
     }
 
-    /// INVARIANT: Cannot hide message larger than carrier capacity
+    /// INVARIANT: Cannot embed a payload larger than the carrier capacity
     #[test]
     fn steganography_rejects_oversized_message(
-        secret in arb_bytes(100),
-        small_carrier_size in 1usize..50
+        secret in arb_nonempty_bytes(100),
+        deficit in 1usize..8
     ) {
-        let carrier: Vec<u8> = vec![0u8; small_carrier_size];
+        let carrier = vec![0u8; secret.len() * 8 - deficit];
         let stego_op = SteganographyOperator::new();
 
-        // Should return error for oversized message
-        let result = stego_op.hide(&secret, &carrier);
+        let result = stego_op.embed(&secret, CarrierType::Image(carrier));
         prop_assert!(result.is_err(),
             "Should reject oversized message");
     }
@@ -271,71 +290,70 @@ proptest! {
     /// INVARIANT: Valid proofs verify successfully
     #[test]
     fn valid_zk_proofs_verify(
-        statement in arb_nonempty_bytes(100),
-        witness in arb_nonempty_bytes(100)
+        commitment in arb_nonempty_bytes(100),
+        secret in arb_nonempty_bytes(100)
     ) {
         let zk = ZKProofOperator::new();
 
-        let proof = zk.prove(&statement, &witness)?;
-        let verified = zk.verify(&statement, &proof)?;
+        let proof = zk.prove_knowledge(&secret, &commitment)?;
 
-        prop_assert!(verified,
+        prop_assert!(zk.verify_knowledge(&proof, &commitment)?,
             "Valid proof failed verification");
+        prop_assert!(zk.verify(&proof)?,
+            "Valid proof failed generic verification");
     }
 
     /// INVARIANT: Proofs are deterministic for same input
     #[test]
     fn zk_proofs_deterministic(
-        statement in arb_nonempty_bytes(100),
-        witness in arb_nonempty_bytes(100)
+        commitment in arb_nonempty_bytes(100),
+        secret in arb_nonempty_bytes(100)
     ) {
         let zk = ZKProofOperator::new();
 
-        let proof1 = zk.prove(&statement, &witness)?;
-        let proof2 = zk.prove(&statement, &witness)?;
+        let proof1 = zk.prove_knowledge(&secret, &commitment)?;
+        let proof2 = zk.prove_knowledge(&secret, &commitment)?;
 
-        prop_assert_eq!(proof1, proof2,
+        prop_assert_eq!(proof1.proof_data, proof2.proof_data,
             "ZK proofs not deterministic");
     }
 
-    /// INVARIANT: Proofs don't reveal witness
+    /// INVARIANT: Proofs don't reveal the secret
     ///
-    /// Proof should not contain the witness data
+    /// Proof data should not contain the secret
     #[test]
-    fn zk_proofs_dont_reveal_witness(
-        statement in arb_nonempty_bytes(100),
-        witness in arb_nonempty_bytes(100)
+    fn zk_proofs_dont_reveal_secret(
+        commitment in arb_nonempty_bytes(100),
+        secret in prop::collection::vec(any::<u8>(), 8..100)
     ) {
         let zk = ZKProofOperator::new();
-        let proof = zk.prove(&statement, &witness)?;
+        let proof = zk.prove_knowledge(&secret, &commitment)?;
 
-        // Proof should not contain the witness
-        // (This is a basic check - full zero-knowledge requires cryptographic proof)
-        let proof_bytes = proof.to_bytes();
+        // Basic check - full zero-knowledge requires a cryptographic proof
         prop_assert!(
-            !contains_subslice(&proof_bytes, &witness),
-            "Proof appears to contain witness data"
+            !contains_subslice(&proof.proof_data, &secret),
+            "Proof appears to contain secret data"
         );
     }
 
-    /// INVARIANT: Different witnesses produce different proofs
+    /// INVARIANT: Different secrets produce different proofs
     #[test]
-    fn different_witnesses_different_proofs(
-        statement in arb_nonempty_bytes(100),
-        witness1 in arb_nonempty_bytes(100),
-        witness2 in arb_nonempty_bytes(100)
+    fn different_secrets_different_proofs(
+        commitment in arb_nonempty_bytes(100),
+        secret1 in arb_nonempty_bytes(100),
+        secret2 in arb_nonempty_bytes(100)
     ) {
-        if witness1 == witness2 {
+        if secret1 == secret2 {
             return Ok(());
         }
 
         let zk = ZKProofOperator::new();
 
-        let proof1 = zk.prove(&statement, &witness1)?;
-        let proof2 = zk.prove(&statement, &witness2)?;
+        let proof1 = zk.prove_knowledge(&secret1, &commitment)?;
+        let proof2 = zk.prove_knowledge(&secret2, &commitment)?;
 
-        prop_assert_ne!(proof1, proof2,
-            "Different witnesses produced same proof");
+        prop_assert_ne!(proof1.proof_data, proof2.proof_data,
+            "Different secrets produced same proof");
     }
 }
 
@@ -344,48 +362,23 @@ proptest! {
 // ============================================================================
 
 proptest! {
-    /// INVARIANT: Masking then resonance check works correctly
-    #[test]
-    fn composed_masking_and_resonance(
-        message in arb_nonempty_bytes(100),
-        mask_params in arb_masking_params(),
-        res_params in arb_resonance_params()
-    ) {
-        let masker = MaskingOperator::new();
-        let resonator = ResonanceOperator::new();
-
-        // Mask the message
-        let masked = masker.mask(&message, &mask_params)?;
-
-        // Masked message should have high self-resonance
-        let self_res = resonator.compute_resonance(&masked, &masked, &res_params)?;
-        prop_assert!((self_res - 1.0).abs() < 1e-10);
-
-        // Unmasked version should match original in resonance
-        let unmasked = masker.unmask(&masked, &mask_params)?;
-        let orig_res = resonator.compute_resonance(&message, &unmasked, &res_params)?;
-        prop_assert!((orig_res - 1.0).abs() < 1e-10);
-    }
-
     /// INVARIANT: Stego + Masking composition preserves reversibility
     #[test]
     fn composed_stego_and_masking(
-        secret in arb_nonempty_bytes(50),
-        carrier_size in 200usize..500,
+        secret in arb_bytes(50),
+        text in "[a-z ]{0,20}",
         mask_params in arb_masking_params()
     ) {
-        let carrier: Vec<u8> = (0..carrier_size).map(|i| (i % 256) as u8).collect();
-
         let stego_op = SteganographyOperator::new();
         let masker = MaskingOperator::new();
 
         // First hide in carrier, then mask
-        let hidden = stego_op.hide(&secret, &carrier)?;
+        let hidden = stego_op.embed(&secret, CarrierType::ZeroWidth(text))?;
         let masked = masker.mask(&hidden, &mask_params)?;
 
         // Reverse: unmask, then extract
         let unmasked = masker.unmask(&masked, &mask_params)?;
-        let extracted = stego_op.extract(&unmasked, secret.len())?;
+        let extracted = stego_op.extract(&unmasked, "zero_width")?;
 
         prop_assert_eq!(secret, extracted,
             "Composed operations not reversible");
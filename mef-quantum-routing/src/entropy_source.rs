@@ -7,7 +7,6 @@
 
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use serde::{Deserialize, Serialize};
 
 /// Trait for entropy sources
 pub trait EntropySource {
@@ -180,7 +179,7 @@ mod tests {
 
         // Test random_f64
         let val = entropy.random_f64();
-        assert!(val >= 0.0 && val < 1.0);
+        assert!((0.0..1.0).contains(&val));
 
         // Test random_usize
         let val = entropy.random_usize(10);
@@ -196,10 +195,10 @@ mod tests {
     fn test_weighted_selection() {
         let mut entropy = QuantumEntropySource::from_seed([42u8; 32]);
 
-        let weights = vec![0.1, 0.3, 0.6];
+        let weights = [0.1, 0.3, 0.6];
 
         // Run multiple selections and verify distribution
-        let mut counts = vec![0; 3];
+        let mut counts = [0; 3];
         for _ in 0..1000 {
             if let Some(idx) = entropy.select_weighted(&weights) {
                 counts[idx] += 1;
@@ -21,6 +21,7 @@
 use crate::entropy_source::{EntropySource, QuantumEntropySource};
 use crate::topology::{NetworkTopology, ResonanceState};
 use anyhow::Result;
+use mef_common::cmp_desc_nan_last;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
@@ -99,6 +100,7 @@ impl Default for RouterConfig {
 }
 
 /// Quantum Random Walk Router
+#[derive(Debug)]
 pub struct QuantumRandomWalkRouter<E: EntropySource = QuantumEntropySource> {
     /// Network topology
     topology: Arc<RwLock<NetworkTopology>>,
@@ -228,7 +230,7 @@ impl<E: EntropySource> QuantumRandomWalkRouter<E> {
             .collect();
 
         // Sort by probability descending
-        alternatives.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        alternatives.sort_by(|a, b| cmp_desc_nan_last(a.1, b.1));
 
         // Take top N
         alternatives
@@ -435,7 +437,7 @@ mod tests {
         let decision = router.next_hop(target).unwrap().unwrap();
 
         // Should have alternatives (up to max_alternatives)
-        assert!(decision.alternatives.len() > 0);
+        assert!(!decision.alternatives.is_empty());
         assert!(decision.alternatives.len() <= router.config.max_alternatives);
     }
 }
@@ -6,6 +6,7 @@
  * explicit routing protocols.
  */
 
+use mef_common::cmp_desc_nan_last;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -164,14 +165,6 @@ impl NetworkTopology {
         }
     }
 
-    /// Create with default settings
-    pub fn default() -> Self {
-        Self::new(
-            300,  // 5 minute timeout
-            1000, // Track up to 1000 nodes
-        )
-    }
-
     /// Add or update node
     pub fn add_node(&mut self, node_id: Uuid, resonance: ResonanceState) {
         if let Some(metrics) = self.nodes.get_mut(&node_id) {
@@ -219,12 +212,13 @@ impl NetworkTopology {
 
     /// Find best next hop for target resonance
     pub fn find_best_hop(&self, target_resonance: &ResonanceState) -> Option<Uuid> {
+        // Highest score wins, ties go to the last; NaN scores never win
         self.get_active_nodes()
             .iter()
             .max_by(|a, b| {
                 let score_a = a.routing_score(target_resonance);
                 let score_b = b.routing_score(target_resonance);
-                score_a.partial_cmp(&score_b).unwrap()
+                cmp_desc_nan_last(score_b, score_a)
             })
             .map(|n| n.node_id)
     }
@@ -272,7 +266,18 @@ impl NetworkTopology {
     }
 }
 
+impl Default for NetworkTopology {
+    /// Create with default settings
+    fn default() -> Self {
+        Self::new(
+            300,  // 5 minute timeout
+            1000, // Track up to 1000 nodes
+        )
+    }
+}
+
 /// Topology view for routing decisions
+#[derive(Debug)]
 pub struct TopologyView<'a> {
     /// Reference to topology
     topology: &'a NetworkTopology,
@@ -295,6 +300,16 @@ impl<'a> TopologyView<'a> {
         }
     }
 
+    /// Get the underlying topology
+    pub fn topology(&self) -> &'a NetworkTopology {
+        self.topology
+    }
+
+    /// Get the target resonance
+    pub fn target_resonance(&self) -> ResonanceState {
+        self.target_resonance
+    }
+
     /// Get candidate nodes for routing
     pub fn get_candidates(&self) -> Vec<(Uuid, f64)> {
         self.scores.clone()
@@ -302,16 +317,17 @@ impl<'a> TopologyView<'a> {
 
     /// Get best candidate
     pub fn get_best_candidate(&self) -> Option<(Uuid, f64)> {
+        // Highest score wins, ties go to the last; NaN scores never win
         self.scores
             .iter()
-            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .max_by(|a, b| cmp_desc_nan_last(b.1, a.1))
             .copied()
     }
 
     /// Get top N candidates
     pub fn get_top_candidates(&self, n: usize) -> Vec<(Uuid, f64)> {
         let mut sorted = self.scores.clone();
-        sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        sorted.sort_by(|a, b| cmp_desc_nan_last(a.1, b.1));
         sorted.into_iter().take(n).collect()
     }
 }
@@ -429,4 +445,38 @@ mod tests {
         assert_eq!(removed, 1);
         assert_eq!(topology.node_count(), 0);
     }
+
+    #[test]
+    fn test_nan_scores_do_not_panic() {
+        let topology = NetworkTopology::new(300, 100);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let view = TopologyView {
+            topology: &topology,
+            target_resonance: ResonanceState::new(1.0, 1.0, 1.0),
+            scores: vec![(a, 0.5), (b, f64::NAN), (c, 0.9)],
+        };
+
+        assert_eq!(view.get_best_candidate().map(|(id, _)| id), Some(c));
+
+        // NaN scores sort after every real score
+        let top: Vec<Uuid> = view
+            .get_top_candidates(3)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(top, vec![c, a, b]);
+    }
+
+    #[test]
+    fn test_best_candidate_tie_goes_to_last() {
+        let topology = NetworkTopology::new(300, 100);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let view = TopologyView {
+            topology: &topology,
+            target_resonance: ResonanceState::new(1.0, 1.0, 1.0),
+            scores: vec![(a, 0.9), (b, f64::NAN), (c, 0.9)],
+        };
+
+        assert_eq!(view.get_best_candidate().map(|(id, _)| id), Some(c));
+    }
 }
@@ -62,7 +62,7 @@ fn main() -> anyhow::Result<()> {
     println!("   Formula: SW(v) = g_τ(m(v)) · v");
     let mut sweep = Sweep::new(0.5, 0.1, "cosine".to_string());
     let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
-    let result = sweep.apply(&v);
+    let result = Sweep::apply(&mut sweep, &v);
 
     println!("   Input:  {:?}", v.to_vec());
     println!("   Output: {:?}", result.to_vec());
@@ -71,7 +71,7 @@ fn main() -> anyhow::Result<()> {
     // 5. Pfadinvarianz Operator Demo
     println!("5. Pfadinvarianz Operator Demo");
     println!("   Formula: PI(v) = (1/|Π|) Σ_{{p∈Π}} T_p(v)");
-    let pi = Pfadinvarianz::new("lexicographic".to_string(), 1e-6);
+    let pi = Pfadinvarianz::new("lexicographic".to_string(), 1e-6)?;
     let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
     let result = pi.apply(&v);
 
@@ -84,7 +84,7 @@ fn main() -> anyhow::Result<()> {
     println!("   Formula: WT(v) = Σ_{{ℓ∈L}} w'_ℓ · P_ℓ(v)");
     let mut wt = WeightTransfer::default();
     let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
-    let result = wt.apply(&v);
+    let result = WeightTransfer::apply(&mut wt, &v);
 
     println!("   Input:  {:?}", v.to_vec());
     println!("   Output: {:?}", result.to_vec());
@@ -105,7 +105,7 @@ fn main() -> anyhow::Result<()> {
     let product = perm_op.compose(&p_dagger)?;
 
     let identity_matrix = QuantumUnitaryOperator::identity();
-    let mut max_diff = 0.0;
+    let mut max_diff: f64 = 0.0;
     for i in 0..METATRON_DIMENSION {
         for j in 0..METATRON_DIMENSION {
             let diff = (product.matrix[[i, j]] - identity_matrix.matrix[[i, j]]).norm();
@@ -166,7 +166,7 @@ fn main() -> anyhow::Result<()> {
         .iter()
         .filter(|&&p| p > 1e-10)
         .map(|&p| p * p.ln())
-        .sum();
+        .sum::<f64>();
     println!("   Von Neumann entropy S = {:.3}", entropy);
     println!("   ✓ Random state generated\n");
 
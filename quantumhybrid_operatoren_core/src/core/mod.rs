@@ -4,36 +4,54 @@
 
 pub mod traits;
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
 // Re-export wichtiger Traits
 pub use traits::{
-    ComposableOperator, ContractiveOperator, IdempotentOperator, InvertibleOperator,
-    OperatorStats, QuantumOperator, ResonanceOperator, StatefulOperator, UnitaryOperator,
+    BoxedOperator, ComposableOperator, ComposedOperator, ContractiveOperator, IdempotentOperator,
+    InvertibleOperator, OperatorStats, QuantumOperator, ResonanceOperator, StatefulOperator,
+    UnitaryOperator,
 };
 
 // Re-export Hilfsfunktionen
 pub use traits::{euclidean_distance, normalize_vector};
 
 /// Fehlertypen für Operatoren
-#[derive(Debug, thiserror::Error)]
+///
+/// `std::error::Error` wird nur mit dem Feature `std` implementiert.
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
 pub enum OperatorError {
-    #[error("Invalid input: {0}")]
+    /// Ungültige Eingabe
+    #[cfg_attr(feature = "std", error("Invalid input: {0}"))]
     InvalidInput(String),
 
-    #[error("Invalid parameters: {0}")]
+    /// Ungültige Parameter
+    #[cfg_attr(feature = "std", error("Invalid parameters: {0}"))]
     InvalidParams(String),
 
-    #[error("Operation failed: {0}")]
+    /// Operation fehlgeschlagen
+    #[cfg_attr(feature = "std", error("Operation failed: {0}"))]
     OperationFailed(String),
 
-    #[error("Not invertible: {0}")]
+    /// Operator ist nicht invertierbar
+    #[cfg_attr(feature = "std", error("Not invertible: {0}"))]
     NotInvertible(String),
 
-    #[error("Dimension mismatch: expected {expected}, got {actual}")]
-    DimensionMismatch { expected: usize, actual: usize },
-
-    #[error("Numerical error: {0}")]
+    #[cfg_attr(feature = "std", error("Dimension mismatch: expected {expected}, got {actual}"))]
+    /// Dimensionen passen nicht zusammen
+    DimensionMismatch {
+        /// Erwartete Dimension
+        expected: usize,
+        /// Tatsächliche Dimension
+        actual: usize,
+    },
+
+    /// Numerischer Fehler (z.B. NaN oder Überlauf)
+    #[cfg_attr(feature = "std", error("Numerical error: {0}"))]
     NumericalError(String),
 }
 
 /// Result-Typ für Operator-Operationen
-pub type Result<T> = std::result::Result<T, OperatorError>;
+pub type Result<T> = ::core::result::Result<T, OperatorError>;
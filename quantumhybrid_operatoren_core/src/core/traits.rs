@@ -5,9 +5,12 @@
  * im Framework implementieren müssen.
  */
 
+use ::core::fmt::Debug;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+
+#[allow(unused_imports)]
+use crate::compat::*;
 
 /// Haupt-Trait für Quantum-Resonant Operatoren
 ///
@@ -63,7 +66,7 @@ pub trait QuantumOperator: Send + Sync {
 
     /// Gibt den Namen des Operators zurück
     fn name(&self) -> &str {
-        std::any::type_name::<Self>()
+        ::core::any::type_name::<Self>()
     }
 
     /// Gibt eine Beschreibung des Operators zurück
@@ -166,6 +169,16 @@ pub trait IdempotentOperator: QuantumOperator {
     ) -> Result<bool>;
 }
 
+/// Operator hinter einem Trait-Objekt, z.B. das Ergebnis einer Komposition
+pub type BoxedOperator<I, O, P> = Box<dyn QuantumOperator<Input = I, Output = O, Params = P>>;
+
+/// Komposition A ∘ B: Eingabe von B, Ausgabe von A, Parameter beider
+pub type ComposedOperator<A, B> = BoxedOperator<
+    <B as QuantumOperator>::Input,
+    <A as QuantumOperator>::Output,
+    (<B as QuantumOperator>::Params, <A as QuantumOperator>::Params),
+>;
+
 /// Trait für komponierbare Operatoren
 ///
 /// Ermöglicht die Komposition von Operatoren: O₂ ∘ O₁
@@ -177,7 +190,7 @@ pub trait ComposableOperator: QuantumOperator {
     ///
     /// # Returns
     /// Ein neuer Operator der die Komposition repräsentiert
-    fn compose<O>(&self, other: &O) -> Result<Box<dyn QuantumOperator<Input = O::Input, Output = Self::Output, Params = (O::Params, Self::Params)>>>
+    fn compose<O>(&self, other: &O) -> Result<ComposedOperator<Self, O>>
     where
         O: QuantumOperator<Output = Self::Input>;
 }
@@ -209,8 +222,14 @@ pub struct OperatorStats {
     pub avg_duration_ns: u64,
     /// Anzahl der Fehler
     pub errors: u64,
-    /// Letzte Ausführungszeit
+    /// Letzte Ausführungszeit (Unix-Sekunden, nur mit `std`)
     pub last_execution: Option<u64>,
+    /// Mittleres Norm-Verhältnis ||O(v)|| / ||v|| (empirische Lipschitz-Schätzung)
+    #[serde(default)]
+    pub mean_norm_ratio: f64,
+    /// Anzahl der Anwendungen mit ||v|| > 0, die in `mean_norm_ratio` eingehen
+    #[serde(default)]
+    pub norm_ratio_samples: u64,
 }
 
 impl Default for OperatorStats {
@@ -220,6 +239,31 @@ impl Default for OperatorStats {
             avg_duration_ns: 0,
             errors: 0,
             last_execution: None,
+            mean_norm_ratio: 0.0,
+            norm_ratio_samples: 0,
+        }
+    }
+}
+
+impl OperatorStats {
+    /// Zeichnet eine Anwendung mit Input- und Output-Norm auf
+    ///
+    /// Inputs mit Norm 0 zählen als Ausführung, gehen aber nicht in das
+    /// Norm-Verhältnis ein.
+    pub fn record_application(&mut self, input_norm: f64, output_norm: f64) {
+        self.executions += 1;
+        if input_norm > 0.0 {
+            self.norm_ratio_samples += 1;
+            let ratio = output_norm / input_norm;
+            self.mean_norm_ratio += (ratio - self.mean_norm_ratio) / self.norm_ratio_samples as f64;
+        }
+
+        #[cfg(feature = "std")]
+        {
+            self.last_execution = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .ok()
+                .map(|d| d.as_secs());
         }
     }
 }
@@ -73,27 +73,51 @@
  * # Features
  *
  * - **std** (default): Standard library support
- * - **no_std**: No standard library (für embedded systems)
+ * - **no_std**: No standard library (für embedded systems), nur `core` + `alloc`.
+ *   Build mit `--no-default-features --features no_std`. Zufallsbasierte
+ *   Konstruktoren (`random()`, `measure()`) benötigen `std`; ohne `std`
+ *   stehen die `*_with_rng`-Varianten zur Verfügung.
  */
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+extern crate alloc;
+
 pub mod core;
 pub mod operators;
 
+/// Gemeinsame Importe für `std`- und `no_std`-Builds
+///
+/// Ohne `std` fehlen `Vec`, `String` etc. im Prelude und die
+/// Float-Methoden (`sqrt`, `exp`, ...) kommen über `libm`.
+mod compat {
+    pub(crate) use alloc::{
+        boxed::Box,
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) use num_traits::Float;
+}
+
 /// Prelude - Re-export der wichtigsten Typen
 pub mod prelude {
     // Core Traits
     pub use crate::core::{
         ComposableOperator, ContractiveOperator, IdempotentOperator, InvertibleOperator,
-        OperatorError, QuantumOperator, ResonanceOperator, StatefulOperator, UnitaryOperator,
+        OperatorError, QuantumOperator, ResonanceOperator as ResonanceOp, StatefulOperator,
+        UnitaryOperator,
     };
 
     // Operators
     pub use crate::operators::{
         DoubleKick, DoubleKickInfo, DoubleKickParams, MaskingOperator, MaskingParams,
-        Pfadinvarianz, PfadinvarianzParams, QuantumState, QuantumStateParams,
+        CanonMode, Pfadinvarianz, PfadinvarianzParams, QuantumState, QuantumStateParams,
         QuantumUnitaryOperator, ResonanceInput, ResonanceOperator, ResonanceState,
         ResonanceWindow, ScaleLevel, Sweep, SweepParams, WeightTransfer, WeightTransferParams,
         METATRON_DIMENSION,
@@ -120,3 +144,46 @@ mod tests {
         let _qstate = QuantumState::basis_state(0).unwrap();
     }
 }
+
+/// Läuft mit `cargo test --no-default-features --features no_std`
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use super::*;
+    use ndarray::Array1;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_operators_without_std() {
+        let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let dk = DoubleKick::default();
+        assert_eq!(DoubleKick::apply(&dk, &v).len(), 5);
+        assert!(dk.verify_non_expansive());
+
+        let mut sweep = Sweep::default();
+        assert_eq!(Sweep::apply(&mut sweep, &v).len(), 5);
+
+        let pi = Pfadinvarianz::default();
+        let projected = Pfadinvarianz::apply(&pi, &v);
+        assert!(projected.dot(&projected) <= v.dot(&v));
+
+        let mut wt = WeightTransfer::default();
+        assert_eq!(WeightTransfer::apply(&mut wt, &v).len(), 5);
+
+        let masking = MaskingOperator::new();
+        let params = MaskingParams::from_seed(b"no_std");
+        let masked = masking.mask(b"embedded", &params).unwrap();
+        assert_eq!(masking.unmask(&masked, &params).unwrap(), b"embedded");
+
+        let resonance = ResonanceOperator::new();
+        let state = ResonanceState::new(1.0, 0.8, 0.5);
+        assert!(resonance.is_resonant(&state, &state, &ResonanceWindow::standard()));
+
+        let mut rng = ChaCha20Rng::seed_from_u64(7);
+        let mut qstate = QuantumState::random_with_rng(&mut rng).unwrap();
+        assert!(qstate.is_normalized(1e-10));
+        let node = qstate.measure_with_rng(&mut rng);
+        assert!((1..=METATRON_DIMENSION).contains(&node));
+    }
+}
@@ -40,13 +40,21 @@ use rand::SeedableRng;
 use rand_distr::{Distribution, StandardNormal};
 use serde::{Deserialize, Serialize};
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
 /// DoubleKick operator information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DoubleKickInfo {
+    /// Erster Impuls-Koeffizient α₁
     pub alpha1: f64,
+    /// Zweiter Impuls-Koeffizient α₂
     pub alpha2: f64,
+    /// Gesamte Impulsstärke η = |α₁| + |α₂|
     pub eta: f64,
+    /// Ob u₁ und u₂ orthogonal sind
     pub u1_u2_orthogonal: bool,
+    /// Ob der Operator non-expansive ist
     pub non_expansive: bool,
 }
 
@@ -73,6 +81,7 @@ impl DoubleKick {
     /// Für non-expansiveness sollte |α₁| + |α₂| ≤ 0.1
     pub fn new(alpha1: f64, alpha2: f64) -> Self {
         let eta = alpha1.abs() + alpha2.abs();
+        #[cfg(feature = "std")]
         if eta > 0.1 {
             eprintln!(
                 "Warning: DoubleKick η={} > 0.1, may affect contractivity",
@@ -160,7 +169,9 @@ impl Default for DoubleKick {
 /// Parameter für DoubleKick Operator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DoubleKickParams {
+    /// Erster Impuls-Koeffizient α₁
     pub alpha1: f64,
+    /// Zweiter Impuls-Koeffizient α₂
     pub alpha2: f64,
 }
 
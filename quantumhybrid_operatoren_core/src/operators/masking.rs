@@ -53,6 +53,9 @@ use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
 /// Masking-Parameter
 ///
 /// # Felder
@@ -85,11 +88,12 @@ impl MaskingParams {
     /// ```ignore
     /// let params = MaskingParams::random();
     /// ```
+    #[cfg(feature = "std")]
     pub fn random() -> Self {
         use rand::Rng;
         let mut rng = rand::thread_rng();
         Self {
-            theta: rng.gen_range(0.0..std::f64::consts::TAU),
+            theta: rng.gen_range(0.0..::core::f64::consts::TAU),
             sigma: rand::random(),
         }
     }
@@ -110,11 +114,19 @@ impl MaskingParams {
         let mut hasher = Hasher::new();
         hasher.update(seed);
         hasher.update(b"masking_params");
-        let hash = hasher.finalize();
+        Self::from_hasher(&hasher)
+    }
+
+    /// Leite σ und θ aus 40 Bytes XOF-Ausgabe ab
+    ///
+    /// Ein einfacher BLAKE3-Hash liefert nur 32 Bytes, θ braucht 8 weitere.
+    fn from_hasher(hasher: &Hasher) -> Self {
+        let mut output = [0u8; 40];
+        hasher.finalize_xof().fill(&mut output);
 
-        let sigma: [u8; 32] = hash.as_bytes()[0..32].try_into().unwrap();
-        let theta_bytes: [u8; 8] = hash.as_bytes()[32..40].try_into().unwrap();
-        let theta = f64::from_le_bytes(theta_bytes) % std::f64::consts::TAU;
+        let sigma: [u8; 32] = output[0..32].try_into().unwrap();
+        let theta_bits = u64::from_le_bytes(output[32..40].try_into().unwrap());
+        let theta = (theta_bits as f64 / u64::MAX as f64) * ::core::f64::consts::TAU;
 
         Self { theta, sigma }
     }
@@ -130,13 +142,7 @@ impl MaskingParams {
         let mut hasher = Hasher::new();
         hasher.update(b"ephemeral_key");
         hasher.update(&epoch.to_le_bytes());
-        let hash = hasher.finalize();
-
-        let sigma: [u8; 32] = hash.as_bytes()[0..32].try_into().unwrap();
-        let theta_bytes: [u8; 8] = hash.as_bytes()[32..40].try_into().unwrap();
-        let theta = f64::from_le_bytes(theta_bytes) % std::f64::consts::TAU;
-
-        Self { theta, sigma }
+        Self::from_hasher(&hasher)
     }
 }
 
@@ -301,6 +307,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_masking_roundtrip() {
         let op = MaskingOperator::new();
         let message = b"Hello, Quantum Resonant Blockchain!";
@@ -353,6 +360,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_empty_message() {
         let op = MaskingOperator::new();
         let message: &[u8] = b"";
@@ -363,6 +371,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_large_message() {
         let op = MaskingOperator::new();
         let message = vec![42u8; 10_000];
@@ -385,6 +394,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_quantum_operator_trait() {
         let op = MaskingOperator::new();
         let message = b"Test message".to_vec();
@@ -13,7 +13,7 @@ pub mod weight_transfer;
 // Re-export wichtiger Operatoren
 pub use doublekick::{DoubleKick, DoubleKickInfo, DoubleKickParams};
 pub use masking::{MaskingOperator, MaskingParams};
-pub use pfadinvarianz::{Pfadinvarianz, PfadinvarianzParams};
+pub use pfadinvarianz::{CanonMode, Pfadinvarianz, PfadinvarianzParams};
 pub use quantum_state::{
     QuantumState, QuantumStateParams, QuantumUnitaryOperator, METATRON_DIMENSION,
 };
@@ -5,34 +5,80 @@
 //! Idempotent, non-expansive projection ensuring canonical ordering
 
 use crate::core::{ContractiveOperator, IdempotentOperator, QuantumOperator};
+use ::core::str::FromStr;
 use anyhow::Result;
 use ndarray::Array1;
 use serde::{Deserialize, Serialize};
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
+/// Canonical ordering applied to the path vectors before averaging
+///
+/// Parsed from the `canon` config string:
+/// - `"lexicographic"`: component-wise, first differing component decides
+/// - `"magnitude"` (alias `"norm"`): ascending Euclidean norm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CanonMode {
+    /// Component-wise, first differing component decides
+    Lexicographic,
+    /// Ascending Euclidean norm
+    #[serde(alias = "norm")]
+    Magnitude,
+}
+
+impl FromStr for CanonMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "lexicographic" => Ok(Self::Lexicographic),
+            "magnitude" | "norm" => Ok(Self::Magnitude),
+            other => anyhow::bail!(
+                "Unknown canonicalization mode '{}' (expected 'lexicographic' or 'magnitude')",
+                other
+            ),
+        }
+    }
+}
+
+/// Parameters for [`Pfadinvarianz`] as a [`QuantumOperator`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PfadinvarianzParams {
-    pub canon: String,
+    /// Ordering used by [`QuantumOperator::apply`]; unknown modes fail to deserialize
+    pub canon: CanonMode,
+    /// Tolerance for invariance checks
     pub tolerance: f64,
 }
 
 impl Default for PfadinvarianzParams {
     fn default() -> Self {
         Self {
-            canon: "lexicographic".to_string(),
+            canon: CanonMode::Lexicographic,
             tolerance: 1e-6,
         }
     }
 }
 
+/// Path-invariance (PI) operator: averages over path permutations in canonical order
 #[derive(Debug, Clone)]
 pub struct Pfadinvarianz {
-    canon: String,
+    canon: CanonMode,
     tol: f64,
     permutations: Vec<Vec<usize>>,
 }
 
 impl Pfadinvarianz {
-    pub fn new(canon: String, tol: f64) -> Self {
+    /// Create from a canonicalization mode string
+    ///
+    /// Fails on an unrecognized mode instead of silently skipping ordering.
+    pub fn new(canon: String, tol: f64) -> Result<Self> {
+        Ok(Self::with_mode(canon.parse()?, tol))
+    }
+
+    /// Create from a canonicalization mode
+    pub fn with_mode(canon: CanonMode, tol: f64) -> Self {
         let permutations = Self::initialize_permutations();
         Self {
             canon,
@@ -41,6 +87,16 @@ impl Pfadinvarianz {
         }
     }
 
+    /// Canonicalization mode used by [`apply`](Self::apply)
+    pub fn canon_mode(&self) -> CanonMode {
+        self.canon
+    }
+
+    /// Configured tolerance
+    pub fn tolerance(&self) -> f64 {
+        self.tol
+    }
+
     fn initialize_permutations() -> Vec<Vec<usize>> {
         vec![
             vec![0, 1, 2, 3, 4],
@@ -56,38 +112,40 @@ impl Pfadinvarianz {
         Array1::from(perm.iter().map(|&i| v[i]).collect::<Vec<_>>())
     }
 
-    fn canonical_order(&self, vectors: &mut [Array1<f64>]) {
-        match self.canon.as_str() {
-            "lexicographic" => {
+    fn canonical_order(&self, vectors: &mut [Array1<f64>], canon: CanonMode) {
+        match canon {
+            CanonMode::Lexicographic => {
                 vectors.sort_by(|a, b| {
-                    for i in 0..a.len() {
-                        match a[i].partial_cmp(&b[i]) {
-                            Some(std::cmp::Ordering::Equal) => continue,
-                            other => return other.unwrap(),
-                        }
-                    }
-                    std::cmp::Ordering::Equal
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|(x, y)| x.total_cmp(y))
+                        .find(|ord| ord.is_ne())
+                        .unwrap_or(::core::cmp::Ordering::Equal)
                 });
             }
-            "norm" => {
+            CanonMode::Magnitude => {
                 vectors.sort_by(|a, b| {
                     let norm_a = a.dot(a).sqrt();
                     let norm_b = b.dot(b).sqrt();
-                    norm_a.partial_cmp(&norm_b).unwrap()
+                    norm_a.total_cmp(&norm_b)
                 });
             }
-            _ => {}
         }
     }
 
+    /// Project `v` using the configured canonicalization mode
     pub fn apply(&self, v: &Array1<f64>) -> Array1<f64> {
+        self.project(v, self.canon)
+    }
+
+    fn project(&self, v: &Array1<f64>, canon: CanonMode) -> Array1<f64> {
         let mut path_vectors: Vec<Array1<f64>> = self
             .permutations
             .iter()
             .map(|perm| self.apply_permutation(v, perm))
             .collect();
 
-        self.canonical_order(&mut path_vectors);
+        self.canonical_order(&mut path_vectors, canon);
 
         let n = path_vectors.len() as f64;
         let sum: Array1<f64> = path_vectors
@@ -100,7 +158,7 @@ impl Pfadinvarianz {
 
 impl Default for Pfadinvarianz {
     fn default() -> Self {
-        Self::new("lexicographic".to_string(), 1e-6)
+        Self::with_mode(CanonMode::Lexicographic, 1e-6)
     }
 }
 
@@ -109,8 +167,8 @@ impl QuantumOperator for Pfadinvarianz {
     type Output = Array1<f64>;
     type Params = PfadinvarianzParams;
 
-    fn apply(&self, input: Self::Input, _params: &Self::Params) -> Result<Self::Output> {
-        Ok(self.apply(&input))
+    fn apply(&self, input: Self::Input, params: &Self::Params) -> Result<Self::Output> {
+        Ok(self.project(&input, params.canon))
     }
 
     fn name(&self) -> &str {
@@ -146,3 +204,72 @@ impl IdempotentOperator for Pfadinvarianz {
         Ok(distance < tolerance)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canon_mode_parsing() {
+        assert_eq!(
+            "lexicographic".parse::<CanonMode>().unwrap(),
+            CanonMode::Lexicographic
+        );
+        assert_eq!(
+            "magnitude".parse::<CanonMode>().unwrap(),
+            CanonMode::Magnitude
+        );
+        assert_eq!("norm".parse::<CanonMode>().unwrap(), CanonMode::Magnitude);
+
+        assert!("random".parse::<CanonMode>().is_err());
+        assert!(Pfadinvarianz::new("random".to_string(), 1e-6).is_err());
+    }
+
+    #[test]
+    fn test_lexicographic_ordering() {
+        let pi = Pfadinvarianz::with_mode(CanonMode::Lexicographic, 1e-6);
+        let mut vectors = vec![
+            Array1::from(vec![1.0, 2.0]),
+            Array1::from(vec![0.0, 5.0]),
+            Array1::from(vec![1.0, 1.0]),
+        ];
+
+        pi.canonical_order(&mut vectors, pi.canon_mode());
+
+        assert_eq!(vectors[0].to_vec(), vec![0.0, 5.0]);
+        assert_eq!(vectors[1].to_vec(), vec![1.0, 1.0]);
+        assert_eq!(vectors[2].to_vec(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_magnitude_ordering() {
+        let pi = Pfadinvarianz::new("magnitude".to_string(), 1e-6).unwrap();
+        let mut vectors = vec![
+            Array1::from(vec![3.0, 4.0]),
+            Array1::from(vec![1.0, 0.0]),
+            Array1::from(vec![0.0, 2.0]),
+        ];
+
+        pi.canonical_order(&mut vectors, pi.canon_mode());
+
+        assert_eq!(vectors[0].to_vec(), vec![1.0, 0.0]);
+        assert_eq!(vectors[1].to_vec(), vec![0.0, 2.0]);
+        assert_eq!(vectors[2].to_vec(), vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_params_canon_is_parsed() {
+        let params: PfadinvarianzParams =
+            serde_json::from_str(r#"{"canon":"norm","tolerance":1e-6}"#).unwrap();
+        assert_eq!(params.canon, CanonMode::Magnitude);
+
+        let round_trip: PfadinvarianzParams =
+            serde_json::from_str(&serde_json::to_string(&params).unwrap()).unwrap();
+        assert_eq!(round_trip.canon, CanonMode::Magnitude);
+
+        assert!(serde_json::from_str::<PfadinvarianzParams>(
+            r#"{"canon":"random","tolerance":1e-6}"#
+        )
+        .is_err());
+    }
+}
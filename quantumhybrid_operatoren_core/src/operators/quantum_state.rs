@@ -43,6 +43,7 @@
  * use quantumhybrid_operatoren_core::operators::quantum_state::*;
  * use num_complex::Complex64;
  *
+ * # fn main() -> anyhow::Result<()> {
  * // Create superposition state
  * let amplitudes = vec![
  *     Complex64::new(1.0, 0.0),
@@ -52,11 +53,13 @@
  *
  * // Apply unitary operator
  * let permutation = vec![2, 3, 1, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
- * let operator = QuantumOperator::from_permutation(&permutation);
- * let new_state = state.apply(&operator)?;
+ * let operator = QuantumUnitaryOperator::from_permutation(&permutation);
+ * let mut new_state = state.apply(&operator)?;
  *
  * // Measure
- * let node = state.measure();
+ * let node = new_state.measure();
+ * # Ok(())
+ * # }
  * ```
  */
 
@@ -67,6 +70,9 @@ use num_complex::Complex64;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
 /// Dimension des Metatron Cube Hilbert-Raums
 pub const METATRON_DIMENSION: usize = 13;
 
@@ -155,8 +161,13 @@ impl QuantumState {
     }
 
     /// Erstelle zufälligen Zustand (Haar-Maß)
+    #[cfg(feature = "std")]
     pub fn random() -> Result<Self> {
-        let mut rng = thread_rng();
+        Self::random_with_rng(&mut thread_rng())
+    }
+
+    /// Erstelle zufälligen Zustand aus gegebener RNG (auch ohne `std`)
+    pub fn random_with_rng<R: Rng>(rng: &mut R) -> Result<Self> {
         let amps: Vec<Complex64> = (0..METATRON_DIMENSION)
             .map(|_| Complex64::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)))
             .collect();
@@ -248,9 +259,14 @@ impl QuantumState {
     ///
     /// # Side Effects
     /// Kollabiert den Zustand auf den gemessenen Basis-Zustand
+    #[cfg(feature = "std")]
     pub fn measure(&mut self) -> usize {
+        self.measure_with_rng(&mut thread_rng())
+    }
+
+    /// Messung mit gegebener RNG (auch ohne `std`)
+    pub fn measure_with_rng<R: Rng>(&mut self, rng: &mut R) -> usize {
         let probs = self.probabilities();
-        let mut rng = thread_rng();
 
         // Sample aus Wahrscheinlichkeitsverteilung
         let mut cumulative = 0.0;
@@ -418,6 +434,7 @@ impl UnitaryOperator for QuantumUnitaryOperator {
 /// Parameter für Quantum State Operator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuantumStateParams {
+    /// Zustand nach der Anwendung normalisieren
     pub normalize: bool,
 }
 
@@ -502,6 +519,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_identity_operator() {
         let op = QuantumUnitaryOperator::identity();
         assert!(op.is_unitary(1e-8));
@@ -532,6 +550,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_measure() {
         let mut state = QuantumState::basis_state(0).unwrap();
         let measurement = state.measure();
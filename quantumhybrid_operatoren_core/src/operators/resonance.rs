@@ -50,6 +50,9 @@ use crate::core::{euclidean_distance, QuantumOperator, ResonanceOperator as Reso
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
 /// Resonanzfenster-Parameter
 ///
 /// Definiert die Breite des Resonanzfensters und optionale Gewichte
@@ -173,6 +176,7 @@ impl ResonanceState {
     }
 
     /// Erstelle zufälligen Zustand
+    #[cfg(feature = "std")]
     pub fn random() -> Self {
         use rand::Rng;
         let mut rng = rand::thread_rng();
@@ -309,7 +313,8 @@ impl ResonanceOperator {
                 let strength = self.resonance_strength(state, packet_state, window);
                 (*id, strength)
             })
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .filter(|(_, strength)| !strength.is_nan())
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
     }
 }
 
@@ -322,7 +327,9 @@ impl Default for ResonanceOperator {
 /// Input für Resonanz-Check
 #[derive(Debug, Clone)]
 pub struct ResonanceInput {
+    /// Resonanzzustand des Knotens
     pub node_state: ResonanceState,
+    /// Resonanzzustand des Pakets
     pub packet_state: ResonanceState,
 }
 
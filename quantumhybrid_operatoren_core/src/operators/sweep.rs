@@ -5,15 +5,22 @@
 //!
 //! Cosine schedule: τ_t = τ₀ + 0.5(1 + cos(πt/T))Δτ
 
-use crate::core::{ContractiveOperator, QuantumOperator};
+use crate::core::{ContractiveOperator, OperatorStats, QuantumOperator, StatefulOperator};
 use anyhow::Result;
 use ndarray::Array1;
 use serde::{Deserialize, Serialize};
 
+#[allow(unused_imports)]
+use crate::compat::*;
+
+/// Parameter für Sweep Operator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SweepParams {
+    /// Basis-Schwelle τ₀
     pub tau0: f64,
+    /// Steilheit β der Gate-Funktion
     pub beta: f64,
+    /// Schwellen-Schedule (`"cosine"`, `"linear"`, sonst konstant)
     pub schedule: String,
 }
 
@@ -27,6 +34,7 @@ impl Default for SweepParams {
     }
 }
 
+/// Sweep Operator: SW(v) = g_τ(m(v)) · v
 #[derive(Debug, Clone)]
 pub struct Sweep {
     tau0: f64,
@@ -35,9 +43,11 @@ pub struct Sweep {
     iteration: usize,
     max_iterations: usize,
     delta_tau: f64,
+    stats: OperatorStats,
 }
 
 impl Sweep {
+    /// Erstelle neuen Sweep Operator
     pub fn new(tau0: f64, beta: f64, schedule: String) -> Self {
         Self {
             tau0,
@@ -46,6 +56,7 @@ impl Sweep {
             iteration: 0,
             max_iterations: 100,
             delta_tau: 0.3,
+            stats: OperatorStats::default(),
         }
     }
 
@@ -53,16 +64,19 @@ impl Sweep {
         1.0 / (1.0 + (-x).exp())
     }
 
+    /// Gate-Funktion g_τ(x) = σ((x - τ)/β)
     pub fn gate_function(&self, x: f64, tau: f64) -> f64 {
         self.sigmoid((x - tau) / self.beta)
     }
 
+    /// Berechne aktuelle Schwelle τ_t gemäß Schedule
     pub fn compute_schedule(&self) -> f64 {
         match self.schedule.as_str() {
             "cosine" => {
                 let t = (self.iteration % self.max_iterations) as f64;
                 let cap_t = self.max_iterations as f64;
-                self.tau0 + 0.5 * (1.0 + (std::f64::consts::PI * t / cap_t).cos()) * self.delta_tau
+                self.tau0
+                    + 0.5 * (1.0 + (::core::f64::consts::PI * t / cap_t).cos()) * self.delta_tau
             }
             "linear" => {
                 let t = (self.iteration % self.max_iterations) as f64;
@@ -73,12 +87,16 @@ impl Sweep {
         }
     }
 
+    /// Wende Sweep Operator an und rücke den Schedule vor
     pub fn apply(&mut self, v: &Array1<f64>) -> Array1<f64> {
         let m_v = v.mean().unwrap_or(0.0);
         let tau = self.compute_schedule();
         let gate_value = self.gate_function(m_v, tau);
         self.iteration += 1;
-        v * gate_value
+        let output = v * gate_value;
+        self.stats
+            .record_application(v.dot(v).sqrt(), output.dot(&output).sqrt());
+        output
     }
 }
 
@@ -95,7 +113,7 @@ impl QuantumOperator for Sweep {
 
     fn apply(&self, input: Self::Input, _params: &Self::Params) -> Result<Self::Output> {
         let mut sweep = self.clone();
-        Ok(sweep.apply(&input))
+        Ok(Sweep::apply(&mut sweep, &input))
     }
 
     fn name(&self) -> &str {
@@ -116,3 +134,39 @@ impl ContractiveOperator for Sweep {
         1.0 // Gate value is in [0, 1]
     }
 }
+
+impl StatefulOperator for Sweep {
+    fn stats(&self) -> &OperatorStats {
+        &self.stats
+    }
+
+    fn reset_stats(&mut self) {
+        self.stats = OperatorStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_stay_within_lipschitz_bound() {
+        let mut sweep = Sweep::default();
+        let v = Array1::from(vec![0.2, 0.4, 0.6, 0.8, 1.0]);
+
+        for _ in 0..50 {
+            Sweep::apply(&mut sweep, &v);
+        }
+
+        let stats = sweep.stats();
+        assert_eq!(stats.executions, 50);
+        assert_eq!(stats.norm_ratio_samples, 50);
+        assert!(stats.mean_norm_ratio > 0.0);
+        assert!(stats.mean_norm_ratio <= sweep.lipschitz_constant());
+        #[cfg(feature = "std")]
+        assert!(stats.last_execution.is_some());
+
+        sweep.reset_stats();
+        assert_eq!(sweep.stats().executions, 0);
+    }
+}
@@ -5,16 +5,22 @@
 //!
 //! Multi-scale convex combination across Micro, Meso, Macro levels
 
-use crate::core::{ContractiveOperator, QuantumOperator};
+use crate::core::{ContractiveOperator, OperatorStats, QuantumOperator, StatefulOperator};
 use anyhow::Result;
 use ndarray::{Array1, Array2};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(unused_imports)]
+use crate::compat::*;
+
+/// Skalenebene
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ScaleLevel {
+    /// Feinste Skala
     Micro,
+    /// Mittlere Skala
     Meso,
+    /// Gröbste Skala
     Macro,
 }
 
@@ -28,9 +34,12 @@ impl ScaleLevel {
     }
 }
 
+/// Parameter für Weight-Transfer Operator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeightTransferParams {
+    /// Transferrate γ
     pub gamma: f64,
+    /// Namen der Skalenebenen (`"micro"`, `"meso"`, `"macro"`)
     pub levels: Vec<String>,
 }
 
@@ -43,16 +52,19 @@ impl Default for WeightTransferParams {
     }
 }
 
+/// Weight-Transfer Operator: WT(v) = Σ_ℓ w'_ℓ · P_ℓ(v)
 #[derive(Debug, Clone)]
 pub struct WeightTransfer {
     gamma: f64,
     levels: Vec<ScaleLevel>,
-    weights: HashMap<ScaleLevel, f64>,
-    target_weights: HashMap<ScaleLevel, f64>,
-    projections: HashMap<ScaleLevel, Array2<f64>>,
+    weights: BTreeMap<ScaleLevel, f64>,
+    target_weights: BTreeMap<ScaleLevel, f64>,
+    projections: BTreeMap<ScaleLevel, Array2<f64>>,
+    stats: OperatorStats,
 }
 
 impl WeightTransfer {
+    /// Erstelle neuen Weight-Transfer Operator (γ ∈ (0, 0.5])
     pub fn new(gamma: f64, levels: Vec<ScaleLevel>) -> Self {
         assert!(
             gamma > 0.0 && gamma <= 0.5,
@@ -62,9 +74,10 @@ impl WeightTransfer {
         let mut wt = Self {
             gamma,
             levels: levels.clone(),
-            weights: HashMap::new(),
-            target_weights: HashMap::new(),
-            projections: HashMap::new(),
+            weights: BTreeMap::new(),
+            target_weights: BTreeMap::new(),
+            projections: BTreeMap::new(),
+            stats: OperatorStats::default(),
         };
 
         wt.initialize_weights();
@@ -72,6 +85,53 @@ impl WeightTransfer {
         wt
     }
 
+    /// Create with explicit weights per scale level
+    ///
+    /// Weights must be finite and non-negative; they are renormalized to
+    /// sum to 1 so the output stays a convex combination of the level
+    /// projections. Only levels present in `weights` are used. The weights
+    /// are also the targets `apply` pulls towards, so they persist across
+    /// applications.
+    pub fn with_weights(gamma: f64, weights: BTreeMap<ScaleLevel, f64>) -> Result<Self> {
+        let weights = Self::normalize_weights(weights)?;
+        let levels = [ScaleLevel::Micro, ScaleLevel::Meso, ScaleLevel::Macro]
+            .into_iter()
+            .filter(|level| weights.contains_key(level))
+            .collect();
+
+        let mut wt = Self::new(gamma, levels);
+        wt.target_weights = weights.clone();
+        wt.weights = weights;
+        Ok(wt)
+    }
+
+    /// Validate weights and renormalize them to sum to 1
+    ///
+    /// Fails on negative or non-finite weights, or if all weights are zero.
+    pub fn normalize_weights(
+        weights: BTreeMap<ScaleLevel, f64>,
+    ) -> Result<BTreeMap<ScaleLevel, f64>> {
+        if let Some((level, w)) = weights.iter().find(|(_, w)| !w.is_finite() || **w < 0.0) {
+            anyhow::bail!(
+                "Invalid weight {} for level {}: must be finite and non-negative",
+                w,
+                level.as_str()
+            );
+        }
+
+        let total: f64 = weights.values().sum();
+        if total <= 0.0 {
+            anyhow::bail!("Weights must have a positive sum");
+        }
+
+        Ok(weights.into_iter().map(|(k, v)| (k, v / total)).collect())
+    }
+
+    /// Current weight per scale level
+    pub fn weights(&self) -> &BTreeMap<ScaleLevel, f64> {
+        &self.weights
+    }
+
     fn initialize_weights(&mut self) {
         let n_levels = self.levels.len() as f64;
         for level in &self.levels {
@@ -113,7 +173,7 @@ impl WeightTransfer {
     }
 
     fn update_weights(&mut self) {
-        let mut new_weights = HashMap::new();
+        let mut new_weights = BTreeMap::new();
         for level in &self.levels {
             let old_w = self.weights.get(level).copied().unwrap_or(0.0);
             let target_w = self.target_weights.get(level).copied().unwrap_or(old_w);
@@ -128,6 +188,7 @@ impl WeightTransfer {
             .collect();
     }
 
+    /// Wende Weight-Transfer an und aktualisiere die Gewichte
     pub fn apply(&mut self, v: &Array1<f64>) -> Array1<f64> {
         self.update_weights();
 
@@ -139,6 +200,8 @@ impl WeightTransfer {
                 result += &(projection.dot(v) * weight);
             }
         }
+        self.stats
+            .record_application(v.dot(v).sqrt(), result.dot(&result).sqrt());
         result
     }
 }
@@ -159,7 +222,7 @@ impl QuantumOperator for WeightTransfer {
 
     fn apply(&self, input: Self::Input, _params: &Self::Params) -> Result<Self::Output> {
         let mut wt = self.clone();
-        Ok(wt.apply(&input))
+        Ok(WeightTransfer::apply(&mut wt, &input))
     }
 
     fn name(&self) -> &str {
@@ -176,7 +239,132 @@ impl QuantumOperator for WeightTransfer {
 }
 
 impl ContractiveOperator for WeightTransfer {
+    /// Largest norm bound over the level projections
+    ///
+    /// A convex combination is no more expansive than its most expansive
+    /// term, and `‖P‖₂ ≤ √(‖P‖₁ · ‖P‖∞)` bounds each projection. The
+    /// default Micro and Macro projections stretch some inputs by 1.2, so
+    /// the operator is not non-expansive.
     fn lipschitz_constant(&self) -> f64 {
-        1.0 // Convex combination maintains non-expansiveness
+        self.levels
+            .iter()
+            .filter_map(|level| self.projections.get(level))
+            .map(|p| {
+                let max_row = p
+                    .rows()
+                    .into_iter()
+                    .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+                    .fold(0.0, f64::max);
+                let max_col = p
+                    .columns()
+                    .into_iter()
+                    .map(|col| col.iter().map(|x| x.abs()).sum::<f64>())
+                    .fold(0.0, f64::max);
+                (max_row * max_col).sqrt()
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+impl StatefulOperator for WeightTransfer {
+    fn stats(&self) -> &OperatorStats {
+        &self.stats
+    }
+
+    fn reset_stats(&mut self) {
+        self.stats = OperatorStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weights(micro: f64, meso: f64, macro_: f64) -> BTreeMap<ScaleLevel, f64> {
+        BTreeMap::from([
+            (ScaleLevel::Micro, micro),
+            (ScaleLevel::Meso, meso),
+            (ScaleLevel::Macro, macro_),
+        ])
+    }
+
+    #[test]
+    fn test_weights_renormalized() {
+        let wt = WeightTransfer::with_weights(0.1, weights(1.0, 2.0, 1.0)).unwrap();
+
+        let total: f64 = wt.weights().values().sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!((wt.weights()[&ScaleLevel::Meso] - 0.5).abs() < 1e-12);
+        assert!((wt.weights()[&ScaleLevel::Micro] - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_weights_rejected() {
+        assert!(WeightTransfer::with_weights(0.1, weights(-0.1, 0.6, 0.5)).is_err());
+        assert!(WeightTransfer::with_weights(0.1, weights(f64::NAN, 0.5, 0.5)).is_err());
+        assert!(WeightTransfer::with_weights(0.1, weights(0.0, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn test_output_is_convex_combination() {
+        for configured in [weights(0.2, 0.2, 0.2), weights(0.7, 0.1, 0.2)] {
+            let mut wt = WeightTransfer::with_weights(0.1, configured.clone()).unwrap();
+            let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+            for _ in 0..50 {
+                let result = WeightTransfer::apply(&mut wt, &v);
+
+                // Weights after the update are still non-negative and sum to 1
+                let total: f64 = wt.weights().values().sum();
+                assert!((total - 1.0).abs() < 1e-12);
+                assert!(wt.weights().values().all(|w| *w >= 0.0));
+
+                // Output equals the weighted sum of the level projections
+                let mut expected = Array1::zeros(v.len());
+                for level in &wt.levels {
+                    expected += &(wt.projections[level].dot(&v) * wt.weights()[level]);
+                }
+                let diff = &result - &expected;
+                assert!(diff.dot(&diff).sqrt() < 1e-12);
+            }
+
+            // Configured weights do not drift towards the defaults
+            let expected = WeightTransfer::normalize_weights(configured).unwrap();
+            for (level, w) in &expected {
+                assert!((wt.weights()[level] - w).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lipschitz_constant_bounds_projections() {
+        let wt = WeightTransfer::default();
+        assert!((wt.lipschitz_constant() - 1.2).abs() < 1e-10);
+        assert!(!wt.is_contractive());
+
+        // Meso alone is doubly stochastic and thus non-expansive
+        let meso =
+            WeightTransfer::with_weights(0.1, BTreeMap::from([(ScaleLevel::Meso, 1.0)])).unwrap();
+        assert!((meso.lipschitz_constant() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_stats_track_applications() {
+        let mut wt = WeightTransfer::default();
+        let v = Array1::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        for _ in 0..5 {
+            WeightTransfer::apply(&mut wt, &v);
+        }
+        WeightTransfer::apply(&mut wt, &Array1::zeros(5));
+
+        assert_eq!(wt.stats().executions, 6);
+        // Zero input counts as execution but not towards the norm ratio
+        assert_eq!(wt.stats().norm_ratio_samples, 5);
+        assert!(wt.stats().mean_norm_ratio > 0.0);
+        assert!(wt.stats().mean_norm_ratio <= wt.lipschitz_constant());
+
+        wt.reset_stats();
+        assert_eq!(wt.stats().executions, 0);
     }
 }